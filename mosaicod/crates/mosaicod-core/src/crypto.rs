@@ -0,0 +1,64 @@
+//! Envelope-encryption primitives for chunk data at rest.
+//!
+//! Each encrypted topic gets its own [`DataEncryptionKey`] (DEK), wrapped by a
+//! [`KeyEncryptionKey`] (KEK) before being persisted as a [`WrappedKey`] alongside the
+//! topic. This module holds only the key types and the KEK trait; generating a topic's DEK
+//! and choosing the KEK are done by the caller, not by `FacadeChunk` or `Context`.
+
+use rand::Rng;
+
+/// A topic's symmetric data-encryption key. One DEK is generated per topic and reused for
+/// every chunk belonging to it; per-chunk uniqueness comes from
+/// [`DataEncryptionKey::nonce_for_chunk`] rather than from rotating the key itself.
+#[derive(Clone)]
+pub struct DataEncryptionKey([u8; 32]);
+
+impl DataEncryptionKey {
+    /// Generates a fresh, cryptographically random key.
+    pub fn generate() -> Self {
+        Self(rand::rng().random::<[u8; 32]>())
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Derives the 96-bit AEAD nonce used to encrypt `chunk_id`'s data file. Chunk ids are
+    /// unique within a topic, so every chunk encrypted under this DEK gets a distinct
+    /// nonce without needing a nonce counter persisted anywhere.
+    pub fn nonce_for_chunk(&self, chunk_id: i64) -> [u8; 12] {
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&chunk_id.to_be_bytes());
+        nonce
+    }
+}
+
+/// A [`DataEncryptionKey`] wrapped (encrypted) under a [`KeyEncryptionKey`], safe to
+/// persist alongside a topic's properties.
+#[derive(Clone)]
+pub struct WrappedKey(pub Vec<u8>);
+
+/// Wraps and unwraps per-topic [`DataEncryptionKey`]s. Implemented once per deployment's
+/// key-management backend (a local master key, a cloud KMS, ...).
+pub trait KeyEncryptionKey: Send + Sync {
+    fn wrap(&self, dek: &DataEncryptionKey) -> Result<WrappedKey, CryptoError>;
+    fn unwrap(&self, wrapped: &WrappedKey) -> Result<DataEncryptionKey, CryptoError>;
+}
+
+/// Errors produced while generating, wrapping, unwrapping, or using a
+/// [`DataEncryptionKey`].
+#[derive(Debug, thiserror::Error)]
+pub enum CryptoError {
+    #[error("failed to wrap data encryption key :: {0}")]
+    WrapFailed(String),
+    #[error("failed to unwrap data encryption key :: {0}")]
+    UnwrapFailed(String),
+    #[error("chunk encryption failed :: {0}")]
+    EncryptFailed(String),
+    #[error("chunk decryption failed :: {0}")]
+    DecryptFailed(String),
+}
@@ -103,6 +103,13 @@ pub struct TextualStats {
     pub max: Option<String>,
 
     pub has_null: bool,
+
+    /// Approximate distinct-value sketch, used to decide whether this column is a good
+    /// candidate for dictionary encoding on write. See [`Self::is_low_cardinality`].
+    distinct_estimator: HyperLogLog,
+    /// Total number of values passed to [`Self::eval`] (including nulls), i.e. the
+    /// denominator for the cardinality ratio `distinct_count_estimate() / row_count`.
+    row_count: u64,
 }
 
 impl Default for TextualStats {
@@ -118,12 +125,17 @@ impl TextualStats {
             max: None,
 
             has_null: false,
+
+            distinct_estimator: HyperLogLog::new(),
+            row_count: 0,
         }
     }
 
     /// Evaluates a new text value and updates the column statistics.
     /// If the provided value is [`None`], it is condered a null value.
     pub fn eval(&mut self, val: &Option<&str>) {
+        self.row_count += 1;
+
         if let Some(val) = val {
             let val = *val;
             match &self.min {
@@ -135,11 +147,34 @@ impl TextualStats {
                 Some(current_max) if current_max.as_str() >= val => {}
                 _ => self.max = Some(val.to_owned()),
             }
+
+            self.distinct_estimator.insert(val);
         } else {
             self.has_null = true;
         }
     }
 
+    /// Returns the estimated number of distinct non-null values seen so far.
+    pub fn distinct_count_estimate(&self) -> f64 {
+        self.distinct_estimator.estimate()
+    }
+
+    /// Whether the ratio of estimated distinct values to total rows seen falls at or below
+    /// `threshold`, i.e. whether this column is a good candidate for dictionary encoding.
+    pub fn is_low_cardinality(&self, threshold: f64) -> bool {
+        self.row_count > 0 && self.distinct_count_estimate() / self.row_count as f64 <= threshold
+    }
+
+    /// Folds in another column's distinct-value sketch and row count, e.g. when combining
+    /// per-chunk stats into a topic-wide total. Unlike [`Self::merge`], which only has the
+    /// other side's precomputed min/max/has_null, this requires the other side's own
+    /// [`HyperLogLog`] sketch so the registers can be combined register-wise rather than
+    /// just summed (summing would double-count values shared by both sides).
+    pub fn merge_distinct_estimator(&mut self, other_sketch: &HyperLogLog, other_row_count: u64) {
+        self.distinct_estimator.merge(other_sketch);
+        self.row_count += other_row_count;
+    }
+
     /// Consumes the stats and returns owned strings for min and max.
     pub fn into_owned(self) -> (String, String, bool) {
         (
@@ -168,6 +203,90 @@ impl TextualStats {
     }
 }
 
+/// Number of hash bits used to pick a register, giving `2^14` registers, i.e. a
+/// standard-deviation error of roughly `1.04 / sqrt(16384)` (~0.8%) on the distinct-count
+/// estimate.
+const HLL_PRECISION: u32 = 14;
+const HLL_NUM_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// A HyperLogLog sketch used to approximate the number of distinct values a column has seen,
+/// without storing the values themselves. See [`TextualStats::is_low_cardinality`] for why:
+/// it drives the decision to dictionary-encode a column on write.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0u8; HLL_NUM_REGISTERS],
+        }
+    }
+
+    /// Hashes `value` to a 64-bit digest, using the top [`HLL_PRECISION`] bits to pick a
+    /// register and the position of the first set bit among the rest as that register's rank.
+    pub fn insert(&mut self, value: &str) {
+        let hash = Self::hash(value);
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        let remaining = hash << HLL_PRECISION;
+        let rank = 1 + remaining.leading_zeros() as u8;
+
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn hash(value: &str) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Combines `other`'s registers into `self`'s, register-wise max, so the result estimates
+    /// the distinct count over the union of both sketches' inputs.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (r, o) in self.registers.iter_mut().zip(other.registers.iter()) {
+            if *o > *r {
+                *r = *o;
+            }
+        }
+    }
+
+    /// Estimates the number of distinct values inserted so far, using the standard HLL
+    /// harmonic-mean estimator with the small-range (linear counting) and large-range bias
+    /// corrections from the original algorithm.
+    pub fn estimate(&self) -> f64 {
+        let m = HLL_NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&rank| 2f64.powi(-(rank as i32)))
+            .sum();
+        let raw_estimate = alpha * m * m / sum;
+
+        if raw_estimate <= 2.5 * m {
+            let zero_registers = self.registers.iter().filter(|&&rank| rank == 0).count();
+            if zero_registers > 0 {
+                return m * (m / zero_registers as f64).ln();
+            }
+        } else if raw_estimate > (1u64 << 32) as f64 / 30.0 {
+            return -(2f64.powi(32)) * (1.0 - raw_estimate / 2f64.powi(32)).ln();
+        }
+
+        raw_estimate
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +325,52 @@ mod tests {
         assert!(stats.has_null);
     }
 
+    #[test]
+    fn text_stats_is_low_cardinality_for_repeated_values() {
+        let mut stats = TextualStats::new();
+        for _ in 0..1000 {
+            stats.eval(&Some("enabled"));
+        }
+        for _ in 0..1000 {
+            stats.eval(&Some("disabled"));
+        }
+
+        assert!(stats.distinct_count_estimate() < 10.0);
+        assert!(stats.is_low_cardinality(0.5));
+    }
+
+    #[test]
+    fn text_stats_is_not_low_cardinality_for_unique_values() {
+        let mut stats = TextualStats::new();
+        for i in 0..2000 {
+            stats.eval(&Some(&i.to_string()));
+        }
+
+        assert!(!stats.is_low_cardinality(0.5));
+    }
+
+    #[test]
+    fn hyperloglog_merge_matches_feeding_both_inputs_into_one_sketch() {
+        let mut combined = HyperLogLog::new();
+        let mut left = HyperLogLog::new();
+        let mut right = HyperLogLog::new();
+
+        for i in 0..500 {
+            let value = format!("left-{i}");
+            combined.insert(&value);
+            left.insert(&value);
+        }
+        for i in 0..500 {
+            let value = format!("right-{i}");
+            combined.insert(&value);
+            right.insert(&value);
+        }
+
+        left.merge(&right);
+
+        assert_eq!(left, combined);
+    }
+
     #[test]
     fn text_stats_merge_with_empty_string() {
         let mut stats = TextualStats::new();
@@ -0,0 +1,112 @@
+//! Progress tracking for long-running, resumable operations (e.g. topic consolidation).
+//!
+//! A [`JobReport`] is the durable, queryable record of one such operation: it is persisted
+//! through a repo facade (mirroring [`super::TopicManifest`]'s "generated during topic
+//! consolidation" role) so an operator can see progress, and so a crashed process can resume
+//! the job by re-enqueuing only the tasks its last persisted report says are still incomplete.
+
+/// What kind of long-running operation a [`JobReport`] tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobKind {
+    /// Merges small segment files overlapping a timestamp range into fewer, larger files.
+    TopicConsolidation,
+}
+
+/// The lifecycle state of a job. `Running` is the only non-terminal state: a process
+/// restarting after a crash re-enqueues the incomplete tasks of every job found in this
+/// state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Running,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+impl JobState {
+    /// Whether a job in this state is done being scheduled, i.e. safe to leave alone on
+    /// crash-resume instead of re-enqueuing its tasks.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, JobState::Running)
+    }
+}
+
+/// The persisted progress record of one job: how many of its `total_tasks` independent tasks
+/// (e.g. per-chunk stats aggregation, manifest timestamp computation) have completed, and its
+/// terminal outcome once it reaches one.
+#[derive(Debug, Clone)]
+pub struct JobReport {
+    pub id: String,
+    pub kind: JobKind,
+    pub state: JobState,
+    pub completed_tasks: u32,
+    pub total_tasks: u32,
+    /// Unix epoch milliseconds the job was first created.
+    pub started_at: i64,
+    /// Set when `state` is [`JobState::Failed`].
+    pub error: Option<String>,
+}
+
+impl JobReport {
+    pub fn new(id: impl Into<String>, kind: JobKind, total_tasks: u32, started_at: i64) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            state: JobState::Running,
+            completed_tasks: 0,
+            total_tasks,
+            started_at,
+            error: None,
+        }
+    }
+
+    /// Whether every task has reported completion. Does not itself transition `state`; the
+    /// caller still records the terminal state explicitly once a job's tasks are all done
+    /// (or cancelled, or one has failed).
+    pub fn is_done(&self) -> bool {
+        self.completed_tasks >= self.total_tasks
+    }
+
+    /// Fraction of tasks completed so far, `0.0` for a job with no tasks.
+    pub fn progress_ratio(&self) -> f64 {
+        if self.total_tasks == 0 {
+            0.0
+        } else {
+            self.completed_tasks as f64 / self.total_tasks as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_job_report_starts_running_with_no_completed_tasks() {
+        let report = JobReport::new("job-1", JobKind::TopicConsolidation, 4, 0);
+
+        assert_eq!(report.state, JobState::Running);
+        assert_eq!(report.completed_tasks, 0);
+        assert!(!report.is_done());
+        assert_eq!(report.progress_ratio(), 0.0);
+    }
+
+    #[test]
+    fn is_done_once_every_task_has_completed() {
+        let mut report = JobReport::new("job-1", JobKind::TopicConsolidation, 2, 0);
+        report.completed_tasks = 1;
+        assert!(!report.is_done());
+
+        report.completed_tasks = 2;
+        assert!(report.is_done());
+        assert_eq!(report.progress_ratio(), 1.0);
+    }
+
+    #[test]
+    fn only_running_is_non_terminal() {
+        assert!(!JobState::Running.is_terminal());
+        assert!(JobState::Completed.is_terminal());
+        assert!(JobState::Cancelled.is_terminal());
+        assert!(JobState::Failed.is_terminal());
+    }
+}
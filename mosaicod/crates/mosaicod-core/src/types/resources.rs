@@ -21,6 +21,8 @@ pub enum ResourceType {
 pub enum ResourceError {
     #[error("error encoding resource to url :: {0}")]
     UrlError(#[from] url::ParseError),
+    #[error("resource locator `{0}` decodes ambiguously")]
+    AmbiguousName(String),
 }
 
 pub trait Resource: std::fmt::Display + Send + Sync {
@@ -95,6 +97,32 @@ impl TopicResourceLocator {
     pub fn path_manifest(&self) -> path::PathBuf {
         path::Path::new(self.name()).join("manifest.json")
     }
+
+    /// Return the full path of the topic's Iceberg table metadata, kept alongside the
+    /// manifest so the Iceberg catalog view stays colocated with the rest of a topic's
+    /// metadata rather than in its own top-level layout.
+    pub fn path_iceberg_metadata(&self) -> path::PathBuf {
+        path::Path::new(self.name()).join("iceberg").join("metadata.json")
+    }
+
+    /// Returns the path for a file produced by a compaction run.
+    ///
+    /// Compacted output is kept under its own `compacted/` sub-directory, tagged with
+    /// the run it came from, so a compaction run can never collide with the regular
+    /// `data-NNNNN` write-path filenames.
+    pub fn path_compacted(
+        &self,
+        run_id: u128,
+        chunk_number: usize,
+        extension: &dyn traits::AsExtension,
+    ) -> path::PathBuf {
+        let filename = format!("compacted-{:020}-{:05}", run_id, chunk_number);
+        let mut path = path::Path::new(self.name()).join("compacted").join(filename);
+
+        path.set_extension(extension.as_extension());
+
+        path
+    }
 }
 
 impl Resource for TopicResourceLocator {
@@ -113,7 +141,7 @@ where
 {
     fn from(value: T) -> Self {
         Self {
-            locator: sanitize_name(&value.as_ref().to_string_lossy()),
+            locator: encode_name(&normalize_name(&value.as_ref().to_string_lossy())),
             ..Default::default()
         }
     }
@@ -121,10 +149,12 @@ where
 
 impl std::fmt::Display for TopicResourceLocator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = decode_name(&self.locator).unwrap_or_else(|_| self.locator.clone());
+
         if let Some(ts) = &self.timestamp_range {
-            write!(f, "[topic|{}|{}]", self.locator, ts)
+            write!(f, "[topic|{}|{}]", name, ts)
         } else {
-            write!(f, "[topic|{}]", self.locator)
+            write!(f, "[topic|{}]", name)
         }
     }
 }
@@ -165,6 +195,10 @@ pub struct TopicChunksStats {
 pub struct TopicProperties {
     pub serialization_format: Format,
     pub ontology_tag: String,
+    /// Whether this topic's chunk data files are encrypted at rest under a per-topic DEK
+    /// (see `mosaicod_core::crypto`). Defaults to `false`, so topics created before
+    /// encryption support was added keep reading and writing plaintext chunks unchanged.
+    pub encrypted: bool,
 }
 
 impl TopicProperties {
@@ -172,8 +206,16 @@ impl TopicProperties {
         Self {
             serialization_format,
             ontology_tag,
+            encrypted: false,
         }
     }
+
+    /// Opts this topic into transparent chunk encryption. The topic's DEK is generated on
+    /// its first write; existing plaintext chunks, if any, are left untouched.
+    pub fn with_encryption_enabled(mut self) -> Self {
+        self.encrypted = true;
+        self
+    }
 }
 
 /// Represents system-level metadata and statistical information for a specific topic.
@@ -252,13 +294,14 @@ where
     T: AsRef<path::Path>,
 {
     fn from(value: T) -> Self {
-        Self(sanitize_name(&value.as_ref().to_string_lossy()))
+        Self(encode_name(&normalize_name(&value.as_ref().to_string_lossy())))
     }
 }
 
 impl std::fmt::Display for SequenceResourceLocator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[sequence|{}]", self.0)
+        let name = decode_name(&self.0).unwrap_or_else(|_| self.0.clone());
+        write!(f, "[sequence|{}]", name)
     }
 }
 
@@ -399,33 +442,64 @@ impl From<SequenceTopicGroupSet> for Vec<SequenceTopicGroup> {
     }
 }
 
-/// Builds a sanitized resource name
-///
-/// Sanitized resource names have the following requirements:
-/// - remove any space
-/// - remove any leading `/`
-/// - any non-alphanumeric char as first element is removed
-/// - these symbol `! " ' * £ $ % &` are removed
-/// - any non-ASCII char is replaced with a `?`
-fn sanitize_name(name: &str) -> String {
-    let chars_to_replace = vec!["!", "\"", "'", "*", "£", "$", "%", "&", "."];
+/// Strips the purely structural parts of a raw resource name: surrounding whitespace and
+/// leading path separators. Unlike the byte-level encoding below, this is genuinely lossy
+/// (a name's leading `/`s and surrounding whitespace were never meaningful), so it's kept
+/// separate from [`encode_name`] rather than folded into it.
+fn normalize_name(name: &str) -> String {
+    name.trim().trim_start_matches('/').to_owned()
+}
+
+/// A byte is left unescaped by [`encode_name`] if it's alphanumeric, the `/` hierarchy
+/// separator, or one of the punctuation marks commonly used in resource names (`-`, `_`).
+/// Everything else, including all non-ASCII bytes, is percent-encoded.
+fn is_unreserved_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_')
+}
+
+/// Percent-encodes every byte of `name` not [`is_unreserved_byte`], producing a lossless,
+/// collision-free on-disk locator and `mosaico:` URL path: two distinct names can never
+/// encode to the same string, and [`decode_name`] always recovers the exact original.
+/// Replaces the old `sanitize_name`, which silently dropped punctuation and replaced every
+/// non-ASCII character with `?`.
+fn encode_name(name: &str) -> String {
+    let mut encoded = String::with_capacity(name.len());
+
+    for byte in name.as_bytes() {
+        if is_unreserved_byte(*byte) {
+            encoded.push(*byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
 
-    let mut sanitized: String = name
-        .replace(" ", "")
-        .trim()
-        .trim_start_matches('/')
-        .to_owned();
+    encoded
+}
 
-    sanitized = sanitized
-        .chars()
-        .map(|c| if c.is_ascii() { c } else { '?' })
-        .collect();
+/// Reverses [`encode_name`]. Fails with [`ResourceError::AmbiguousName`] if `encoded`
+/// contains a `%` not followed by two hex digits, or if un-escaping yields bytes that
+/// aren't valid UTF-8 — either means `encoded` wasn't actually produced by [`encode_name`].
+fn decode_name(encoded: &str) -> Result<String, ResourceError> {
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
 
-    for c in chars_to_replace {
-        sanitized = sanitized.replace(c, "");
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = encoded
+                .get(i + 1..i + 3)
+                .ok_or_else(|| ResourceError::AmbiguousName(encoded.to_owned()))?;
+            let byte = u8::from_str_radix(hex, 16)
+                .map_err(|_| ResourceError::AmbiguousName(encoded.to_owned()))?;
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
     }
 
-    sanitized
+    String::from_utf8(decoded).map_err(|_| ResourceError::AmbiguousName(encoded.to_owned()))
 }
 
 #[cfg(test)]
@@ -433,34 +507,54 @@ mod tests {
     use super::*;
 
     #[test]
-    fn resource_name() {
+    fn normalize_name_strips_only_structural_parts() {
         let target = "my/resource/name";
-        let san = sanitize_name("/my/resource/name");
-        assert_eq!(san, target);
-
-        let san = sanitize_name("    my/resource/name   ");
-        assert_eq!(san, target);
 
-        let san = sanitize_name("//my/resource/name");
-        assert_eq!(san, target);
-
-        let san = sanitize_name("/ /my/resource/name");
-        assert_eq!(san, target);
-
-        let san = sanitize_name("/ //my/resource/name");
-        assert_eq!(san, target);
-
-        let san = sanitize_name("/!\"my/resource/name");
-        assert_eq!(san, target);
+        assert_eq!(normalize_name("/my/resource/name"), target);
+        assert_eq!(normalize_name("    my/resource/name   "), target);
+        assert_eq!(normalize_name("//my/resource/name"), target);
+    }
 
-        let san = sanitize_name("/my/resource/na.me");
-        assert_eq!(san, target);
+    #[test]
+    fn encode_name_round_trips_unicode_and_previously_stripped_symbols() {
+        let names = [
+            "my/resource/name",
+            "my/resource/na.me",
+            "/!\"my/resource/name",
+            "èmy/resource/name",
+            "my/resourcè/name",
+            "100% done!",
+            "name with spaces & symbols: £$'*\"",
+            "こんにちは/世界",
+        ];
+
+        for name in names {
+            let encoded = encode_name(name);
+            assert_eq!(
+                decode_name(&encoded).expect("encode_name always produces a decodable string"),
+                name
+            );
+        }
+    }
 
-        let san = sanitize_name("/èmy/resource/name");
-        assert_eq!(san, "?my/resource/name");
+    #[test]
+    fn encode_name_never_collides_distinct_names() {
+        // Previously, `sanitize_name` dropped `.` and `!` entirely, so these two distinct
+        // names collided on the same sanitized string.
+        assert_ne!(encode_name("na.me"), encode_name("name"));
+        assert_ne!(encode_name("na!me"), encode_name("name"));
+    }
 
-        let san = sanitize_name("my/resourcè/name");
-        assert_eq!(san, "my/resourc?/name");
+    #[test]
+    fn decode_name_rejects_ambiguous_percent_sequences() {
+        assert!(matches!(
+            decode_name("my/resource%"),
+            Err(ResourceError::AmbiguousName(_))
+        ));
+        assert!(matches!(
+            decode_name("my/resource%zz"),
+            Err(ResourceError::AmbiguousName(_))
+        ));
     }
 
     #[test]
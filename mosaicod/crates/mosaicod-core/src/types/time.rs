@@ -84,33 +84,58 @@ impl From<Timestamp> for DateTime {
     }
 }
 
-/// Represents a closed interval of time where both the start and end are included.
+/// Whether an endpoint of a [`TimestampRange`] includes or excludes the boundary value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    /// The boundary value itself is part of the range.
+    Inclusive,
+    /// The boundary value itself is not part of the range.
+    Exclusive,
+}
+
+/// Represents an interval of time, closed by default, with each end independently
+/// markable as inclusive or exclusive of its boundary value.
 ///
-/// This struct defines a range $[start, end]$. A timestamp is considered
-/// contained within this range if $start \le t \le end$.
+/// This struct defines a range between `start` and `end`. A timestamp is considered
+/// contained within this range if it is greater than (or equal to, if `start_bound` is
+/// [`Bound::Inclusive`]) `start`, and less than (or equal to, if `end_bound` is
+/// [`Bound::Inclusive`]) `end`.
 #[derive(Clone)]
 pub struct TimestampRange {
     pub start: Timestamp,
     pub end: Timestamp,
+    pub start_bound: Bound,
+    pub end_bound: Bound,
 }
 
 impl TimestampRange {
     pub fn between(start: Timestamp, end: Timestamp) -> Self {
-        Self { start, end }
-    }
-
-    pub fn starting_at(start: Timestamp) -> Self {
         Self {
             start,
-            end: Timestamp::unbounded_pos(),
+            end,
+            start_bound: Bound::Inclusive,
+            end_bound: Bound::Inclusive,
         }
     }
 
+    pub fn starting_at(start: Timestamp) -> Self {
+        Self::between(start, Timestamp::unbounded_pos())
+    }
+
     pub fn ending_at(end: Timestamp) -> Self {
-        Self {
-            start: Timestamp::unbounded_neg(),
-            end,
-        }
+        Self::between(Timestamp::unbounded_neg(), end)
+    }
+
+    /// Returns a copy of this range with its start bound set to `bound`.
+    pub fn with_start_bound(mut self, bound: Bound) -> Self {
+        self.start_bound = bound;
+        self
+    }
+
+    /// Returns a copy of this range with its end bound set to `bound`.
+    pub fn with_end_bound(mut self, bound: Bound) -> Self {
+        self.end_bound = bound;
+        self
     }
 
     /// Returns true is both start and end are unbounded timestamps
@@ -118,15 +143,179 @@ impl TimestampRange {
         self.start.is_unbounded() && self.end.is_unbounded()
     }
 
-    /// Check if the timestamp range if empty (i.e. start >= end)
+    /// Check if the timestamp range if empty (i.e. start > end for a closed range, or
+    /// start >= end when either bound is exclusive)
     pub fn is_empty(&self) -> bool {
-        self.start >= self.end
+        match (self.start_bound, self.end_bound) {
+            (Bound::Inclusive, Bound::Inclusive) => self.start > self.end,
+            _ => self.start >= self.end,
+        }
+    }
+
+    /// Returns true if `t` falls within this range, respecting unbounded sentinels and
+    /// each end's inclusivity.
+    pub fn contains(&self, t: Timestamp) -> bool {
+        let above_start = if self.start.is_unbounded_neg() {
+            true
+        } else {
+            match self.start_bound {
+                Bound::Inclusive => t >= self.start,
+                Bound::Exclusive => t > self.start,
+            }
+        };
+
+        let below_end = if self.end.is_unbounded_pos() {
+            true
+        } else {
+            match self.end_bound {
+                Bound::Inclusive => t <= self.end,
+                Bound::Exclusive => t < self.end,
+            }
+        };
+
+        above_start && below_end
+    }
+
+    /// Returns the intersection of this range with `other`, taking the greater of the two
+    /// lower bounds and the lesser of the two upper bounds. When the two ranges agree on
+    /// a shared boundary value but disagree on its inclusivity, the stricter (exclusive)
+    /// bound wins. Returns `None` if the ranges don't overlap at all.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (start, start_bound) = tighter_lower_bound(
+            self.start,
+            self.start_bound,
+            other.start,
+            other.start_bound,
+        );
+        let (end, end_bound) = tighter_upper_bound(self.end, self.end_bound, other.end, other.end_bound);
+
+        let candidate = Self {
+            start,
+            end,
+            start_bound,
+            end_bound,
+        };
+
+        if candidate.is_empty() {
+            None
+        } else {
+            Some(candidate)
+        }
+    }
+
+    /// Returns the union of this range with `other`, taking the lesser of the two lower
+    /// bounds and the greater of the two upper bounds. Returns `None` if the ranges are
+    /// disjoint and not adjacent, since their union wouldn't be a single contiguous range.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.intersection(other).is_none() && !are_adjacent(self, other) {
+            return None;
+        }
+
+        let (start, start_bound) = looser_lower_bound(
+            self.start,
+            self.start_bound,
+            other.start,
+            other.start_bound,
+        );
+        let (end, end_bound) = looser_upper_bound(self.end, self.end_bound, other.end, other.end_bound);
+
+        Some(Self {
+            start,
+            end,
+            start_bound,
+            end_bound,
+        })
+    }
+}
+
+/// Two ranges are adjacent if one's end equals the other's start and at least one of the
+/// two bounds at that shared value is inclusive, so no gap lies between them.
+fn are_adjacent(a: &TimestampRange, b: &TimestampRange) -> bool {
+    (a.end == b.start && (a.end_bound == Bound::Inclusive || b.start_bound == Bound::Inclusive))
+        || (b.end == a.start && (b.end_bound == Bound::Inclusive || a.start_bound == Bound::Inclusive))
+}
+
+fn tighter_lower_bound(
+    a: Timestamp,
+    a_bound: Bound,
+    b: Timestamp,
+    b_bound: Bound,
+) -> (Timestamp, Bound) {
+    match a.cmp(&b) {
+        std::cmp::Ordering::Greater => (a, a_bound),
+        std::cmp::Ordering::Less => (b, b_bound),
+        std::cmp::Ordering::Equal => (a, stricter(a_bound, b_bound)),
+    }
+}
+
+fn tighter_upper_bound(
+    a: Timestamp,
+    a_bound: Bound,
+    b: Timestamp,
+    b_bound: Bound,
+) -> (Timestamp, Bound) {
+    match a.cmp(&b) {
+        std::cmp::Ordering::Less => (a, a_bound),
+        std::cmp::Ordering::Greater => (b, b_bound),
+        std::cmp::Ordering::Equal => (a, stricter(a_bound, b_bound)),
+    }
+}
+
+fn looser_lower_bound(
+    a: Timestamp,
+    a_bound: Bound,
+    b: Timestamp,
+    b_bound: Bound,
+) -> (Timestamp, Bound) {
+    match a.cmp(&b) {
+        std::cmp::Ordering::Less => (a, a_bound),
+        std::cmp::Ordering::Greater => (b, b_bound),
+        std::cmp::Ordering::Equal => (a, looser(a_bound, b_bound)),
+    }
+}
+
+fn looser_upper_bound(
+    a: Timestamp,
+    a_bound: Bound,
+    b: Timestamp,
+    b_bound: Bound,
+) -> (Timestamp, Bound) {
+    match a.cmp(&b) {
+        std::cmp::Ordering::Greater => (a, a_bound),
+        std::cmp::Ordering::Less => (b, b_bound),
+        std::cmp::Ordering::Equal => (a, looser(a_bound, b_bound)),
+    }
+}
+
+/// A shared boundary is exclusive if either side says so.
+fn stricter(a: Bound, b: Bound) -> Bound {
+    if a == Bound::Exclusive || b == Bound::Exclusive {
+        Bound::Exclusive
+    } else {
+        Bound::Inclusive
+    }
+}
+
+/// A shared boundary is inclusive if either side says so.
+fn looser(a: Bound, b: Bound) -> Bound {
+    if a == Bound::Inclusive || b == Bound::Inclusive {
+        Bound::Inclusive
+    } else {
+        Bound::Exclusive
     }
 }
 
 impl std::fmt::Display for TimestampRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} -> {}", self.start, self.end)
+        let open = match self.start_bound {
+            Bound::Inclusive => "[",
+            Bound::Exclusive => "(",
+        };
+        let close = match self.end_bound {
+            Bound::Inclusive => "]",
+            Bound::Exclusive => ")",
+        };
+        write!(f, "{open}{} -> {}{close}", self.start, self.end)
     }
 }
 
@@ -222,4 +411,58 @@ mod tests {
             TimestampRange::between(Timestamp::unbounded_pos(), Timestamp::unbounded_neg());
         assert!(ts_empty.is_empty());
     }
+
+    #[test]
+    fn timestamp_range_contains_respects_bound_inclusivity() {
+        let closed = TimestampRange::between(1000.into(), 2000.into());
+        assert!(closed.contains(1000.into()));
+        assert!(closed.contains(2000.into()));
+        assert!(!closed.contains(2001.into()));
+
+        let half_open = closed
+            .clone()
+            .with_start_bound(Bound::Exclusive)
+            .with_end_bound(Bound::Exclusive);
+        assert!(!half_open.contains(1000.into()));
+        assert!(!half_open.contains(2000.into()));
+        assert!(half_open.contains(1500.into()));
+
+        let unbounded = TimestampRange::starting_at(1000.into());
+        assert!(unbounded.contains(i64::MAX.into()));
+        assert!(!unbounded.contains(999.into()));
+    }
+
+    #[test]
+    fn timestamp_range_intersection() {
+        let a = TimestampRange::between(1000.into(), 2000.into());
+        let b = TimestampRange::between(1500.into(), 2500.into());
+        let overlap = a.intersection(&b).expect("ranges overlap");
+        assert_eq!(overlap.start, 1500.into());
+        assert_eq!(overlap.end, 2000.into());
+
+        let disjoint = TimestampRange::between(3000.into(), 4000.into());
+        assert!(a.intersection(&disjoint).is_none());
+
+        // Sharing an exclusive boundary produces an empty intersection.
+        let exclusive_at_2000 =
+            TimestampRange::between(2000.into(), 3000.into()).with_start_bound(Bound::Exclusive);
+        assert!(a.intersection(&exclusive_at_2000).is_none());
+    }
+
+    #[test]
+    fn timestamp_range_union() {
+        let a = TimestampRange::between(1000.into(), 2000.into());
+        let b = TimestampRange::between(1500.into(), 2500.into());
+        let merged = a.union(&b).expect("ranges overlap");
+        assert_eq!(merged.start, 1000.into());
+        assert_eq!(merged.end, 2500.into());
+
+        let adjacent = TimestampRange::between(2000.into(), 3000.into());
+        let merged = a.union(&adjacent).expect("ranges are adjacent");
+        assert_eq!(merged.start, 1000.into());
+        assert_eq!(merged.end, 3000.into());
+
+        let far = TimestampRange::between(5000.into(), 6000.into());
+        assert!(a.union(&far).is_none());
+    }
 }
@@ -0,0 +1,36 @@
+//! Minimal Iceberg REST catalog responses.
+//!
+//! This intentionally implements just enough of the
+//! [Iceberg REST catalog spec](https://iceberg.apache.org/rest-catalog-spec/) for a read
+//! path: serializing a [`TableMetadata`] into the `LoadTableResult` payload the
+//! `GET /v1/namespaces/{namespace}/tables/{table}` endpoint returns. Wiring this into an
+//! actual HTTP server is left to the embedding service, mosaico has no HTTP framework
+//! dependency today.
+
+use crate::metadata::TableMetadata;
+use serde::Serialize;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CatalogError {
+    #[error("table metadata serialization error :: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Body of the Iceberg REST catalog `LoadTableResult` response.
+#[derive(Debug, Serialize)]
+struct LoadTableResult<'a> {
+    #[serde(rename = "metadata-location")]
+    metadata_location: String,
+    metadata: &'a TableMetadata,
+}
+
+/// Builds the JSON body an Iceberg REST catalog would return for
+/// `GET /v1/namespaces/{namespace}/tables/{table}`.
+pub fn load_table(metadata: &TableMetadata) -> Result<Vec<u8>, CatalogError> {
+    let result = LoadTableResult {
+        metadata_location: format!("{}/metadata/metadata.json", metadata.location),
+        metadata,
+    };
+
+    Ok(serde_json::to_vec(&result)?)
+}
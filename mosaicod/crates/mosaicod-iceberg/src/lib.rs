@@ -0,0 +1,17 @@
+//! Iceberg-compatible catalog view over mosaico topics.
+//!
+//! Each topic is effectively a timestamp-ordered columnar dataset persisted to an
+//! object store, so this crate re-describes a topic's committed state as an
+//! [Iceberg](https://iceberg.apache.org) table: a schema derived from the topic's
+//! [`Format`](mosaicod_core::types::Format), a snapshot list, and manifest files listing
+//! the topic's data files with their per-file
+//! [`TimestampRange`](mosaicod_core::types::TimestampRange) as partition bounds. External
+//! query engines (DataFusion, Trino, Spark) can then read mosaico-managed data through
+//! the Iceberg ecosystem without going through Flight, while the facade layer keeps
+//! ownership of the write path.
+
+mod catalog;
+mod metadata;
+
+pub use catalog::{CatalogError, load_table};
+pub use metadata::{DataFile, ManifestFile, Schema, Snapshot, TableMetadata};
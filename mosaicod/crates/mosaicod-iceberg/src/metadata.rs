@@ -0,0 +1,165 @@
+//! Iceberg table metadata construction.
+//!
+//! Builds the subset of the [Iceberg table spec](https://iceberg.apache.org/spec/)
+//! mosaico needs to expose a topic as a queryable table: a schema, a manifest file per
+//! snapshot, and a snapshot list threading those manifests together.
+
+use arrow::datatypes::{DataType, SchemaRef};
+use mosaicod_core::types::TimestampRange;
+use serde::{Deserialize, Serialize};
+
+/// An Iceberg field, derived from one column of the topic's Arrow schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Field {
+    pub id: usize,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub required: bool,
+}
+
+/// An Iceberg table schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Schema {
+    #[serde(rename = "schema-id")]
+    pub schema_id: i64,
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    /// Derives an Iceberg schema from a topic's Arrow schema.
+    pub fn from_arrow(schema_id: i64, schema: &SchemaRef) -> Self {
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(id, field)| Field {
+                id,
+                name: field.name().clone(),
+                ty: arrow_type_to_iceberg(field.data_type()),
+                required: !field.is_nullable(),
+            })
+            .collect();
+
+        Self { schema_id, fields }
+    }
+}
+
+/// Maps an Arrow data type to its closest Iceberg primitive type name.
+///
+/// Types without a direct Iceberg equivalent fall back to `"string"`, matching how
+/// ontology-model columns without a narrower statistics representation are already
+/// treated as [`Stats::Unsupported`](mosaicod_core::types::Stats::Unsupported) elsewhere.
+fn arrow_type_to_iceberg(ty: &DataType) -> String {
+    match ty {
+        DataType::Boolean => "boolean".to_owned(),
+        DataType::Int32 => "int".to_owned(),
+        DataType::Int64 => "long".to_owned(),
+        DataType::Float32 => "float".to_owned(),
+        DataType::Float64 => "double".to_owned(),
+        DataType::Utf8 | DataType::LargeUtf8 => "string".to_owned(),
+        DataType::Binary | DataType::LargeBinary => "binary".to_owned(),
+        DataType::Timestamp(_, _) => "timestamp".to_owned(),
+        _ => "string".to_owned(),
+    }
+}
+
+/// A single data file tracked by a manifest, with its timestamp coverage recorded as
+/// the lower/upper partition bounds Iceberg readers use for file pruning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataFile {
+    pub path: String,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub lower_bound: i64,
+    pub upper_bound: i64,
+}
+
+impl DataFile {
+    pub fn new(
+        path: impl Into<String>,
+        record_count: i64,
+        file_size_in_bytes: i64,
+        range: &TimestampRange,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            record_count,
+            file_size_in_bytes,
+            lower_bound: range.start.as_i64(),
+            upper_bound: range.end.as_i64(),
+        }
+    }
+}
+
+/// A manifest file: the list of data files added by one snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub path: String,
+    pub added_data_files: Vec<DataFile>,
+}
+
+/// A point-in-time snapshot of a table's file set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    #[serde(rename = "snapshot-id")]
+    pub snapshot_id: i64,
+    #[serde(rename = "timestamp-ms")]
+    pub timestamp_ms: i64,
+    #[serde(rename = "manifest-list")]
+    pub manifest_list: ManifestFile,
+}
+
+/// Iceberg table metadata for a single mosaico topic.
+///
+/// Every call to [`TableMetadata::with_new_snapshot`] records a new snapshot reflecting
+/// the topic's current file set, so the catalog tracks the same history of commits that
+/// topic create/delete/compact operations produce rather than just the current tip.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableMetadata {
+    #[serde(rename = "format-version")]
+    pub format_version: u8,
+    pub location: String,
+    #[serde(rename = "current-schema-id")]
+    pub current_schema_id: i64,
+    pub schemas: Vec<Schema>,
+    pub snapshots: Vec<Snapshot>,
+    #[serde(rename = "current-snapshot-id")]
+    pub current_snapshot_id: Option<i64>,
+}
+
+impl TableMetadata {
+    /// Creates table metadata with a single schema and no snapshots yet.
+    pub fn new(location: impl Into<String>, schema: &SchemaRef) -> Self {
+        Self {
+            format_version: 2,
+            location: location.into(),
+            current_schema_id: 0,
+            schemas: vec![Schema::from_arrow(0, schema)],
+            snapshots: Vec::new(),
+            current_snapshot_id: None,
+        }
+    }
+
+    /// Appends a new snapshot describing the table's current file set.
+    ///
+    /// `timestamp_ms` should be the wall-clock time of the commit (e.g. a topic
+    /// create/delete/compact operation) that produced this file set.
+    pub fn with_new_snapshot(mut self, data_files: Vec<DataFile>, timestamp_ms: i64) -> Self {
+        let snapshot_id = self.snapshots.len() as i64 + 1;
+
+        let manifest_list = ManifestFile {
+            path: format!("{}/metadata/snap-{}.avro", self.location, snapshot_id),
+            added_data_files: data_files,
+        };
+
+        self.snapshots.push(Snapshot {
+            snapshot_id,
+            timestamp_ms,
+            manifest_list,
+        });
+        self.current_snapshot_id = Some(snapshot_id);
+
+        self
+    }
+}
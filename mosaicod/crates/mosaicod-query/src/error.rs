@@ -22,6 +22,9 @@ pub enum Error {
 
     #[error("store error :: {0}")]
     StoreError(#[from] mosaicod_store::Error),
+
+    #[error("parquet writer error :: {0}")]
+    WriterError(#[from] mosaicod_rw::Error),
 }
 
 impl Error {
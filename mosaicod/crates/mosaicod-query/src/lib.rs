@@ -9,5 +9,14 @@ pub use builder::*;
 mod timeseries;
 pub use timeseries::*;
 
+mod manifest;
+pub use manifest::*;
+
+mod rollup;
+pub use rollup::*;
+
 mod error;
 pub use error::*;
+
+mod text;
+pub use text::*;
@@ -0,0 +1,144 @@
+//! [`matches_ontology_stats`]: the column-stats overlap test `list_flights`'s criteria
+//! filtering (see `mosaicod_server::endpoints::list_flights`) runs against a sequence's
+//! aggregated [`types::OntologyModelStats`] to skip entries that can't match.
+use super::{Op, OntologyField, Value};
+use mosaicod_core::types::{self, NumericStats, Stats, TextualStats};
+use std::collections::HashMap;
+
+/// Whether `stats` could satisfy every predicate in `predicates`. A column missing from
+/// `stats`, or without recorded min/max (e.g. an all-null column), is always assumed to
+/// pass rather than risk ruling out a real match.
+pub fn matches_ontology_stats(
+    stats: &types::OntologyModelStats,
+    predicates: &[(OntologyField, Op<Value>)],
+) -> bool {
+    !predicates.iter().any(|(field, op)| {
+        stats
+            .cols
+            .get(field.field())
+            .is_some_and(|s| excludes(op, s))
+    })
+}
+
+/// Whether `stats` proves that no row in the file can satisfy `op`.
+fn excludes(op: &Op<Value>, stats: &Stats) -> bool {
+    match stats {
+        Stats::Unsupported => false,
+        Stats::Numeric(s) => excludes_numeric(op, s),
+        Stats::Textual(s) => excludes_textual(op, s),
+    }
+}
+
+fn excludes_numeric(op: &Op<Value>, stats: &NumericStats) -> bool {
+    // No min/max on record (e.g. an all-null column still holds the placeholder
+    // min > max): can't prove exclusion.
+    if stats.min > stats.max {
+        return false;
+    }
+
+    match op {
+        Op::Eq(v) => as_f64(v).is_some_and(|v| v < stats.min || v > stats.max),
+        Op::Lt(v) => as_f64(v).is_some_and(|v| v <= stats.min),
+        Op::Leq(v) => as_f64(v).is_some_and(|v| v < stats.min),
+        Op::Gt(v) => as_f64(v).is_some_and(|v| v >= stats.max),
+        Op::Geq(v) => as_f64(v).is_some_and(|v| v > stats.max),
+        Op::Between(range) => match (as_f64(&range.min), as_f64(&range.max)) {
+            (Some(min), Some(max)) => stats.max < min || stats.min > max,
+            _ => false,
+        },
+        Op::In(items) => {
+            !items.is_empty()
+                && items.iter().all(|v| match as_f64(v) {
+                    Some(v) => v < stats.min || v > stats.max,
+                    None => false,
+                })
+        }
+        Op::Neq(_) | Op::Ex | Op::Nex | Op::Match(_) => false,
+    }
+}
+
+fn excludes_textual(op: &Op<Value>, stats: &TextualStats) -> bool {
+    let (min, max) = match (stats.min.as_deref(), stats.max.as_deref()) {
+        (Some(min), Some(max)) => (min, max),
+        // No min/max on record (e.g. an all-null column): can't prove exclusion.
+        _ => return false,
+    };
+
+    match op {
+        Op::Eq(v) => as_str(v).is_some_and(|v| v < min || v > max),
+        Op::Lt(v) => as_str(v).is_some_and(|v| v <= min),
+        Op::Leq(v) => as_str(v).is_some_and(|v| v < min),
+        Op::Gt(v) => as_str(v).is_some_and(|v| v >= max),
+        Op::Geq(v) => as_str(v).is_some_and(|v| v > max),
+        Op::Between(range) => match (as_str(&range.min), as_str(&range.max)) {
+            (Some(vmin), Some(vmax)) => max < vmin || min > vmax,
+            _ => false,
+        },
+        Op::In(items) => {
+            !items.is_empty()
+                && items.iter().all(|v| match as_str(v) {
+                    Some(v) => v < min || v > max,
+                    None => false,
+                })
+        }
+        Op::Neq(_) | Op::Ex | Op::Nex | Op::Match(_) => false,
+    }
+}
+
+fn as_f64(v: &Value) -> Option<f64> {
+    match v {
+        Value::Integer(v) => Some(*v as f64),
+        Value::Float(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn as_str(v: &Value) -> Option<&str> {
+    match v {
+        Value::Text(v) => Some(v.as_str()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_ontology_stats_excludes_when_predicate_out_of_range() {
+        let mut cols = HashMap::new();
+        cols.insert(
+            "value".to_owned(),
+            Stats::Numeric(NumericStats {
+                min: 0.0,
+                max: 10.0,
+                has_null: false,
+                has_nan: false,
+            }),
+        );
+        let stats = types::OntologyModelStats { cols };
+
+        let in_range = vec![(
+            OntologyField::try_new("value".to_owned()).unwrap(),
+            Op::Gt(Value::Integer(5)),
+        )];
+        let out_of_range = vec![(
+            OntologyField::try_new("value".to_owned()).unwrap(),
+            Op::Gt(Value::Integer(20)),
+        )];
+
+        assert!(matches_ontology_stats(&stats, &in_range));
+        assert!(!matches_ontology_stats(&stats, &out_of_range));
+    }
+
+    #[test]
+    fn matches_ontology_stats_passes_through_unknown_columns() {
+        let stats = types::OntologyModelStats {
+            cols: HashMap::new(),
+        };
+        let field = OntologyField::try_new("unknown".to_owned()).unwrap();
+        let predicates = vec![(field, Op::Eq(Value::Integer(1)))];
+
+        assert!(matches_ontology_stats(&stats, &predicates));
+    }
+}
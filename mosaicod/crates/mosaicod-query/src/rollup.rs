@@ -0,0 +1,69 @@
+//! Continuous downsampling "rollups": pre-aggregated resolutions of a raw timeseries, so a
+//! wide-range query can be served from a coarse bucketed view instead of scanning and sorting
+//! every raw row. See [`super::Timeseries::write_rollup`] for how a rollup is derived from raw
+//! data.
+
+/// An aggregate function computed per time bucket for one field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateFn {
+    Min,
+    Max,
+    Sum,
+    Count,
+}
+
+impl AggregateFn {
+    /// The column name an aggregate over `field` is written out under, e.g. `value__sum`.
+    /// Kept distinct per function so a field can carry more than one, notably [`Self::Sum`]
+    /// and [`Self::Count`] together: a downstream reader recomputes the mean exactly as
+    /// `sum / count` without re-scanning raw data, and re-aggregating this rollup into a
+    /// coarser one stays exact (sums and counts combine by addition; an average of averages
+    /// does not).
+    pub fn output_column(self, field: &str) -> String {
+        let suffix = match self {
+            AggregateFn::Min => "min",
+            AggregateFn::Max => "max",
+            AggregateFn::Sum => "sum",
+            AggregateFn::Count => "count",
+        };
+        format!("{field}__{suffix}")
+    }
+}
+
+/// One field's set of aggregates within a [`RollupSpec`].
+#[derive(Debug, Clone)]
+pub struct RollupAggregate {
+    pub field: String,
+    pub functions: Vec<AggregateFn>,
+}
+
+impl RollupAggregate {
+    pub fn new(field: impl Into<String>, functions: Vec<AggregateFn>) -> Self {
+        Self {
+            field: field.into(),
+            functions,
+        }
+    }
+}
+
+/// A single pre-aggregated resolution: rows are grouped into `bucket_width_ms`-wide buckets
+/// (`floor(timestamp / bucket_width_ms) * bucket_width_ms`) and each bucket reduced to one row
+/// per [`RollupAggregate`]. `name` identifies the rollup on disk, under the topic's
+/// `rollups/<name>` subpath (see `Timeseries::write_rollup`).
+#[derive(Debug, Clone)]
+pub struct RollupSpec {
+    pub name: String,
+    pub bucket_width_ms: i64,
+    pub aggregates: Vec<RollupAggregate>,
+}
+
+impl RollupSpec {
+    pub fn new(name: impl Into<String>, bucket_width_ms: i64, aggregates: Vec<RollupAggregate>) -> Self {
+        Self {
+            name: name.into(),
+            bucket_width_ms,
+            aggregates,
+        }
+    }
+}
+
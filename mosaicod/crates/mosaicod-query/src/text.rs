@@ -0,0 +1,475 @@
+//! Human-writable text query language that compiles to the same filter IR the JSON
+//! deserialization path produces (see [`Error::DeserializationError`]), e.g.:
+//!
+//! ```text
+//! between 1000 and 2000 and topic.region = "eu" and not value < 10
+//! ```
+//!
+//! A tokenizer (["lex"](lex)) produces identifiers, string/number literals, comparison
+//! operators (`=`, `!=`, `<`, `<=`, `>`, `>=`, `~` for [`Op::Match`]), the boolean connectives
+//! `and`/`or`/`not`, parentheses, and the `between ... and ...` time clause. A recursive-descent
+//! parser then builds an AST with the standard `or` < `and` < `not` < comparison precedence.
+//!
+//! The current filter IR ([`OntologyExprGroup`]) only represents a flat conjunction of
+//! comparisons, so `or` is parsed (to keep precedence handling uniform) but rejected once the
+//! AST is lowered, the same way an unsupported JSON operator is rejected with
+//! [`Error::unsupported_op`]. `not` is only liftable into the IR for the invertible comparison
+//! operators (`=`, `!=`, `<`, `<=`, `>`, `>=`); negating `~` or a composite expression is
+//! likewise rejected.
+use super::{Error, OntologyExprGroup, OntologyField, Op, Value};
+use mosaicod_core::types::TimestampRange;
+
+/// The result of compiling a text query: an optional time bound, lowered straight to
+/// [`TimestampRange`], alongside the remaining predicates lowered to the same
+/// [`OntologyExprGroup`] the JSON path produces.
+#[derive(Debug, Default)]
+pub struct ParsedQuery {
+    pub ts_range: Option<TimestampRange>,
+    pub filter: Option<OntologyExprGroup<Value>>,
+}
+
+/// Parses `input` into a [`ParsedQuery`].
+pub fn parse(input: &str) -> Result<ParsedQuery, Error> {
+    let tokens = lex(input)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+    };
+
+    let query = parser.parse_query()?;
+    parser.expect_eof()?;
+    Ok(query)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Number(String),
+    Eq,
+    Neq,
+    Lt,
+    Leq,
+    Gt,
+    Geq,
+    Match,
+    And,
+    Or,
+    Not,
+    Between,
+    LParen,
+    RParen,
+}
+
+fn lex(input: &str) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '~' => {
+                tokens.push(Token::Match);
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Neq);
+                i += 2;
+            }
+            '<' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Leq);
+                i += 2;
+            }
+            '<' => {
+                tokens.push(Token::Lt);
+                i += 1;
+            }
+            '>' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Geq);
+                i += 2;
+            }
+            '>' => {
+                tokens.push(Token::Gt);
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::Eq);
+                i += 1;
+            }
+            '"' => {
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('"') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(&ch) => {
+                            value.push(ch);
+                            i += 1;
+                        }
+                        None => {
+                            return Err(Error::DeserializationError(
+                                "unterminated string literal".to_owned(),
+                            ));
+                        }
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            c if c.is_ascii_digit() || (c == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit)) => {
+                let start = i;
+                i += 1;
+                while chars
+                    .get(i)
+                    .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+                {
+                    i += 1;
+                }
+                tokens.push(Token::Number(chars[start..i].iter().collect()));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                i += 1;
+                while chars
+                    .get(i)
+                    .is_some_and(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.to_ascii_lowercase().as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    "between" => Token::Between,
+                    _ => Token::Ident(word),
+                });
+            }
+            other => {
+                return Err(Error::DeserializationError(format!(
+                    "unexpected character `{other}`"
+                )));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// AST produced by the recursive-descent parser, lowered to the flat [`OntologyExprGroup`] IR
+/// by [`Parser::lower`].
+enum Expr {
+    Or(Vec<Expr>),
+    And(Vec<Expr>),
+    Not(Box<Expr>),
+    Cmp(OntologyField, Op<Value>),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect_eof(&self) -> Result<(), Error> {
+        if self.pos == self.tokens.len() {
+            Ok(())
+        } else {
+            Err(Error::DeserializationError(format!(
+                "unexpected trailing token `{:?}`",
+                self.tokens[self.pos]
+            )))
+        }
+    }
+
+    /// `query := (time_clause ("and" or_expr)?) | or_expr`
+    fn parse_query(&mut self) -> Result<ParsedQuery, Error> {
+        if self.peek() == Some(&Token::Between) {
+            let ts_range = self.parse_time_clause()?;
+
+            let filter = if self.peek() == Some(&Token::And) {
+                self.advance();
+                Some(self.lower(self.parse_or()?)?)
+            } else {
+                None
+            };
+
+            return Ok(ParsedQuery {
+                ts_range: Some(ts_range),
+                filter,
+            });
+        }
+
+        let expr = self.parse_or()?;
+        Ok(ParsedQuery {
+            ts_range: None,
+            filter: Some(self.lower(expr)?),
+        })
+    }
+
+    /// `time_clause := "between" number "and" number`
+    fn parse_time_clause(&mut self) -> Result<TimestampRange, Error> {
+        self.advance(); // "between"
+        let lb = self.parse_timestamp()?;
+        self.expect(Token::And)?;
+        let ub = self.parse_timestamp()?;
+        Ok(TimestampRange::between(lb.into(), ub.into()))
+    }
+
+    fn parse_timestamp(&mut self) -> Result<i64, Error> {
+        match self.advance() {
+            Some(Token::Number(raw)) => raw.parse().map_err(|_| {
+                Error::DeserializationError(format!("invalid timestamp literal `{raw}`"))
+            }),
+            other => Err(Error::DeserializationError(format!(
+                "expected a timestamp literal, found {other:?}"
+            ))),
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), Error> {
+        match self.advance() {
+            Some(token) if token == expected => Ok(()),
+            other => Err(Error::DeserializationError(format!(
+                "expected `{expected:?}`, found {other:?}"
+            ))),
+        }
+    }
+
+    /// `or_expr := and_expr ("or" and_expr)*`
+    fn parse_or(&mut self) -> Result<Expr, Error> {
+        let mut terms = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.advance();
+            terms.push(self.parse_and()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.pop().expect("just pushed one term")
+        } else {
+            Expr::Or(terms)
+        })
+    }
+
+    /// `and_expr := not_expr ("and" not_expr)*`
+    fn parse_and(&mut self) -> Result<Expr, Error> {
+        let mut terms = vec![self.parse_not()?];
+        while self.peek() == Some(&Token::And) {
+            self.advance();
+            terms.push(self.parse_not()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.pop().expect("just pushed one term")
+        } else {
+            Expr::And(terms)
+        })
+    }
+
+    /// `not_expr := "not" not_expr | atom`
+    fn parse_not(&mut self) -> Result<Expr, Error> {
+        if self.peek() == Some(&Token::Not) {
+            self.advance();
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_atom()
+    }
+
+    /// `atom := "(" or_expr ")" | comparison`
+    fn parse_atom(&mut self) -> Result<Expr, Error> {
+        if self.peek() == Some(&Token::LParen) {
+            self.advance();
+            let expr = self.parse_or()?;
+            self.expect(Token::RParen)?;
+            return Ok(expr);
+        }
+        self.parse_comparison()
+    }
+
+    /// `comparison := ident comparison_op value`
+    fn parse_comparison(&mut self) -> Result<Expr, Error> {
+        let field = match self.advance() {
+            Some(Token::Ident(name)) => name,
+            other => {
+                return Err(Error::DeserializationError(format!(
+                    "expected a field name, found {other:?}"
+                )));
+            }
+        };
+        let field = OntologyField::try_new(field)?;
+
+        let build: fn(Value) -> Op<Value> = match self.advance() {
+            Some(Token::Eq) => Op::Eq,
+            Some(Token::Neq) => Op::Neq,
+            Some(Token::Lt) => Op::Lt,
+            Some(Token::Leq) => Op::Leq,
+            Some(Token::Gt) => Op::Gt,
+            Some(Token::Geq) => Op::Geq,
+            Some(Token::Match) => Op::Match,
+            other => {
+                return Err(Error::DeserializationError(format!(
+                    "expected a comparison operator, found {other:?}"
+                )));
+            }
+        };
+
+        let value = self.parse_value()?;
+        Ok(Expr::Cmp(field, build(value)))
+    }
+
+    fn parse_value(&mut self) -> Result<Value, Error> {
+        match self.advance() {
+            Some(Token::Str(s)) => Ok(Value::Text(s)),
+            Some(Token::Ident(word)) if word.eq_ignore_ascii_case("true") => {
+                Ok(Value::Boolean(true))
+            }
+            Some(Token::Ident(word)) if word.eq_ignore_ascii_case("false") => {
+                Ok(Value::Boolean(false))
+            }
+            Some(Token::Number(raw)) => {
+                if let Ok(v) = raw.parse::<i64>() {
+                    Ok(Value::Integer(v))
+                } else {
+                    raw.parse::<f64>().map(Value::Float).map_err(|_| {
+                        Error::DeserializationError(format!("invalid number literal `{raw}`"))
+                    })
+                }
+            }
+            other => Err(Error::DeserializationError(format!(
+                "expected a value literal, found {other:?}"
+            ))),
+        }
+    }
+
+    /// Lowers `expr` to the flat [`OntologyExprGroup`] conjunction. `or` is always rejected;
+    /// `not` is only liftable over an invertible comparison operator.
+    fn lower(&self, expr: Expr) -> Result<OntologyExprGroup<Value>, Error> {
+        Ok(OntologyExprGroup::new(self.lower_terms(expr)?))
+    }
+
+    fn lower_terms(&self, expr: Expr) -> Result<Vec<(OntologyField, Op<Value>)>, Error> {
+        match expr {
+            Expr::Cmp(field, op) => Ok(vec![(field, op)]),
+            Expr::And(terms) => {
+                let mut flat = Vec::new();
+                for term in terms {
+                    flat.extend(self.lower_terms(term)?);
+                }
+                Ok(flat)
+            }
+            Expr::Or(_) => Err(Error::unsupported_op("or".to_owned())),
+            Expr::Not(inner) => match *inner {
+                Expr::Cmp(field, op) => Ok(vec![(field, negate(op)?)]),
+                _ => Err(Error::unsupported_op("not".to_owned())),
+            },
+        }
+    }
+}
+
+/// Negates a comparison operator the way De Morgan's law would, for the operators that have
+/// an exact complement. `~` ([`Op::Match`]), `Between`, and `In` have no single-operator
+/// complement in this IR, so negating them is rejected instead of silently approximated.
+fn negate(op: Op<Value>) -> Result<Op<Value>, Error> {
+    match op {
+        Op::Eq(v) => Ok(Op::Neq(v)),
+        Op::Neq(v) => Ok(Op::Eq(v)),
+        Op::Lt(v) => Ok(Op::Geq(v)),
+        Op::Leq(v) => Ok(Op::Gt(v)),
+        Op::Gt(v) => Ok(Op::Leq(v)),
+        Op::Geq(v) => Ok(Op::Lt(v)),
+        _ => Err(Error::unsupported_op("not".to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn predicates(filter: OntologyExprGroup<Value>) -> Vec<(OntologyField, Op<Value>)> {
+        filter.into_iter().map(|expr| expr.into_parts()).collect()
+    }
+
+    #[test]
+    fn parses_a_single_comparison() {
+        let query = parse(r#"topic.region = "eu""#).unwrap();
+        let predicates = predicates(query.filter.unwrap());
+
+        assert_eq!(predicates.len(), 1);
+        assert_eq!(predicates[0].0.field(), "topic.region");
+        assert!(matches!(&predicates[0].1, Op::Eq(Value::Text(v)) if v == "eu"));
+    }
+
+    #[test]
+    fn parses_and_combined_comparisons() {
+        let query = parse("value >= 10 and site != \"test\"").unwrap();
+        let predicates = predicates(query.filter.unwrap());
+
+        assert_eq!(predicates.len(), 2);
+        assert!(matches!(predicates[0].1, Op::Geq(Value::Integer(10))));
+        assert!(matches!(&predicates[1].1, Op::Neq(Value::Text(v)) if v == "test"));
+    }
+
+    #[test]
+    fn parses_parenthesized_and_not() {
+        let query = parse("not (value < 10)").unwrap();
+        let predicates = predicates(query.filter.unwrap());
+
+        assert_eq!(predicates.len(), 1);
+        assert!(matches!(predicates[0].1, Op::Geq(Value::Integer(10))));
+    }
+
+    #[test]
+    fn parses_contains_operator() {
+        let query = parse(r#"tag ~ "sensor""#).unwrap();
+        let predicates = predicates(query.filter.unwrap());
+
+        assert!(matches!(&predicates[0].1, Op::Match(Value::Text(v)) if v == "sensor"));
+    }
+
+    #[test]
+    fn parses_time_clause_combined_with_filter() {
+        let query = parse("between 1000 and 2000 and region = \"eu\"").unwrap();
+
+        let ts_range = query.ts_range.expect("time clause yields a timestamp range");
+        assert_eq!(ts_range.start, 1000.into());
+        assert_eq!(ts_range.end, 2000.into());
+        assert_eq!(predicates(query.filter.unwrap()).len(), 1);
+    }
+
+    #[test]
+    fn rejects_or_since_the_ir_has_no_disjunction() {
+        let err = parse("region = \"eu\" or region = \"us\"").unwrap_err();
+        assert!(matches!(err, Error::OpError { .. }));
+    }
+
+    #[test]
+    fn rejects_unsupported_field() {
+        let err = parse("not_a_valid.field! = 1").unwrap_err();
+        assert!(matches!(err, Error::DeserializationError(_)));
+    }
+}
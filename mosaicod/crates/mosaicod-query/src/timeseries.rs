@@ -4,20 +4,24 @@
 //!
 //! The engine integrates directly with the configured [`store::Store`] to resolve
 //! paths and access data sources like Parquet files efficiently.
-use super::{Error, OntologyExprGroup, OntologyField, Op, Value};
+use super::{AggregateFn, Error, OntologyExprGroup, OntologyField, Op, RollupSpec, Value};
+use arrow::array::RecordBatch;
 use arrow::datatypes::{Schema, SchemaRef};
 use datafusion::execution::SendableRecordBatchStream;
 use datafusion::execution::runtime_env::{RuntimeEnv, RuntimeEnvBuilder};
 use datafusion::functions::core::expr_ext::FieldAccessor;
-use datafusion::functions_aggregate::expr_fn::{max, min};
+use datafusion::functions_aggregate::expr_fn::{count, max, min, sum};
 use datafusion::prelude::*;
 use datafusion::scalar::ScalarValue;
 use log::trace;
+use mosaicod_core::traits::AsyncWriteToPath;
 use mosaicod_core::{params, types};
-use mosaicod_rw::ToParquetProperties;
+use mosaicod_rw::format::ParquetWriterConfig;
+use mosaicod_rw::metadata_cache::{ParquetMetadataCache, ParquetMetadataCacheConfig};
+use mosaicod_rw::{ToParquetProperties, Writer};
 use mosaicod_store as store;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 pub type TimeseriesRef = Arc<Timeseries>;
@@ -25,6 +29,11 @@ pub type TimeseriesRef = Arc<Timeseries>;
 pub struct Timeseries {
     runtime: Arc<RuntimeEnv>,
     store: Arc<store::Store>,
+    /// Process-wide cache of parsed Parquet footers. See
+    /// [`Timeseries::invalidate_chunk_metadata`] for the reindexing invalidation hook.
+    /// [`Timeseries::read`] does not consult it: each read loads chunk footers from the
+    /// store through DataFusion's own Parquet reader.
+    metadata_cache: Arc<ParquetMetadataCache>,
 }
 
 impl Timeseries {
@@ -35,12 +44,26 @@ impl Timeseries {
                 .build()?,
         );
 
+        let cfg = params::configurables();
+        let metadata_cache = ParquetMetadataCache::new(ParquetMetadataCacheConfig {
+            capacity: cfg.parquet_metadata_cache_capacity,
+            ttl: (cfg.parquet_metadata_cache_ttl_secs > 0)
+                .then(|| std::time::Duration::from_secs(cfg.parquet_metadata_cache_ttl_secs)),
+        });
+
         Ok(Timeseries {
             runtime,
             store: store.clone(),
+            metadata_cache,
         })
     }
 
+    /// Drops every cached footer for `path`. Call this after a chunk at `path` is rewritten
+    /// during reindexing, so subsequent reads don't serve a footer from its previous version.
+    pub fn invalidate_chunk_metadata(&self, path: &str) {
+        self.metadata_cache.invalidate_path(path);
+    }
+
     /// Read time-series data from a path.
     ///
     /// All files in the provided path will be included in the read.
@@ -94,6 +117,109 @@ impl Timeseries {
             .url_schema
             .join(&path.as_ref().to_string_lossy())?)
     }
+
+    /// Derives `rollup` from the raw data under `path` and writes it as its own Parquet file
+    /// under `path`'s `rollups/<rollup.name>` subpath (see [`Self::rollup_path`]), returning
+    /// the [`types::TopicManifestTimestamp`] covering the written buckets.
+    ///
+    /// Must be (re-)run whenever new raw data lands on `path`, e.g. from the ingestion path
+    /// that handles `DoPutCmd`, so the rollup stays in sync with the raw data it summarizes.
+    pub async fn write_rollup(
+        &self,
+        path: impl AsRef<Path>,
+        format: types::Format,
+        rollup: &RollupSpec,
+    ) -> Result<types::TopicManifestTimestamp, Error> {
+        let ts_col = params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP;
+
+        let raw = self.read(path.as_ref(), format.clone(), None).await?;
+        let rolled_up = raw.rolled_up(rollup)?;
+
+        let batches = rolled_up.data_frame.collect().await?;
+        let first_batch = batches.first().ok_or(Error::NotFound)?;
+        let last_batch = batches.last().ok_or(Error::NotFound)?;
+
+        let schema = first_batch.schema();
+        let ts_idx = schema
+            .index_of(ts_col)
+            .expect("rollup aggregation always aliases the bucket column to the timestamp column");
+
+        let bucket_min = ScalarValue::try_from_array(first_batch.column(ts_idx), 0)?;
+        let bucket_max =
+            ScalarValue::try_from_array(last_batch.column(ts_idx), last_batch.num_rows() - 1)?;
+
+        let ts_min = scalar_value_to_timestamp(bucket_min)
+            .ok_or_else(|| Error::bad_field(ts_col.to_owned()))?;
+        let bucket_max = scalar_value_to_timestamp(bucket_max)
+            .ok_or_else(|| Error::bad_field(ts_col.to_owned()))?;
+        // The last bucket covers [bucket_max, bucket_max + bucket_width), so its upper bound
+        // is the end of that interval rather than its start.
+        let ts_max: types::Timestamp = (bucket_max.as_i64() + rollup.bucket_width_ms - 1).into();
+
+        let stats = textual_dictionary_stats(&schema, &batches);
+        let mut writer = Writer::new_with_stats(&schema, format, &ParquetWriterConfig::default(), &stats)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        let bytes = writer.close()?;
+
+        let dest = Self::rollup_path(path.as_ref(), &rollup.name);
+        self.store
+            .write_to_path(&dest.to_string_lossy(), bytes)
+            .await?;
+
+        Ok(types::TopicManifestTimestamp::new(types::TimestampRange::between(
+            ts_min, ts_max,
+        )))
+    }
+
+    /// Where a rollup named `rollup_name` lives relative to `path`'s raw data.
+    fn rollup_path(path: &Path, rollup_name: &str) -> PathBuf {
+        path.join("rollups").join(rollup_name)
+    }
+}
+
+/// Builds the subset of [`types::OntologyModelStats`] that
+/// [`mosaicod_rw::format::dictionary_eligible_columns`] actually looks at -- per-column
+/// [`types::TextualStats`] -- by scanning `batches` directly, rather than threading through
+/// the streaming accumulator [`mosaicod_rw::ChunkedWriter`] builds for regular topic writes.
+/// A rollup's output is already fully materialized in memory by the time it's written, so a
+/// single pass here is cheap; non-textual columns are left [`types::Stats::Unsupported`]
+/// since they don't affect the dictionary-encoding decision.
+fn textual_dictionary_stats(schema: &SchemaRef, batches: &[RecordBatch]) -> types::OntologyModelStats {
+    use arrow::array::{Array, AsArray};
+
+    let mut cols = HashMap::new();
+
+    for field in schema.fields() {
+        let mut stats = types::TextualStats::new();
+        let mut is_textual = false;
+
+        for batch in batches {
+            let Some(array) = batch.column_by_name(field.name()) else {
+                continue;
+            };
+            let Some(values) = array.as_string_opt::<i32>() else {
+                continue;
+            };
+
+            is_textual = true;
+            for i in 0..values.len() {
+                stats.eval(&(!values.is_null(i)).then(|| values.value(i)));
+            }
+        }
+
+        cols.insert(
+            field.name().clone(),
+            if is_textual {
+                types::Stats::Textual(stats)
+            } else {
+                types::Stats::Unsupported
+            },
+        );
+    }
+
+    types::OntologyModelStats { cols }
 }
 
 pub struct TimeseriesResult {
@@ -145,6 +271,19 @@ impl TimeseriesResult {
         Ok(TimeseriesResult { data_frame })
     }
 
+    /// Narrows the result down to `columns`, in the order given. A no-op when `columns`
+    /// is empty, i.e. no projection was requested.
+    pub fn select_columns(mut self, columns: &[String]) -> Result<Self, Error> {
+        if columns.is_empty() {
+            return Ok(self);
+        }
+
+        let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+        self.data_frame = self.data_frame.select_columns(&columns)?;
+
+        Ok(self)
+    }
+
     pub async fn stream(self) -> Result<SendableRecordBatchStream, Error> {
         self.data_frame.execute_stream().await.map_err(|e| e.into())
     }
@@ -198,6 +337,39 @@ impl TimeseriesResult {
 
         Err(Error::NotFound)
     }
+
+    /// Aggregates this result into `rollup`'s bucketed resolution: rows are grouped by
+    /// `floor(timestamp / bucket_width_ms) * bucket_width_ms` (computed here via truncating
+    /// integer division, valid since timestamps are always non-negative) and each
+    /// [`RollupAggregate`] reduced per bucket, aliased via [`AggregateFn::output_column`].
+    /// Buckets come out sorted by timestamp, matching [`Timeseries::read`]'s ordering.
+    pub fn rolled_up(self, rollup: &RollupSpec) -> Result<Self, Error> {
+        let ts_col = params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP;
+        let bucket_width = lit(rollup.bucket_width_ms);
+
+        let bucket_expr = ((col(ts_col) / bucket_width.clone()) * bucket_width).alias(ts_col);
+
+        let mut aggr_exprs = Vec::new();
+        for aggregate in &rollup.aggregates {
+            let field = col(aggregate.field.as_str());
+            for function in &aggregate.functions {
+                let expr = match function {
+                    AggregateFn::Min => min(field.clone()),
+                    AggregateFn::Max => max(field.clone()),
+                    AggregateFn::Sum => sum(field.clone()),
+                    AggregateFn::Count => count(field.clone()),
+                };
+                aggr_exprs.push(expr.alias(function.output_column(&aggregate.field)));
+            }
+        }
+
+        let data_frame = self
+            .data_frame
+            .aggregate(vec![bucket_expr], aggr_exprs)?
+            .sort(vec![col(ts_col).sort(true, false)])?;
+
+        Ok(TimeseriesResult { data_frame })
+    }
 }
 
 fn scalar_value_to_timestamp(value: ScalarValue) -> Option<types::Timestamp> {
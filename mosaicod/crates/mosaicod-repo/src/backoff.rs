@@ -0,0 +1,166 @@
+//! Retry helper for establishing repository/store connections.
+//!
+//! Connection setup assumes the backend is reachable by default, so a transient restart
+//! of the database or object store would otherwise surface as a hard failure at
+//! startup. [`retry_with_backoff`] rides out that kind of blip the way sqlx and similar
+//! services do: retry with exponential backoff and randomized jitter, bounded by a
+//! maximum elapsed time, and give up immediately on anything that isn't classified as
+//! transient.
+
+use std::time::Duration;
+
+/// Exponential backoff schedule used while retrying a connection attempt.
+#[derive(Debug, Clone, Copy)]
+pub struct BackoffConfig {
+    /// Delay before the first retry.
+    pub initial_interval: Duration,
+    /// Upper bound on any single retry delay.
+    pub max_interval: Duration,
+    /// Total time budget across all retries. Once exceeded, the last error is returned.
+    pub max_elapsed_time: Duration,
+    /// Factor the delay is multiplied by after each failed attempt.
+    pub multiplier: f64,
+    /// Fraction of the computed delay randomized away, on both sides, to avoid
+    /// thundering-herd reconnects across many clients.
+    pub jitter_factor: f64,
+}
+
+impl Default for BackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_millis(100),
+            max_interval: Duration::from_secs(10),
+            max_elapsed_time: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter_factor: 0.2,
+        }
+    }
+}
+
+impl BackoffConfig {
+    fn jittered(&self, interval: Duration) -> Duration {
+        use rand::Rng;
+
+        let jitter = interval.as_secs_f64() * self.jitter_factor;
+        let delta = rand::rng().random_range(-jitter..=jitter);
+        Duration::from_secs_f64((interval.as_secs_f64() + delta).max(0.0))
+    }
+
+    fn next_interval(&self, interval: Duration) -> Duration {
+        Duration::from_secs_f64(interval.as_secs_f64() * self.multiplier).min(self.max_interval)
+    }
+}
+
+/// Classifies whether a connection error is worth retrying.
+pub enum Transience {
+    /// Likely to resolve on its own (e.g. the backend is still restarting).
+    Transient,
+    /// Will not resolve by retrying (e.g. bad credentials, malformed DSN).
+    Permanent,
+}
+
+/// Retries `attempt` using `config`'s exponential backoff schedule until it succeeds,
+/// `is_transient` reports the error as permanent, or the elapsed time budget runs out.
+///
+/// On exhausting the time budget, the last observed error is returned.
+pub async fn retry_with_backoff<T, E, F, Fut>(
+    config: &BackoffConfig,
+    is_transient: impl Fn(&E) -> Transience,
+    mut attempt: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let start = std::time::Instant::now();
+    let mut interval = config.initial_interval;
+
+    loop {
+        match attempt().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                let transient = matches!(is_transient(&err), Transience::Transient);
+
+                if !transient || start.elapsed() >= config.max_elapsed_time {
+                    return Err(err);
+                }
+
+                tokio::time::sleep(config.jittered(interval)).await;
+                interval = config.next_interval(interval);
+            }
+        }
+    }
+}
+
+/// Classifies an [`sqlx::Error`] as transient if it's an I/O error of a kind typically
+/// seen while a database is restarting or not yet accepting connections.
+pub fn classify_sqlx_error(err: &sqlx::Error) -> Transience {
+    if let sqlx::Error::Io(io_err) = err {
+        use std::io::ErrorKind;
+        if matches!(
+            io_err.kind(),
+            ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted
+        ) {
+            return Transience::Transient;
+        }
+    }
+
+    Transience::Permanent
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn retries_transient_errors_until_success() {
+        let attempts = AtomicUsize::new(0);
+
+        let config = BackoffConfig {
+            initial_interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(5),
+            max_elapsed_time: Duration::from_secs(5),
+            ..Default::default()
+        };
+
+        let result: Result<(), &'static str> = retry_with_backoff(
+            &config,
+            |_| Transience::Transient,
+            || {
+                let attempt_no = attempts.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt_no < 2 {
+                        Err("not yet")
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_immediately_on_permanent_errors() {
+        let attempts = AtomicUsize::new(0);
+
+        let config = BackoffConfig::default();
+
+        let result: Result<(), &'static str> = retry_with_backoff(
+            &config,
+            |_| Transience::Permanent,
+            || {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err("bad credentials") }
+            },
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -8,6 +8,8 @@ pub struct FacadeChunk<'a> {
 }
 
 impl<'a> FacadeChunk<'a> {
+    /// Records a chunk's metadata. `datafile` is expected to already be finalized on the
+    /// store, since this facade only records the file, it never reads it.
     pub async fn create(
         topic_id: i32,
         datafile: impl AsRef<std::path::Path>,
@@ -29,11 +31,15 @@ impl<'a> FacadeChunk<'a> {
     /// Push all column statistics using batch inserts for better performance.
     /// This method collects all stats, resolves column IDs, then performs
     /// two batch INSERT operations (one for numeric, one for textual stats).
+    ///
+    /// Returns the `(numeric_count, textual_count)` of stats actually inserted, so a caller
+    /// instrumenting this call (see `mosaicod_server`'s metrics subsystem) doesn't need to
+    /// re-walk `cstats` itself just to label the counter.
     pub async fn push_ontology_model_stats(
         &mut self,
         ontology_tag: &str,
         cstats: types::OntologyModelStats,
-    ) -> Result<(), FacadeError> {
+    ) -> Result<(usize, usize), FacadeError> {
         let mut numeric_batch: Vec<repo::ColumnChunkNumeric> = Vec::new();
         let mut textual_batch: Vec<repo::ColumnChunkTextual> = Vec::new();
 
@@ -70,10 +76,12 @@ impl<'a> FacadeChunk<'a> {
             }
         }
 
+        let counts = (numeric_batch.len(), textual_batch.len());
+
         repo::column_chunk_numeric_create_batch(&mut self.tx, &numeric_batch).await?;
         repo::column_chunk_textual_create_batch(&mut self.tx, &textual_batch).await?;
 
-        Ok(())
+        Ok(counts)
     }
 
     pub async fn finalize(self) -> Result<(), FacadeError> {
@@ -22,6 +22,8 @@ pub enum FacadeError {
     QueryError(#[from] mosaicod_query::Error),
     #[error("marshalling error :: {0}")]
     MarshallingError(#[from] mosaicod_marshal::Error),
+    #[error("crypto error :: {0}")]
+    CryptoError(#[from] mosaicod_core::crypto::CryptoError),
     #[error("topic locked, unable to perform modifications")]
     TopicLocked,
     #[error("topic unlocked, unable to perform the requested operation over an unlocked topic")]
@@ -0,0 +1,82 @@
+use super::FacadeError;
+use crate as repo;
+use mosaicod_core::types::{JobKind, JobReport, JobState};
+
+/// Persists and queries [`JobReport`]s so a long-running operation (e.g. topic
+/// consolidation, see [`crate::FacadeTopic::compact`]) stays visible to operators and
+/// resumable across a crash, the same way [`super::FacadeChunk`] persists a chunk's stats
+/// inside the transaction that finalizes it.
+pub struct FacadeJob {
+    job_id: String,
+}
+
+impl FacadeJob {
+    /// Rebuilds a handle onto an already-persisted job, e.g. one found by
+    /// [`Self::list_running`] after a crash. Does not touch the repo itself.
+    pub fn for_existing(job_id: impl Into<String>) -> Self {
+        Self { job_id: job_id.into() }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.job_id
+    }
+
+    /// Records a new job in the [`JobState::Running`] state with `total_tasks` independent
+    /// tasks outstanding.
+    pub async fn create(
+        kind: JobKind,
+        total_tasks: u32,
+        repo: &repo::Repository,
+    ) -> Result<Self, FacadeError> {
+        let mut tx = repo.transaction().await?;
+        let job = repo::job_create(&mut tx, kind, total_tasks).await?;
+        tx.commit().await?;
+
+        Ok(Self { job_id: job.id })
+    }
+
+    /// Increments the persisted `completed_tasks` count by one. Called after each task
+    /// commits its own work, so a report read mid-job always reflects only fully-completed
+    /// tasks.
+    pub async fn record_task_completed(&self, repo: &repo::Repository) -> Result<(), FacadeError> {
+        let mut tx = repo.transaction().await?;
+        repo::job_increment_completed(&mut tx, &self.job_id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Transitions the job to a terminal state. Idempotent: re-finalizing an already
+    /// terminal job (e.g. a resumed job whose last task commits after a cancellation was
+    /// already recorded) is a no-op rather than an error.
+    pub async fn finalize(&self, state: JobState, repo: &repo::Repository) -> Result<(), FacadeError> {
+        let mut tx = repo.transaction().await?;
+        repo::job_set_state(&mut tx, &self.job_id, state, None).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Records why the job stopped early.
+    pub async fn fail(&self, error: String, repo: &repo::Repository) -> Result<(), FacadeError> {
+        let mut tx = repo.transaction().await?;
+        repo::job_set_state(&mut tx, &self.job_id, JobState::Failed, Some(error)).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Reads the current persisted report for this job.
+    pub async fn report(&self, repo: &repo::Repository) -> Result<JobReport, FacadeError> {
+        let mut tx = repo.transaction().await?;
+        let report = repo::job_get(&mut tx, &self.job_id).await?;
+        tx.commit().await?;
+        Ok(report)
+    }
+
+    /// Returns every job still in [`JobState::Running`], e.g. to re-enqueue their
+    /// incomplete tasks on process restart. See [`JobState::is_terminal`].
+    pub async fn list_running(repo: &repo::Repository) -> Result<Vec<JobReport>, FacadeError> {
+        let mut tx = repo.transaction().await?;
+        let reports = repo::job_list_running(&mut tx).await?;
+        tx.commit().await?;
+        Ok(reports)
+    }
+}
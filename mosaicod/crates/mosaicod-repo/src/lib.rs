@@ -4,6 +4,9 @@ pub use core::{AsExec, Config, Cx, Database, Repository, Tx, UNREGISTERED};
 #[cfg(any(test, feature = "testing"))]
 pub use core::testing;
 
+mod backoff;
+pub use backoff::{BackoffConfig, Transience, classify_sqlx_error, retry_with_backoff};
+
 mod facades;
 pub use facades::*;
 
@@ -0,0 +1,42 @@
+//! Transparent AEAD encryption of chunk data files at rest, layered on top of
+//! [`Writer::close`](crate::Writer::close)'s plaintext bytes.
+//!
+//! Stats used for query pruning (see `FacadeChunk::push_ontology_model_stats`) are always
+//! computed from the plaintext batches before [`encrypt_chunk`] runs, so enabling encryption
+//! never affects row-group pruning.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use mosaicod_core::crypto::DataEncryptionKey;
+
+use super::Error;
+
+/// Encrypts `plaintext` (a finalized chunk data file, see [`crate::Writer::close`]) under
+/// `dek`, using a nonce derived from `chunk_id` so the same DEK is safe to reuse across
+/// every chunk in a topic.
+pub fn encrypt_chunk(
+    dek: &DataEncryptionKey,
+    chunk_id: i64,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(dek.as_bytes()));
+    let nonce = Nonce::from(dek.nonce_for_chunk(chunk_id));
+
+    cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|e| mosaicod_core::crypto::CryptoError::EncryptFailed(e.to_string()).into())
+}
+
+/// Reverses [`encrypt_chunk`], returning the plaintext chunk data file.
+pub fn decrypt_chunk(
+    dek: &DataEncryptionKey,
+    chunk_id: i64,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(dek.as_bytes()));
+    let nonce = Nonce::from(dek.nonce_for_chunk(chunk_id));
+
+    cipher
+        .decrypt(&nonce, ciphertext)
+        .map_err(|e| mosaicod_core::crypto::CryptoError::DecryptFailed(e.to_string()).into())
+}
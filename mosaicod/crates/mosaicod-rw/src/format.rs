@@ -5,15 +5,18 @@
 //! configurations. Each format variant has its own strategy that defines compression settings,
 //! file extensions, and reading options.
 
+use datafusion::config::TableParquetOptions;
 use datafusion::datasource::file_format::parquet::ParquetFormat;
 use datafusion::datasource::listing::ListingOptions;
 use mosaicod_core::{params, traits::AsExtension, types};
 use parquet::{
     basic::{Compression, ZstdLevel},
-    file::properties::{EnabledStatistics, WriterProperties, WriterVersion},
+    file::properties::{EnabledStatistics, WriterProperties, WriterPropertiesBuilder, WriterVersion},
     schema::types::ColumnPath,
 };
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
 
 // ////////////////////////////////////////////////////////////////////////////
 // Format Properties Traits
@@ -38,13 +41,256 @@ pub trait FormatProperties: AsExtension + Send + Sync {
 /// statistics, and DataFusion integration. Formats that store data as Parquet
 /// files should implement this trait.
 pub trait ParquetFormatProperties: FormatProperties {
-    /// Returns the Parquet writer properties configured for this format.
-    fn writer_properties(&self) -> WriterProperties;
+    /// Returns this format's own `WriterPropertiesBuilder`, with any `Some` field in `cfg`
+    /// already layered on top (see [`ParquetWriterConfig`]). The `writer_properties*` methods
+    /// below all build on top of this single per-format builder instead of duplicating it.
+    fn base_writer_properties_builder(&self, cfg: &ParquetWriterConfig) -> WriterPropertiesBuilder;
+
+    /// Returns the Parquet writer properties configured for this format, with any `Some`
+    /// field in `cfg` overriding this format's own default (see [`ParquetWriterConfig`]).
+    fn writer_properties(&self, cfg: &ParquetWriterConfig) -> WriterProperties {
+        self.base_writer_properties_builder(cfg).build()
+    }
+
+    /// Returns this format's writer properties with dictionary encoding additionally enabled
+    /// for `dictionary_columns`, on top of whatever this format's own default (as overridden
+    /// by `cfg`) already is for every other column. Used to drive dictionary encoding from
+    /// measured cardinality (see [`dictionary_eligible_columns`]) rather than a static
+    /// default.
+    fn writer_properties_with_dictionary_columns(
+        &self,
+        cfg: &ParquetWriterConfig,
+        dictionary_columns: &[String],
+    ) -> WriterProperties {
+        with_dictionary_columns(self.base_writer_properties_builder(cfg), dictionary_columns).build()
+    }
+
+    /// Returns this format's writer properties with dictionary encoding and a per-column
+    /// bloom filter enabled for every column in `filterable_columns` (typically low-cardinality
+    /// categorical/ID fields from the ontology model), on top of whatever this format's own
+    /// default already is for every other column. This lets an equality predicate on one of
+    /// these columns prune whole row groups during `FacadeQuery::query` without decoding them,
+    /// the same way the timestamp column's own page statistics already prune range predicates.
+    fn writer_properties_with_filterable_columns(
+        &self,
+        cfg: &ParquetWriterConfig,
+        dictionary_columns: &[String],
+        filterable_columns: &[FilterableColumn],
+    ) -> WriterProperties {
+        let builder = with_dictionary_columns(self.base_writer_properties_builder(cfg), dictionary_columns);
+        let builder = filterable_columns.iter().fold(builder, |builder, column| {
+            builder.set_column_dictionary_enabled(ColumnPath::from(column.name.as_str()), true)
+        });
+        with_filterable_columns(builder, cfg, filterable_columns).build()
+    }
 
     /// Returns DataFusion ListingOptions configured for reading files in this format.
     fn listing_options(&self) -> ListingOptions;
 }
 
+/// A column eligible for row-group pruning via an equality predicate, typically a
+/// low-cardinality categorical/ID field from the ontology model. Passing one to
+/// [`ParquetFormatProperties::writer_properties_with_filterable_columns`] enables a bloom
+/// filter for it, sized from `ndv_hint` and `fpp` when given.
+#[derive(Debug, Clone)]
+pub struct FilterableColumn {
+    pub name: String,
+    /// Estimated number of distinct values, used to size the bloom filter. `None` falls back
+    /// to Parquet's own default NDV estimate.
+    pub ndv_hint: Option<u64>,
+    /// False-positive probability for this column's bloom filter. `None` falls back to
+    /// [`ParquetWriterConfig::bloom_filter_default_fpp`], then Parquet's own default.
+    pub fpp: Option<f64>,
+}
+
+impl FilterableColumn {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ndv_hint: None,
+            fpp: None,
+        }
+    }
+
+    pub fn with_ndv_hint(mut self, ndv: u64) -> Self {
+        self.ndv_hint = Some(ndv);
+        self
+    }
+
+    pub fn with_fpp(mut self, fpp: f64) -> Self {
+        self.fpp = Some(fpp);
+        self
+    }
+}
+
+/// Runtime overrides for a [`ParquetFormatProperties`]' writer settings, mirroring
+/// DataFusion's own `ParquetOptions`, so operators can tune compression, row group sizing,
+/// etc. from a config file or the `DoPut` command without recompiling. Every field is
+/// optional; `None` means "keep this format's own default" (see
+/// [`ParquetFormatProperties::writer_properties`]), so [`ParquetWriterConfig::default`]
+/// changes nothing.
+#[derive(Debug, Clone, Default)]
+pub struct ParquetWriterConfig {
+    pub data_pagesize_limit: Option<usize>,
+    pub write_batch_size: Option<usize>,
+    pub writer_version: Option<WriterVersion>,
+    pub compression: Option<Compression>,
+    pub dictionary_enabled: Option<bool>,
+    pub statistics_enabled: Option<EnabledStatistics>,
+    pub max_row_group_size: Option<usize>,
+    /// Default false-positive probability for bloom filters enabled via
+    /// [`ParquetFormatProperties::writer_properties_with_filterable_columns`], used for any
+    /// [`FilterableColumn`] that doesn't set its own `fpp`. `None` falls back to Parquet's own
+    /// default.
+    pub bloom_filter_default_fpp: Option<f64>,
+}
+
+impl ParquetWriterConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a writer version the way DataFusion's `ParquetOptions::writer_version` does:
+    /// `"1.0"` or `"2.0"`. Returns `None` for anything else.
+    pub fn parse_writer_version(s: &str) -> Option<WriterVersion> {
+        match s {
+            "1.0" => Some(WriterVersion::PARQUET_1_0),
+            "2.0" => Some(WriterVersion::PARQUET_2_0),
+            _ => None,
+        }
+    }
+
+    /// Parses a compression codec the way DataFusion's `ParquetOptions::compression` does,
+    /// e.g. `"zstd(5)"`, `"snappy"`, `"uncompressed"`. Returns `None` for an unrecognized
+    /// codec name or an invalid ZSTD level.
+    pub fn parse_compression(s: &str) -> Option<Compression> {
+        let s = s.trim();
+
+        if let Some(level) = s
+            .strip_prefix("zstd(")
+            .or_else(|| s.strip_prefix("ZSTD("))
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let level: i32 = level.parse().ok()?;
+            return Some(Compression::ZSTD(ZstdLevel::try_new(level).ok()?));
+        }
+
+        match s.to_ascii_lowercase().as_str() {
+            "uncompressed" => Some(Compression::UNCOMPRESSED),
+            "snappy" => Some(Compression::SNAPPY),
+            "gzip" => Some(Compression::GZIP(Default::default())),
+            "lzo" => Some(Compression::LZO),
+            "brotli" => Some(Compression::BROTLI(Default::default())),
+            "lz4" => Some(Compression::LZ4),
+            "zstd" => Some(Compression::ZSTD(ZstdLevel::default())),
+            _ => None,
+        }
+    }
+
+    /// Applies every `Some` field in `self` onto `builder`, leaving whatever `builder`
+    /// already had for fields left `None`.
+    fn apply(&self, mut builder: WriterPropertiesBuilder) -> WriterPropertiesBuilder {
+        if let Some(v) = self.data_pagesize_limit {
+            builder = builder.set_data_page_size_limit(v);
+        }
+        if let Some(v) = self.write_batch_size {
+            builder = builder.set_write_batch_size(v);
+        }
+        if let Some(v) = self.writer_version {
+            builder = builder.set_writer_version(v);
+        }
+        if let Some(v) = self.compression {
+            builder = builder.set_compression(v);
+        }
+        if let Some(v) = self.dictionary_enabled {
+            builder = builder.set_dictionary_enabled(v);
+        }
+        if let Some(v) = self.statistics_enabled {
+            builder = builder.set_statistics_enabled(v);
+        }
+        if let Some(v) = self.max_row_group_size {
+            builder = builder.set_max_row_group_size(v);
+        }
+        builder
+    }
+}
+
+/// Default cardinality ratio (estimated distinct values / row count) at or below which a
+/// textual column is considered a good dictionary-encoding candidate by
+/// [`dictionary_eligible_columns`].
+pub const DEFAULT_DICTIONARY_CARDINALITY_THRESHOLD: f64 = 0.5;
+
+/// Scans `stats` for textual columns whose [`types::TextualStats::is_low_cardinality`]
+/// estimate (backed by the `HyperLogLog` sketch fed during the stats pass) is at or below
+/// `threshold`, e.g. tags, labels, and other enum-like fields. The result is meant to be
+/// passed to [`ParquetFormatProperties::writer_properties_with_dictionary_columns`].
+pub fn dictionary_eligible_columns(stats: &types::OntologyModelStats, threshold: f64) -> Vec<String> {
+    stats
+        .cols
+        .iter()
+        .filter_map(|(name, col_stats)| match col_stats {
+            types::Stats::Textual(text_stats) if text_stats.is_low_cardinality(threshold) => {
+                Some(name.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Chains `.set_column_dictionary_enabled(_, true)` onto `builder` for each column in
+/// `dictionary_columns`.
+fn with_dictionary_columns(
+    mut builder: parquet::file::properties::WriterPropertiesBuilder,
+    dictionary_columns: &[String],
+) -> parquet::file::properties::WriterPropertiesBuilder {
+    for column in dictionary_columns {
+        builder = builder.set_column_dictionary_enabled(ColumnPath::from(column.as_str()), true);
+    }
+    builder
+}
+
+/// Chains a bloom filter (and its NDV/FPP hints) onto `builder` for each column in
+/// `filterable_columns`, falling back to `cfg.bloom_filter_default_fpp` and then Parquet's own
+/// defaults when a column doesn't set its own hint.
+fn with_filterable_columns(
+    mut builder: WriterPropertiesBuilder,
+    cfg: &ParquetWriterConfig,
+    filterable_columns: &[FilterableColumn],
+) -> WriterPropertiesBuilder {
+    for column in filterable_columns {
+        let path = ColumnPath::from(column.name.as_str());
+
+        builder = builder.set_column_bloom_filter_enabled(path.clone(), true);
+
+        if let Some(ndv) = column.ndv_hint {
+            builder = builder.set_column_bloom_filter_ndv(path.clone(), ndv);
+        }
+
+        if let Some(fpp) = column.fpp.or(cfg.bloom_filter_default_fpp) {
+            builder = builder.set_column_bloom_filter_fpp(path, fpp);
+        }
+    }
+    builder
+}
+
+/// Builds the `ListingOptions` shared by every Parquet-based format: reads the Page Index so
+/// the scan can skip pages via their min/max statistics without decoding them, pushes
+/// predicates down into the scan instead of filtering after decode, and reorders pushed-down
+/// filters heuristically to evaluate the cheapest/most selective ones first. `RaggedFormatProperties`
+/// and `ImageFormatProperties` already write page-level statistics and bloom filters on the
+/// timestamp column on the write side (see their `writer_properties`); this is what makes the
+/// read side actually exploit them.
+fn parquet_listing_options(extension: &str) -> ListingOptions {
+    let mut table_options = TableParquetOptions::new();
+    table_options.global.enable_page_index = true;
+    table_options.global.pushdown_filters = true;
+    table_options.global.reorder_filters = true;
+
+    let format = ParquetFormat::new().with_options(table_options);
+
+    ListingOptions::new(Arc::new(format)).with_file_extension(format!(".{extension}"))
+}
+
 // ////////////////////////////////////////////////////////////////////////////
 // Formats Implementation
 // ////////////////////////////////////////////////////////////////////////////
@@ -66,15 +312,13 @@ impl FormatProperties for DefaultFormatProperties {
 }
 
 impl ParquetFormatProperties for DefaultFormatProperties {
-    fn writer_properties(&self) -> WriterProperties {
-        WriterProperties::builder()
-            .set_writer_version(WriterVersion::PARQUET_2_0)
-            .build()
+    fn base_writer_properties_builder(&self, cfg: &ParquetWriterConfig) -> WriterPropertiesBuilder {
+        let builder = WriterProperties::builder().set_writer_version(WriterVersion::PARQUET_2_0);
+        cfg.apply(builder)
     }
 
     fn listing_options(&self) -> ListingOptions {
-        ListingOptions::new(Arc::new(ParquetFormat::default()))
-            .with_file_extension(format!(".{}", self.as_extension()))
+        parquet_listing_options(&self.as_extension())
     }
 }
 
@@ -105,10 +349,10 @@ impl FormatProperties for RaggedFormatProperties {
 }
 
 impl ParquetFormatProperties for RaggedFormatProperties {
-    fn writer_properties(&self) -> WriterProperties {
+    fn base_writer_properties_builder(&self, cfg: &ParquetWriterConfig) -> WriterPropertiesBuilder {
         let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
 
-        WriterProperties::builder()
+        let builder = WriterProperties::builder()
             .set_writer_version(WriterVersion::PARQUET_2_0)
             .set_compression(Compression::ZSTD(
                 ZstdLevel::try_new(Self::COMPRESSION_LEVEL).expect("valid ZSTD compression level"),
@@ -118,13 +362,13 @@ impl ParquetFormatProperties for RaggedFormatProperties {
             // Timestamp column: uncompressed for fast seeking
             .set_column_compression(ts_path.clone(), Compression::UNCOMPRESSED)
             .set_column_statistics_enabled(ts_path.clone(), EnabledStatistics::Page)
-            .set_column_bloom_filter_enabled(ts_path, true)
-            .build()
+            .set_column_bloom_filter_enabled(ts_path, true);
+
+        cfg.apply(builder)
     }
 
     fn listing_options(&self) -> ListingOptions {
-        ListingOptions::new(Arc::new(ParquetFormat::default()))
-            .with_file_extension(format!(".{}", self.as_extension()))
+        parquet_listing_options(&self.as_extension())
     }
 }
 
@@ -155,10 +399,10 @@ impl FormatProperties for ImageFormatProperties {
 }
 
 impl ParquetFormatProperties for ImageFormatProperties {
-    fn writer_properties(&self) -> WriterProperties {
+    fn base_writer_properties_builder(&self, cfg: &ParquetWriterConfig) -> WriterPropertiesBuilder {
         let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
 
-        WriterProperties::builder()
+        let builder = WriterProperties::builder()
             .set_writer_version(WriterVersion::PARQUET_2_0)
             .set_compression(Compression::ZSTD(
                 ZstdLevel::try_new(Self::COMPRESSION_LEVEL).expect("valid ZSTD compression level"),
@@ -168,13 +412,13 @@ impl ParquetFormatProperties for ImageFormatProperties {
             // Timestamp column: uncompressed for fast seeking
             .set_column_compression(ts_path.clone(), Compression::UNCOMPRESSED)
             .set_column_statistics_enabled(ts_path.clone(), EnabledStatistics::Page)
-            .set_column_bloom_filter_enabled(ts_path, true)
-            .build()
+            .set_column_bloom_filter_enabled(ts_path, true);
+
+        cfg.apply(builder)
     }
 
     fn listing_options(&self) -> ListingOptions {
-        ListingOptions::new(Arc::new(ParquetFormat::default()))
-            .with_file_extension(format!(".{}", self.as_extension()))
+        parquet_listing_options(&self.as_extension())
     }
 }
 
@@ -211,6 +455,88 @@ impl ToProperties for types::Format {
     }
 }
 
+// ////////////////////////////////////////////////////////////////////////////
+// Format Registry
+// ////////////////////////////////////////////////////////////////////////////
+
+/// One format strategy registered by name, carrying both halves of the strategy (the base
+/// [`FormatProperties`] always, and [`ParquetFormatProperties`] when the format is
+/// Parquet-backed) so a single registration covers both [`resolve_properties`] and
+/// [`resolve_parquet_properties`].
+#[derive(Clone)]
+struct RegisteredFormat {
+    properties: Arc<dyn FormatProperties>,
+    parquet_properties: Option<Arc<dyn ParquetFormatProperties>>,
+}
+
+fn registry() -> &'static Mutex<HashMap<String, RegisteredFormat>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, RegisteredFormat>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Extension point for formats beyond the three built into [`types::Format`] (`Default`,
+/// `Ragged`, `Image`). `types::Format` stays a closed enum — the variant is part of the
+/// on-disk/wire representation and downstream code already matches on it exhaustively — but
+/// [`resolve_properties`]/[`resolve_parquet_properties`] let a caller look up a strategy by
+/// name without going through that enum at all, the same way DataFusion lets you register a
+/// custom `FileFormatFactory` instead of forking its `FileType` enum.
+///
+/// Built-in names (`"default"`, `"ragged"`, `"image"`) cannot be overridden: the registry is
+/// only ever consulted for names the closed enum doesn't already recognize.
+pub struct FormatRegistry;
+
+impl FormatRegistry {
+    /// Registers `properties` under `name` for later lookup via [`resolve_properties`]. Pass
+    /// `parquet_properties` too when the format is Parquet-backed, so it's also reachable via
+    /// [`resolve_parquet_properties`].
+    ///
+    /// Re-registering an already-registered name replaces the previous entry.
+    pub fn register(
+        name: impl Into<String>,
+        properties: Arc<dyn FormatProperties>,
+        parquet_properties: Option<Arc<dyn ParquetFormatProperties>>,
+    ) {
+        registry().lock().expect("format registry poisoned").insert(
+            name.into(),
+            RegisteredFormat {
+                properties,
+                parquet_properties,
+            },
+        );
+    }
+}
+
+/// Resolves `name` to a [`FormatProperties`], trying the built-in formats first and falling
+/// back to whatever has been registered via [`FormatRegistry::register`].
+pub fn resolve_properties(name: &str) -> Option<Arc<dyn FormatProperties>> {
+    if let Ok(format) = types::Format::from_str(name) {
+        return Some(Arc::from(as_format_property(&format)));
+    }
+
+    registry()
+        .lock()
+        .expect("format registry poisoned")
+        .get(name)
+        .map(|registered| registered.properties.clone())
+}
+
+/// Resolves `name` to a [`ParquetFormatProperties`], trying the built-in formats first and
+/// falling back to whatever has been registered via [`FormatRegistry::register`]. Returns
+/// `None` both for an unknown name and for a registered format that didn't provide Parquet
+/// properties.
+pub fn resolve_parquet_properties(name: &str) -> Option<Arc<dyn ParquetFormatProperties>> {
+    if let Ok(format) = types::Format::from_str(name) {
+        return as_parquet_properties(&format).map(Arc::from);
+    }
+
+    registry()
+        .lock()
+        .expect("format registry poisoned")
+        .get(name)?
+        .parquet_properties
+        .clone()
+}
+
 /// Returns the Parquet-specific properties if this format uses Parquet storage.
 ///
 /// Use this method when you need Parquet-specific configuration like
@@ -222,10 +548,11 @@ impl ToProperties for types::Format {
 /// ```
 /// use mosaicod_core::types::Format;
 /// use mosaicod_rw::ToParquetProperties;
+/// use mosaicod_rw::format::ParquetWriterConfig;
 ///
 /// // Returns option since not every format is based on parquet
 /// if let Some(props) = Format::Default.to_parquet_properties(){
-///     let wprops = props.writer_properties();
+///     let wprops = props.writer_properties(&ParquetWriterConfig::default());
 ///     let loptions = props.listing_options();
 /// }
 /// ```
@@ -274,18 +601,63 @@ mod tests {
 
     #[test]
     fn parquet_strategy_writer_properties() {
+        let cfg = ParquetWriterConfig::default();
+
         let _ = Format::Default
             .to_parquet_properties()
             .unwrap()
-            .writer_properties();
+            .writer_properties(&cfg);
         let _ = Format::Ragged
             .to_parquet_properties()
             .unwrap()
-            .writer_properties();
+            .writer_properties(&cfg);
         let _ = Format::Image
             .to_parquet_properties()
             .unwrap()
-            .writer_properties();
+            .writer_properties(&cfg);
+    }
+
+    #[test]
+    fn writer_properties_applies_config_overrides() {
+        let cfg = ParquetWriterConfig {
+            compression: Some(Compression::SNAPPY),
+            max_row_group_size: Some(42),
+            ..Default::default()
+        };
+
+        let props = DefaultFormatProperties.writer_properties(&cfg);
+
+        assert_eq!(
+            props.compression(&ColumnPath::from("any")),
+            Compression::SNAPPY
+        );
+        assert_eq!(props.max_row_group_size(), 42);
+    }
+
+    #[test]
+    fn parse_writer_version_accepts_known_strings() {
+        assert_eq!(
+            ParquetWriterConfig::parse_writer_version("1.0"),
+            Some(WriterVersion::PARQUET_1_0)
+        );
+        assert_eq!(
+            ParquetWriterConfig::parse_writer_version("2.0"),
+            Some(WriterVersion::PARQUET_2_0)
+        );
+        assert_eq!(ParquetWriterConfig::parse_writer_version("bogus"), None);
+    }
+
+    #[test]
+    fn parse_compression_accepts_zstd_with_level() {
+        assert_eq!(
+            ParquetWriterConfig::parse_compression("zstd(5)"),
+            Some(Compression::ZSTD(ZstdLevel::try_new(5).unwrap()))
+        );
+        assert_eq!(
+            ParquetWriterConfig::parse_compression("snappy"),
+            Some(Compression::SNAPPY)
+        );
+        assert_eq!(ParquetWriterConfig::parse_compression("not_a_codec"), None);
     }
 
     #[test]
@@ -304,10 +676,128 @@ mod tests {
             .listing_options();
     }
 
+    #[test]
+    fn listing_options_enable_page_index_and_filter_pushdown() {
+        let options = parquet_listing_options("parquet");
+        let format = options
+            .format
+            .as_any()
+            .downcast_ref::<ParquetFormat>()
+            .expect("parquet_listing_options always builds a ParquetFormat");
+
+        assert!(format.options().global.enable_page_index);
+        assert!(format.options().global.pushdown_filters);
+        assert!(format.options().global.reorder_filters);
+    }
+
     #[test]
     fn as_parquet_returns_some_for_parquet_formats() {
         assert!(Format::Default.to_parquet_properties().is_some());
         assert!(Format::Ragged.to_parquet_properties().is_some());
         assert!(Format::Image.to_parquet_properties().is_some());
     }
+
+    #[test]
+    fn dictionary_eligible_columns_selects_low_cardinality_textual_columns() {
+        use mosaicod_core::types::{OntologyModelStats, Stats, TextualStats};
+        use std::collections::HashMap;
+
+        let mut low_cardinality = TextualStats::new();
+        for _ in 0..1000 {
+            low_cardinality.eval(&Some("enabled"));
+        }
+
+        let mut high_cardinality = TextualStats::new();
+        for i in 0..1000 {
+            high_cardinality.eval(&Some(i.to_string().as_str()));
+        }
+
+        let mut cols = HashMap::new();
+        cols.insert("tag".to_owned(), Stats::Textual(low_cardinality));
+        cols.insert("id".to_owned(), Stats::Textual(high_cardinality));
+        cols.insert("unsupported".to_owned(), Stats::Unsupported);
+
+        let stats = OntologyModelStats { cols };
+
+        let eligible = dictionary_eligible_columns(&stats, DEFAULT_DICTIONARY_CARDINALITY_THRESHOLD);
+
+        assert_eq!(eligible, vec!["tag".to_owned()]);
+    }
+
+    #[test]
+    fn writer_properties_with_dictionary_columns_enables_dictionary_for_named_columns() {
+        let props = DefaultFormatProperties.writer_properties_with_dictionary_columns(
+            &ParquetWriterConfig::default(),
+            &["tag".to_owned()],
+        );
+
+        assert!(props.dictionary_enabled(&ColumnPath::from("tag")));
+    }
+
+    #[test]
+    fn writer_properties_with_filterable_columns_enables_bloom_filter_and_dictionary() {
+        let column = FilterableColumn::new("tag").with_ndv_hint(100).with_fpp(0.01);
+
+        let props = DefaultFormatProperties.writer_properties_with_filterable_columns(
+            &ParquetWriterConfig::default(),
+            &["tag".to_owned()],
+            &[column],
+        );
+
+        assert!(props.dictionary_enabled(&ColumnPath::from("tag")));
+        let bloom_filter = props
+            .bloom_filter_properties(&ColumnPath::from("tag"))
+            .expect("bloom filter enabled for tag");
+        assert_eq!(bloom_filter.ndv, 100);
+        assert_eq!(bloom_filter.fpp, 0.01);
+    }
+
+    #[test]
+    fn writer_properties_with_filterable_columns_falls_back_to_config_default_fpp() {
+        let cfg = ParquetWriterConfig {
+            bloom_filter_default_fpp: Some(0.02),
+            ..Default::default()
+        };
+        let column = FilterableColumn::new("tag");
+
+        let props = DefaultFormatProperties.writer_properties_with_filterable_columns(
+            &cfg,
+            &[],
+            &[column],
+        );
+
+        let bloom_filter = props
+            .bloom_filter_properties(&ColumnPath::from("tag"))
+            .expect("bloom filter enabled for tag");
+        assert_eq!(bloom_filter.fpp, 0.02);
+    }
+
+    #[test]
+    fn resolve_properties_finds_built_in_formats_by_name() {
+        assert_eq!(resolve_properties("default").unwrap().name(), "default");
+        assert_eq!(resolve_properties("ragged").unwrap().name(), "ragged");
+        assert!(resolve_properties("no-such-format").is_none());
+    }
+
+    #[test]
+    fn resolve_properties_finds_registered_custom_format() {
+        FormatRegistry::register(
+            "chunk4-2-test-format",
+            Arc::new(ImageFormatProperties),
+            Some(Arc::new(ImageFormatProperties)),
+        );
+
+        assert_eq!(
+            resolve_properties("chunk4-2-test-format").unwrap().name(),
+            "image"
+        );
+        assert!(resolve_parquet_properties("chunk4-2-test-format").is_some());
+    }
+
+    #[test]
+    fn resolve_properties_prefers_built_in_formats_over_registrations() {
+        FormatRegistry::register("default", Arc::new(ImageFormatProperties), None);
+
+        assert_eq!(resolve_properties("default").unwrap().name(), "default");
+    }
 }
@@ -0,0 +1,226 @@
+//! Process-scoped cache for parsed Parquet footer metadata.
+//!
+//! Each `listing_options()`-driven scan re-reads and re-parses a chunk's footer and page
+//! indexes from the object store, even when the same chunk was just scanned by a previous
+//! query. [`ParquetMetadataCache`] keeps the parsed [`ParquetMetaData`] around, keyed by the
+//! triple that identifies a specific version of a file ([`MetadataCacheKey`]), so repeated
+//! reads over the same chunk skip the footer I/O entirely.
+
+use parquet::file::metadata::ParquetMetaData;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Identifies a specific version of a stored file. A footer is only ever served back for the
+/// exact `(path, size, mtime)` it was cached under, so a chunk rewritten at the same path
+/// (e.g. during reindexing) naturally misses the cache instead of returning a stale footer,
+/// even without an explicit call to [`ParquetMetadataCache::invalidate_path`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetadataCacheKey {
+    pub path: String,
+    pub size: u64,
+    pub mtime_unix_ns: i64,
+}
+
+/// Runtime-configurable limits for a [`ParquetMetadataCache`], read from the
+/// `parquet_metadata_cache_capacity`/`parquet_metadata_cache_ttl_secs` configurables.
+#[derive(Debug, Clone)]
+pub struct ParquetMetadataCacheConfig {
+    /// Maximum number of footers to retain. Once exceeded, the least recently used entry is
+    /// evicted first.
+    pub capacity: usize,
+    /// How long a cached footer is trusted before it's treated as a miss, independent of
+    /// `capacity` eviction. `None` disables time-based expiry.
+    pub ttl: Option<Duration>,
+}
+
+impl Default for ParquetMetadataCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            ttl: Some(Duration::from_secs(600)),
+        }
+    }
+}
+
+struct Entry {
+    metadata: Arc<ParquetMetaData>,
+    inserted_at: Instant,
+}
+
+/// Process-wide LRU cache of parsed Parquet footers. `mosaicod_query::Timeseries` owns the
+/// process's one instance and exposes `invalidate_chunk_metadata` as the reindexing
+/// invalidation hook.
+pub struct ParquetMetadataCache {
+    config: ParquetMetadataCacheConfig,
+    entries: Mutex<HashMap<MetadataCacheKey, Entry>>,
+    /// Recency order, least recently used first, used for eviction once `capacity` is
+    /// exceeded.
+    order: Mutex<Vec<MetadataCacheKey>>,
+}
+
+impl ParquetMetadataCache {
+    pub fn new(config: ParquetMetadataCacheConfig) -> Arc<Self> {
+        Arc::new(Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+            order: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Returns the cached footer for `key`, if present and not expired by `ttl`.
+    pub fn get(&self, key: &MetadataCacheKey) -> Option<Arc<ParquetMetaData>> {
+        let mut entries = self.entries.lock().expect("metadata cache poisoned");
+        let mut order = self.order.lock().expect("metadata cache poisoned");
+
+        let expired = entries.get(key).is_some_and(|entry| {
+            self.config
+                .ttl
+                .is_some_and(|ttl| entry.inserted_at.elapsed() > ttl)
+        });
+
+        if expired {
+            entries.remove(key);
+            order.retain(|k| k != key);
+            return None;
+        }
+
+        let metadata = entries.get(key).map(|entry| entry.metadata.clone());
+        if metadata.is_some() {
+            // A hit makes `key` the most recently used entry.
+            order.retain(|k| k != key);
+            order.push(key.clone());
+        }
+        metadata
+    }
+
+    /// Inserts `metadata` for `key`, evicting the least recently used entry first once
+    /// `capacity` is exceeded.
+    pub fn put(&self, key: MetadataCacheKey, metadata: Arc<ParquetMetaData>) {
+        let mut entries = self.entries.lock().expect("metadata cache poisoned");
+        let mut order = self.order.lock().expect("metadata cache poisoned");
+
+        order.retain(|k| k != &key);
+        order.push(key.clone());
+
+        entries.insert(
+            key,
+            Entry {
+                metadata,
+                inserted_at: Instant::now(),
+            },
+        );
+
+        while entries.len() > self.config.capacity && !order.is_empty() {
+            let oldest = order.remove(0);
+            entries.remove(&oldest);
+        }
+    }
+
+    /// Drops every cached footer for `path`, regardless of the size/mtime it was cached
+    /// under. Call this after rewriting the chunk at `path` during reindexing, so a stale
+    /// footer from its previous version can't be served even within the `ttl` window.
+    pub fn invalidate_path(&self, path: &str) {
+        let mut entries = self.entries.lock().expect("metadata cache poisoned");
+        let mut order = self.order.lock().expect("metadata cache poisoned");
+
+        entries.retain(|key, _| key.path != path);
+        order.retain(|key| key.path != path);
+    }
+
+    /// Number of footers currently cached.
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("metadata cache poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(path: &str) -> MetadataCacheKey {
+        MetadataCacheKey {
+            path: path.to_owned(),
+            size: 100,
+            mtime_unix_ns: 0,
+        }
+    }
+
+    fn metadata() -> Arc<ParquetMetaData> {
+        Arc::new(ParquetMetaData::new(
+            parquet::file::metadata::FileMetaData::new(1, 0, None, None, vec![], None, None, None),
+            vec![],
+        ))
+    }
+
+    #[test]
+    fn get_returns_none_for_uncached_key() {
+        let cache = ParquetMetadataCache::new(ParquetMetadataCacheConfig::default());
+        assert!(cache.get(&key("a")).is_none());
+    }
+
+    #[test]
+    fn put_then_get_returns_cached_metadata() {
+        let cache = ParquetMetadataCache::new(ParquetMetadataCacheConfig::default());
+        cache.put(key("a"), metadata());
+        assert!(cache.get(&key("a")).is_some());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn a_different_size_or_mtime_misses_the_cache() {
+        let cache = ParquetMetadataCache::new(ParquetMetadataCacheConfig::default());
+        cache.put(key("a"), metadata());
+
+        let mut rewritten = key("a");
+        rewritten.mtime_unix_ns = 1;
+
+        assert!(cache.get(&rewritten).is_none());
+    }
+
+    #[test]
+    fn capacity_evicts_oldest_entry_first() {
+        let cache = ParquetMetadataCache::new(ParquetMetadataCacheConfig {
+            capacity: 2,
+            ttl: None,
+        });
+
+        cache.put(key("a"), metadata());
+        cache.put(key("b"), metadata());
+        cache.put(key("c"), metadata());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key("a")).is_none());
+        assert!(cache.get(&key("b")).is_some());
+        assert!(cache.get(&key("c")).is_some());
+    }
+
+    #[test]
+    fn ttl_expires_entries() {
+        let cache = ParquetMetadataCache::new(ParquetMetadataCacheConfig {
+            capacity: 10,
+            ttl: Some(Duration::from_nanos(1)),
+        });
+
+        cache.put(key("a"), metadata());
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert!(cache.get(&key("a")).is_none());
+    }
+
+    #[test]
+    fn invalidate_path_drops_all_entries_for_that_path() {
+        let cache = ParquetMetadataCache::new(ParquetMetadataCacheConfig::default());
+        cache.put(key("a"), metadata());
+        cache.put(key("b"), metadata());
+
+        cache.invalidate_path("a");
+
+        assert!(cache.get(&key("a")).is_none());
+        assert!(cache.get(&key("b")).is_some());
+    }
+}
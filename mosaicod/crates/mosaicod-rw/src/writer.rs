@@ -1,6 +1,10 @@
-use crate::ToParquetProperties;
+use crate::{
+    ToParquetProperties,
+    format::{FilterableColumn, ParquetWriterConfig, dictionary_eligible_columns},
+};
 
 use super::Error;
+use arrow::array::RecordBatch;
 use arrow::datatypes::Schema;
 use mosaicod_core::types;
 use parquet::arrow::ArrowWriter;
@@ -13,12 +17,16 @@ pub enum Writer {
 }
 
 impl Writer {
-    pub fn new(schema: &Arc<Schema>, format: types::Format) -> Result<Self, Error> {
+    pub fn new(
+        schema: &Arc<Schema>,
+        format: types::Format,
+        cfg: &ParquetWriterConfig,
+    ) -> Result<Self, Error> {
         let parquet_strategy = format
             .to_parquet_properties()
             .expect("Writer::new requires a Parquet-based format");
 
-        let props = parquet_strategy.writer_properties();
+        let props = parquet_strategy.writer_properties(cfg);
 
         Ok(Self::Parquet(ArrowWriter::try_new(
             Vec::new(),
@@ -26,4 +34,80 @@ impl Writer {
             Some(props),
         )?))
     }
+
+    /// Like [`new`](Self::new), but enables dictionary encoding for any low-cardinality
+    /// textual column found in `stats` (see [`dictionary_eligible_columns`]), instead of this
+    /// format's static default.
+    pub fn new_with_stats(
+        schema: &Arc<Schema>,
+        format: types::Format,
+        cfg: &ParquetWriterConfig,
+        stats: &types::OntologyModelStats,
+    ) -> Result<Self, Error> {
+        let parquet_strategy = format
+            .to_parquet_properties()
+            .expect("Writer::new_with_stats requires a Parquet-based format");
+
+        let dictionary_columns = dictionary_eligible_columns(
+            stats,
+            crate::format::DEFAULT_DICTIONARY_CARDINALITY_THRESHOLD,
+        );
+        let props =
+            parquet_strategy.writer_properties_with_dictionary_columns(cfg, &dictionary_columns);
+
+        Ok(Self::Parquet(ArrowWriter::try_new(
+            Vec::new(),
+            schema.clone(),
+            Some(props),
+        )?))
+    }
+
+    /// Like [`new_with_stats`](Self::new_with_stats), but additionally enables a bloom filter
+    /// on every dictionary-eligible column (see [`dictionary_eligible_columns`]), so an equality
+    /// predicate on one of these categorical/ID fields can prune whole row groups during
+    /// `FacadeQuery::query` without decoding them.
+    pub fn new_with_filterable_columns(
+        schema: &Arc<Schema>,
+        format: types::Format,
+        cfg: &ParquetWriterConfig,
+        stats: &types::OntologyModelStats,
+    ) -> Result<Self, Error> {
+        let parquet_strategy = format
+            .to_parquet_properties()
+            .expect("Writer::new_with_filterable_columns requires a Parquet-based format");
+
+        let dictionary_columns = dictionary_eligible_columns(
+            stats,
+            crate::format::DEFAULT_DICTIONARY_CARDINALITY_THRESHOLD,
+        );
+        let filterable_columns: Vec<FilterableColumn> = dictionary_columns
+            .iter()
+            .map(|name| FilterableColumn::new(name.clone()))
+            .collect();
+        let props = parquet_strategy.writer_properties_with_filterable_columns(
+            cfg,
+            &dictionary_columns,
+            &filterable_columns,
+        );
+
+        Ok(Self::Parquet(ArrowWriter::try_new(
+            Vec::new(),
+            schema.clone(),
+            Some(props),
+        )?))
+    }
+
+    /// Appends `batch` to the file being written.
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), Error> {
+        match self {
+            Self::Parquet(writer) => Ok(writer.write(batch)?),
+        }
+    }
+
+    /// Finalizes the file and returns its bytes.
+    pub fn close(self) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Parquet(writer) => Ok(writer.into_inner()?),
+        }
+    }
 }
@@ -0,0 +1,158 @@
+//! Batch action handler.
+//!
+//! Unrolls an [`ActionRequest::Batch`] into its constituent sub-actions and executes
+//! them against a shared [`Context`], modeled on Garage's K2V batch API where a single
+//! request encodes multiple operations and each reports its own outcome.
+
+use super::{layer, sequence, topic};
+use crate::{endpoints::Context, errors::ServerError};
+use log::{info, trace};
+use mosaicod_marshal::{ActionRequest, ActionResponse};
+use mosaicod_repo::Tx;
+
+/// Executes every sub-action of a batch request in order.
+///
+/// In non-strict mode (the default), sub-actions run independently against `ctx` and
+/// each reports its own success or failure — one item failing does not prevent the
+/// rest of the batch from running. In strict mode the batch is all-or-nothing: every
+/// sub-action runs inside one [`Tx`] (see [`execute_strict`]), so a failure partway
+/// through leaves no trace for other connections to observe rather than relying on
+/// compensating deletes after the fact.
+pub async fn execute(
+    ctx: &Context,
+    actions: Vec<ActionRequest>,
+    strict: bool,
+) -> Result<ActionResponse, ServerError> {
+    info!(
+        "executing batch of {} action(s), strict={}",
+        actions.len(),
+        strict
+    );
+
+    if strict {
+        execute_strict(ctx, actions).await
+    } else {
+        Ok(ActionResponse::Batch(execute_best_effort(ctx, actions).await))
+    }
+}
+
+/// Runs each sub-action independently, collecting per-item results without aborting
+/// the batch on the first error.
+async fn execute_best_effort(
+    ctx: &Context,
+    actions: Vec<ActionRequest>,
+) -> Vec<Result<ActionResponse, ServerError>> {
+    let mut results = Vec::with_capacity(actions.len());
+
+    for (idx, action) in actions.into_iter().enumerate() {
+        trace!("batch item {idx}: executing");
+        results.push(super::super::do_action::do_action(ctx.clone(), action).await);
+    }
+
+    results
+}
+
+/// Runs every sub-action against a single repo transaction, so the batch is genuinely
+/// atomic rather than best-effort: nothing a sub-action writes is visible outside this
+/// function until every item has succeeded and `tx` commits. A failing item drops `tx`
+/// without committing, which rolls back everything earlier items in the same batch
+/// wrote — real rollback, not a second round of compensating deletes that could itself
+/// fail and leave state behind.
+///
+/// Every `ActionRequest` variant that mutates repository state has to run through
+/// `tx` to be covered by that guarantee; a variant this function doesn't yet know how
+/// to run transactionally is rejected up front (see [`reject_if_unsupported`]) rather
+/// than silently falling back to `do_action`'s independent, already-committed path.
+async fn execute_strict(
+    ctx: &Context,
+    actions: Vec<ActionRequest>,
+) -> Result<ActionResponse, ServerError> {
+    for action in &actions {
+        reject_if_unsupported(action)?;
+    }
+
+    let mut tx = ctx.repo.transaction().await?;
+    let mut responses = Vec::with_capacity(actions.len());
+
+    for (idx, action) in actions.into_iter().enumerate() {
+        trace!("strict batch item {idx}: executing");
+        responses.push(execute_in_tx(ctx, &mut tx, action).await?);
+    }
+
+    tx.commit().await?;
+
+    Ok(ActionResponse::Batch(
+        responses.into_iter().map(Ok).collect(),
+    ))
+}
+
+/// Runs a single sub-action against the shared transaction `tx` instead of letting it
+/// open (and commit) one of its own.
+async fn execute_in_tx(
+    ctx: &Context,
+    tx: &mut Tx<'_>,
+    action: ActionRequest,
+) -> Result<ActionResponse, ServerError> {
+    match action {
+        ActionRequest::SequenceCreate(data) => {
+            let user_metadata = data.user_metadata()?;
+            sequence::create_in_tx(tx, ctx, data.name, user_metadata.as_str()).await
+        }
+        ActionRequest::SequenceDelete(data) => sequence::delete_in_tx(tx, data.name).await,
+        ActionRequest::TopicCreate(data) => {
+            let user_metadata = data.user_metadata()?;
+            topic::create_in_tx(
+                tx,
+                ctx,
+                data.name,
+                data.sequence_key,
+                data.serialization_format.into(),
+                data.ontology_tag,
+                user_metadata.as_str(),
+            )
+            .await
+        }
+        ActionRequest::TopicDelete(data) => topic::delete_in_tx(tx, data.name).await,
+        ActionRequest::LayerCreate(data) => {
+            layer::create_in_tx(tx, data.name, data.description).await
+        }
+        ActionRequest::LayerDelete(data) => layer::delete_in_tx(tx, data.name).await,
+        ActionRequest::LayerUpdate(data) => {
+            layer::update_in_tx(tx, data.prev_name, data.curr_name, data.curr_description).await
+        }
+        // Every other variant is read-only or otherwise non-mutating; `reject_if_unsupported`
+        // already refused the batch if it contained anything mutating we can't run through
+        // `tx`, so it's safe to fall back to the ordinary, independently-committed path here.
+        other => super::super::do_action::do_action(ctx.clone(), other).await,
+    }
+}
+
+/// Refuses up front any strict-batch sub-action that mutates repository state but that
+/// [`execute_in_tx`] can't yet run inside the shared transaction, rather than letting it
+/// execute outside `tx` and silently escape the batch's atomicity guarantee.
+fn reject_if_unsupported(action: &ActionRequest) -> Result<(), ServerError> {
+    let unsupported = matches!(
+        action,
+        ActionRequest::SequenceAbort(_)
+            | ActionRequest::SequenceFinalize(_)
+            | ActionRequest::SequenceNotifyCreate(_)
+            | ActionRequest::SequenceNotifyPurge(_)
+            | ActionRequest::TopicNotifyCreate(_)
+            | ActionRequest::TopicNotifyPurge(_)
+            | ActionRequest::TopicCompact(_)
+            | ActionRequest::JobCancel(_)
+            // A nested batch would otherwise fall through to `execute_in_tx`'s `other` arm and
+            // run via `do_action`, which re-enters `batch::execute` with its own independent
+            // transaction — committing (or partially applying) completely outside the strict
+            // batch's `tx` and breaking the atomicity guarantee this function exists to provide.
+            | ActionRequest::Batch(_)
+    );
+
+    if unsupported {
+        return Err(ServerError::StreamError(
+            "this action cannot run inside a strict batch transaction".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
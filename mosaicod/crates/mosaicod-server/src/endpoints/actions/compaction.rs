@@ -0,0 +1,33 @@
+//! Topic compaction action handler.
+//!
+//! Merges small segment files overlapping a caller-supplied [`TimestampRange`] into a
+//! smaller number of larger files, mirroring the image/delta-layer compaction used by
+//! layered storage engines like Neon and Iceberg's data-file rewrite.
+
+use crate::{endpoints::Context, errors::ServerError};
+use log::info;
+use mosaicod_core::types::TimestampRange;
+use mosaicod_marshal::ActionResponse;
+use mosaicod_repo::FacadeTopic;
+
+/// Compacts the given topic's segments overlapping `range`.
+pub async fn execute(
+    ctx: &Context,
+    name: String,
+    range: TimestampRange,
+) -> Result<ActionResponse, ServerError> {
+    info!("compacting topic `{}` over range {}", name, range);
+
+    let handle = FacadeTopic::new(name, ctx.store.clone(), ctx.repo.clone());
+    let metadata = handle.metadata().await?;
+
+    let summary = handle
+        .compact(
+            range,
+            metadata.properties.serialization_format,
+            ctx.timeseries_querier.clone(),
+        )
+        .await?;
+
+    Ok(ActionResponse::TopicCompact(summary.into()))
+}
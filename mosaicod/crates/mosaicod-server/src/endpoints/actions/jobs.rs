@@ -0,0 +1,23 @@
+//! Job action handlers.
+//!
+//! Exposes the [`crate::jobs::JobScheduler`] tracked on [`Context`] so a caller can see
+//! progress on a long-running operation (e.g. topic consolidation) and cancel it, instead of
+//! that operation being a fire-and-forget call with no visibility or recoverability.
+
+use crate::{endpoints::Context, errors::ServerError};
+use mosaicod_marshal::ActionResponse;
+use mosaicod_repo::FacadeJob;
+
+/// Returns the current [`mosaicod_core::types::JobReport`] for `job_id`.
+pub async fn report(ctx: &Context, job_id: String) -> Result<ActionResponse, ServerError> {
+    let report = FacadeJob::for_existing(job_id).report(&ctx.repo).await?;
+    Ok(ActionResponse::JobReport(report.into()))
+}
+
+/// Requests cancellation of `job_id`. Cancellation is cooperative: the job stops
+/// scheduling new tasks the next time it checks in, it does not abort a task already in
+/// flight.
+pub async fn cancel(ctx: &Context, job_id: String) -> Result<ActionResponse, ServerError> {
+    let cancelled = ctx.jobs.cancel(&job_id);
+    Ok(ActionResponse::JobCancel(cancelled))
+}
@@ -0,0 +1,14 @@
+//! Metrics action handler.
+//!
+//! Exposes [`crate::metrics::Metrics`] tracked on [`Context`] in Prometheus text format, so
+//! an operator can scrape it through a Flight `DoAction` instead of standing up a separate
+//! HTTP listener just for this.
+
+use crate::{endpoints::Context, errors::ServerError};
+use mosaicod_marshal::ActionResponse;
+
+/// Renders every metric currently registered on `ctx` in Prometheus text exposition format.
+pub async fn export(ctx: &Context) -> Result<ActionResponse, ServerError> {
+    let rendered = ctx.metrics.encode()?;
+    Ok(ActionResponse::MetricsExport(rendered))
+}
@@ -3,7 +3,11 @@
 //! This module contains free functions for handling Flight actions,
 //! organized by resource type (sequence, topic, layer, query).
 
+pub mod batch;
+pub mod compaction;
+pub mod jobs;
 pub mod layer;
+pub mod metrics;
 pub mod query;
 pub mod sequence;
 pub mod topic;
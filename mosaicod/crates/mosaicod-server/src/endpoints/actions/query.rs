@@ -2,22 +2,35 @@
 
 use crate::{endpoints::Context, errors::ServerError};
 use log::{info, trace};
+use mosaicod_core::types::Timestamp;
 use mosaicod_marshal::{self as marshal, ActionResponse};
 use mosaicod_repo::FacadeQuery;
 
 /// Executes a query and returns matching groups.
+///
+/// If `as_of` is provided, the query is answered as of that point in time: only rows
+/// whose timeseries timestamp is at or before `as_of` are visible, letting a client
+/// read the state of a topic as it looked at a past instant instead of its current tip.
 pub async fn execute(
     ctx: &Context,
     query: serde_json::Value,
+    as_of: Option<i64>,
 ) -> Result<ActionResponse, ServerError> {
-    info!("performing a query");
+    info!("performing a query, as_of={:?}", as_of);
 
     let filter = marshal::query_filter_from_serde_value(query)?;
 
     trace!("query filter: {:?}", filter);
 
-    let groups =
-        FacadeQuery::query(filter, ctx.timeseries_querier.clone(), ctx.repo.clone()).await?;
+    let as_of = as_of.map(Timestamp::from);
+
+    let groups = FacadeQuery::query(
+        filter,
+        as_of,
+        ctx.timeseries_querier.clone(),
+        ctx.repo.clone(),
+    )
+    .await?;
 
     trace!("groups found: {:?}", groups);
 
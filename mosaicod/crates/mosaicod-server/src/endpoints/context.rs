@@ -1,3 +1,5 @@
+use crate::jobs::JobSchedulerRef;
+use crate::metrics::MetricsRef;
 use mosaicod_query as query;
 use mosaicod_repo as repo;
 use mosaicod_store as store;
@@ -11,6 +13,15 @@ pub struct Context {
     pub store: store::StoreRef,
     pub repo: repo::Repository,
     pub timeseries_querier: query::TimeseriesRef,
+    /// Tracks every job (e.g. topic consolidation) this process is currently running, so
+    /// the `jobs` action handlers can report progress and request cancellation. See
+    /// [`crate::jobs::JobScheduler`].
+    pub jobs: JobSchedulerRef,
+    /// Counters and histograms for this process's endpoints, exported through the
+    /// `metrics_export` action (see [`crate::endpoints::actions::metrics`]). Enabled by
+    /// default; swap in [`crate::metrics::Metrics::disabled`] via [`Self::with_metrics`] to
+    /// turn it into a no-op recorder.
+    pub metrics: MetricsRef,
 }
 
 impl Context {
@@ -23,6 +34,15 @@ impl Context {
             store,
             repo,
             timeseries_querier: ts_gw,
+            jobs: crate::jobs::JobScheduler::new(),
+            metrics: crate::metrics::Metrics::new(),
         }
     }
+
+    /// Overrides the metrics recorder, e.g. with [`crate::metrics::Metrics::disabled`] when
+    /// this deployment doesn't want the bookkeeping overhead.
+    pub fn with_metrics(mut self, metrics: MetricsRef) -> Self {
+        self.metrics = metrics;
+        self
+    }
 }
@@ -0,0 +1,125 @@
+//! Parses the small criteria grammar `list_flights` accepts in `Criteria.expression`: a path
+//! prefix, optionally followed by `?` and `&`-separated `field<op>value` predicates, e.g.
+//! `"sensors/temp?region=eu&site!=test"`. Predicates are restricted to the comparison ops
+//! that make sense with a single value token (equality and ordering); `Between`, `In` and
+//! `Match` aren't expressible in this grammar.
+use mosaicod_query::{Op, OntologyField, Value};
+
+/// A parsed `list_flights` criteria expression: sequences are kept when their path starts
+/// with `prefix` and their known statistics can't be proven to violate any predicate in
+/// `predicates`.
+#[derive(Debug, Default)]
+pub struct ListingCriteria {
+    pub prefix: String,
+    pub predicates: Vec<(OntologyField, Op<Value>)>,
+}
+
+/// Parses `expression` into a [`ListingCriteria`]. Returns `None` on anything that doesn't
+/// match the grammar (rather than a best-effort partial result), so the caller can fail the
+/// whole request cleanly with `UnsupportedDescriptor` instead of silently under-filtering.
+pub fn parse_criteria(expression: &str) -> Option<ListingCriteria> {
+    let (prefix, predicates_part) = match expression.split_once('?') {
+        Some((prefix, rest)) => (prefix, rest),
+        None => (expression, ""),
+    };
+    // `"/"` names the root, same as the empty prefix.
+    let prefix = if prefix == "/" { "" } else { prefix };
+
+    let predicates = if predicates_part.is_empty() {
+        Vec::new()
+    } else {
+        predicates_part
+            .split('&')
+            .map(parse_predicate)
+            .collect::<Option<Vec<_>>>()?
+    };
+
+    Some(ListingCriteria {
+        prefix: prefix.to_owned(),
+        predicates,
+    })
+}
+
+/// Parses one `field<op>value` predicate. Longer operators are tried first so `!=`/`<=`/`>=`
+/// aren't mis-split on their `=`/`<`/`>` prefix with a stray character left on the value.
+fn parse_predicate(predicate: &str) -> Option<(OntologyField, Op<Value>)> {
+    const OPS: &[(&str, fn(Value) -> Op<Value>)] = &[
+        ("!=", Op::Neq),
+        ("<=", Op::Leq),
+        (">=", Op::Geq),
+        ("=", Op::Eq),
+        ("<", Op::Lt),
+        (">", Op::Gt),
+    ];
+
+    for (token, build) in OPS {
+        if let Some((field, value)) = predicate.split_once(token) {
+            if field.is_empty() || value.is_empty() {
+                return None;
+            }
+            let field = OntologyField::try_new(field.to_owned()).ok()?;
+            return Some((field, build(parse_value(value))));
+        }
+    }
+
+    None
+}
+
+/// Parses `raw` as the most specific `Value` variant it fits: integer, then float, then
+/// boolean, falling back to text.
+fn parse_value(raw: &str) -> Value {
+    if let Ok(v) = raw.parse::<i64>() {
+        Value::Integer(v)
+    } else if let Ok(v) = raw.parse::<f64>() {
+        Value::Float(v)
+    } else if let Ok(v) = raw.parse::<bool>() {
+        Value::Boolean(v)
+    } else {
+        Value::Text(raw.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_criteria_prefix_only() {
+        let criteria = parse_criteria("sensors/temp").unwrap();
+        assert_eq!(criteria.prefix, "sensors/temp");
+        assert!(criteria.predicates.is_empty());
+    }
+
+    #[test]
+    fn parse_criteria_root_is_empty_prefix() {
+        let criteria = parse_criteria("").unwrap();
+        assert_eq!(criteria.prefix, "");
+        assert!(criteria.predicates.is_empty());
+    }
+
+    #[test]
+    fn parse_criteria_with_predicates() {
+        let criteria = parse_criteria("sensors?region=eu&value>=10&site!=test").unwrap();
+        assert_eq!(criteria.prefix, "sensors");
+        assert_eq!(criteria.predicates.len(), 3);
+
+        let (field, op) = &criteria.predicates[0];
+        assert_eq!(field.field(), "region");
+        assert!(matches!(op, Op::Eq(Value::Text(v)) if v == "eu"));
+
+        let (field, op) = &criteria.predicates[1];
+        assert_eq!(field.field(), "value");
+        assert!(matches!(op, Op::Geq(Value::Integer(10))));
+
+        let (field, op) = &criteria.predicates[2];
+        assert_eq!(field.field(), "site");
+        assert!(matches!(op, Op::Neq(Value::Text(v)) if v == "test"));
+    }
+
+    #[test]
+    fn parse_criteria_rejects_malformed_predicate() {
+        assert!(parse_criteria("sensors?not_a_predicate").is_none());
+        assert!(parse_criteria("sensors?=no_field").is_none());
+        assert!(parse_criteria("sensors?no_value=").is_none());
+    }
+}
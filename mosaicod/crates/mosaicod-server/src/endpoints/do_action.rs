@@ -3,7 +3,10 @@
 //! This module implements the main dispatcher for Flight DoAction requests,
 //! delegating to specialized handler functions for each action category.
 
-use super::actions::{layer, query as query_action, sequence, topic};
+use super::actions::{
+    batch, compaction, jobs, layer, metrics as metrics_action, query as query_action, sequence,
+    topic,
+};
 use crate::{endpoints::Context, errors::ServerError};
 use mosaicod_marshal::{ActionRequest, ActionResponse};
 
@@ -50,6 +53,16 @@ pub async fn do_action(ctx: Context, action: ActionRequest) -> Result<ActionResp
         ActionRequest::TopicNotifyList(data) => topic::notify_list(&ctx, data.name).await,
         ActionRequest::TopicNotifyPurge(data) => topic::notify_purge(&ctx, data.name).await,
         ActionRequest::TopicSystemInfo(data) => topic::system_info(&ctx, data.name).await,
+        ActionRequest::TopicCompact(data) => {
+            compaction::execute(&ctx, data.name, data.range).await
+        }
+
+        // Job actions
+        ActionRequest::JobReport(data) => jobs::report(&ctx, data.job_id).await,
+        ActionRequest::JobCancel(data) => jobs::cancel(&ctx, data.job_id).await,
+
+        // Metrics actions
+        ActionRequest::MetricsExport(_) => metrics_action::export(&ctx).await,
 
         // Layer actions
         ActionRequest::LayerCreate(data) => layer::create(&ctx, data.name, data.description).await,
@@ -60,7 +73,10 @@ pub async fn do_action(ctx: Context, action: ActionRequest) -> Result<ActionResp
         ActionRequest::LayerList(_) => layer::list(&ctx).await,
 
         // Query actions
-        ActionRequest::Query(data) => query_action::execute(&ctx, data.query).await,
+        ActionRequest::Query(data) => query_action::execute(&ctx, data.query, data.as_of).await,
+
+        // Batch actions
+        ActionRequest::Batch(data) => batch::execute(&ctx, data.actions, data.strict).await,
     }
 }
 
@@ -1,62 +1,94 @@
 //! Implementation of the Arrow Flight `list_flights` endpoint.
 //!
-//! Returns a stream of all available sequences when queried at the root level.
+//! Returns a stream of sequences matching a small prefix-and-predicate criteria grammar
+//! (see [`super::criteria`]), each annotated with what the repository already knows about
+//! its contents.
 use super::Context;
+use super::criteria::parse_criteria;
 use crate::errors::ServerError;
 use arrow_flight::{Criteria, FlightDescriptor, FlightEndpoint, FlightInfo, Ticket};
 use futures::stream::BoxStream;
 use log::{info, trace};
 use mosaicod_core::types::Resource;
+use mosaicod_query as query;
 use mosaicod_repo as repo;
+use serde::Serialize;
 use tonic::Status;
 
-/// Lists all available flights (sequences) in the repository.
+/// Sequence-level discovery metadata carried in a listed flight's `FlightEndpoint`
+/// `app_metadata`, so a client can plan a read without a separate `GetFlightInfo` round trip.
+#[derive(Serialize)]
+struct ListingAppMetadata {
+    timestamp_min: Option<i64>,
+    timestamp_max: Option<i64>,
+    row_count_estimate: i64,
+}
+
+/// Lists flights (sequences) whose path matches `criteria`'s prefix and whose known
+/// statistics satisfy its predicates.
 ///
-/// When clients query with an empty or root path ("" or "/"), this function
-/// returns a streamed list of all sequences. Each sequence is represented
-/// as a minimal `FlightInfo` containing only the sequence identifier.
+/// `criteria.expression` is parsed via [`parse_criteria`]; the empty string and `"/"` both
+/// parse to the root (match-everything) prefix. Malformed criteria fail cleanly with
+/// [`ServerError::UnsupportedDescriptor`] rather than falling back to an unfiltered dump.
 pub async fn list_flights(
     ctx: Context,
     criteria: Criteria,
 ) -> Result<BoxStream<'static, Result<FlightInfo, Status>>, ServerError> {
-    // Validate criteria - only root-level queries are supported
     let expression = String::from_utf8_lossy(&criteria.expression);
-    let is_root_query = expression.is_empty() || expression == "/";
-
-    if !is_root_query {
-        return Err(ServerError::UnsupportedDescriptor);
-    }
+    let criteria = parse_criteria(&expression).ok_or(ServerError::UnsupportedDescriptor)?;
 
-    info!("listing all sequences");
+    info!(
+        "listing sequences matching prefix `{}` ({} predicate(s))",
+        criteria.prefix,
+        criteria.predicates.len()
+    );
 
     // Fetch all sequences from repository
-    let sequences = repo::FacadeSequence::all(ctx.repo).await?;
+    let sequences = repo::FacadeSequence::all(ctx.repo.clone()).await?;
+
+    trace!("found {} sequences before filtering", sequences.len());
+
+    let mut flight_infos: Vec<Result<FlightInfo, Status>> = Vec::new();
 
-    trace!("found {} sequences", sequences.len());
+    for locator in sequences {
+        let sequence_name = locator.name().to_string();
 
-    // Convert each sequence locator to a minimal FlightInfo
-    let flight_infos: Vec<Result<FlightInfo, Status>> = sequences
-        .into_iter()
-        .map(|locator| {
-            let sequence_name = locator.name().to_string();
+        if !sequence_name.starts_with(criteria.prefix.as_str()) {
+            continue;
+        }
 
-            // Create flight descriptor with the sequence path
-            let descriptor = FlightDescriptor::new_path(vec![sequence_name.clone()]);
+        let summary = repo::FacadeSequence::listing_summary(&locator, ctx.repo.clone()).await?;
 
-            // Create a ticket using the sequence name
-            let endpoint = FlightEndpoint::new().with_ticket(Ticket {
+        if !criteria.predicates.is_empty()
+            && !query::matches_ontology_stats(&summary.stats, &criteria.predicates)
+        {
+            continue;
+        }
+
+        let descriptor = FlightDescriptor::new_path(vec![sequence_name.clone()]);
+
+        let app_metadata = ListingAppMetadata {
+            timestamp_min: summary.timestamp_range.as_ref().map(|r| r.start.as_i64()),
+            timestamp_max: summary.timestamp_range.as_ref().map(|r| r.end.as_i64()),
+            row_count_estimate: summary.row_count_estimate,
+        };
+
+        let endpoint = FlightEndpoint::new()
+            .with_ticket(Ticket {
                 ticket: sequence_name.into(),
-            });
+            })
+            .with_app_metadata(serde_json::to_vec(&app_metadata).unwrap_or_default());
 
-            let flight_info = FlightInfo::new()
-                .with_descriptor(descriptor)
-                .with_endpoint(endpoint);
+        let flight_info = FlightInfo::new()
+            .with_descriptor(descriptor)
+            .with_endpoint(endpoint)
+            .with_total_records(summary.row_count_estimate);
+
+        flight_infos.push(Ok(flight_info));
+    }
 
-            Ok(flight_info)
-        })
-        .collect();
+    trace!("{} sequence(s) matched criteria", flight_infos.len());
 
-    // Create the stream from the vector
     let stream = futures::stream::iter(flight_infos);
 
     Ok(Box::pin(stream))
@@ -0,0 +1,174 @@
+//! Job scheduler for long-running, resumable server-side operations.
+//!
+//! A job (e.g. topic consolidation, see [`crate::endpoints::actions::compaction`]) is
+//! decomposed into independent [`Task`]s. Each task commits its own work and then reports
+//! completion through [`mosaicod_repo::FacadeJob`], so a [`JobReport`] read at any point
+//! reflects only fully-committed progress. [`JobScheduler`] tracks the [`CancellationToken`]
+//! for every job it has running in this process so a `DoAction` can request cancellation, and
+//! [`JobScheduler::resume`] re-enqueues the incomplete tasks of any job a crashed process left
+//! in [`JobState::Running`].
+use mosaicod_core::types::{JobKind, JobReport, JobState};
+use mosaicod_repo::{FacadeError, FacadeJob, Repository};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+pub type JobSchedulerRef = Arc<JobScheduler>;
+
+/// One independent unit of work within a job, e.g. aggregating one chunk's stats or
+/// computing a topic's manifest timestamp. Checked for cancellation between tasks rather
+/// than mid-task, so a task already committing its work always runs to completion.
+pub trait Task: Send + Sync {
+    fn run<'a>(
+        &'a self,
+        cancel: &'a CancellationToken,
+    ) -> Pin<Box<dyn Future<Output = Result<(), FacadeError>> + Send + 'a>>;
+}
+
+/// A cooperative cancellation signal shared between a [`JobScheduler`] entry and every
+/// [`Task`] belonging to that job.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Tracks the [`CancellationToken`] of every job currently running in this process, so
+/// `DoAction` handlers can query progress and request cancellation by job id.
+#[derive(Default)]
+pub struct JobScheduler {
+    running: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl JobScheduler {
+    pub fn new() -> JobSchedulerRef {
+        Arc::new(Self {
+            running: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Persists a new [`JobReport`] and runs `tasks` against it in order, checking
+    /// `cancel` between each one. Stops scheduling new tasks (without rolling back any
+    /// already-committed one) as soon as cancellation is requested or a task fails, so the
+    /// persisted report always reflects real, committed progress.
+    pub async fn run(
+        &self,
+        kind: JobKind,
+        tasks: Vec<Box<dyn Task>>,
+        repo: &Repository,
+    ) -> Result<JobReport, FacadeError> {
+        let facade = FacadeJob::create(kind, tasks.len() as u32, repo).await?;
+        let cancel = CancellationToken::new();
+
+        self.running
+            .lock()
+            .expect("job scheduler poisoned")
+            .insert(facade.id().to_owned(), cancel.clone());
+
+        let result = self.run_tasks(&facade, tasks, &cancel, repo).await;
+
+        self.running
+            .lock()
+            .expect("job scheduler poisoned")
+            .remove(facade.id());
+
+        result
+    }
+
+    async fn run_tasks(
+        &self,
+        facade: &FacadeJob,
+        tasks: Vec<Box<dyn Task>>,
+        cancel: &CancellationToken,
+        repo: &Repository,
+    ) -> Result<JobReport, FacadeError> {
+        for task in &tasks {
+            if cancel.is_cancelled() {
+                facade.finalize(JobState::Cancelled, repo).await?;
+                return facade.report(repo).await;
+            }
+
+            if let Err(err) = task.run(cancel).await {
+                facade.fail(err.to_string(), repo).await?;
+                return facade.report(repo).await;
+            }
+
+            facade.record_task_completed(repo).await?;
+        }
+
+        facade.finalize(JobState::Completed, repo).await?;
+        facade.report(repo).await
+    }
+
+    /// Requests cancellation of the job `job_id` if it's currently running in this
+    /// process. Returns `false` if no such job is tracked here (e.g. it already finished,
+    /// or it's running in a different process and will only stop once this one checks in
+    /// via [`Self::resume`] after a restart).
+    pub fn cancel(&self, job_id: &str) -> bool {
+        match self.running.lock().expect("job scheduler poisoned").get(job_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Requests cancellation of every job currently tracked in this process. Intended for
+    /// graceful shutdown: each job's tasks already check in with `cancel` between steps, so
+    /// once the in-flight task (if any) finishes committing, every job stops scheduling new
+    /// tasks and finalizes to [`JobState::Cancelled`] with its progress already checkpointed
+    /// by [`mosaicod_repo::FacadeJob`] rather than left in an inconsistent state.
+    pub fn cancel_all(&self) {
+        for token in self.running.lock().expect("job scheduler poisoned").values() {
+            token.cancel();
+        }
+    }
+
+    /// Scans for jobs left in [`JobState::Running`] by a prior process (crashed before
+    /// reaching a terminal state) and re-enqueues their incomplete tasks via `decompose`.
+    /// Idempotent: `decompose` is expected to skip any task whose effect is already
+    /// committed (e.g. a chunk whose stats were already persisted before the crash), so
+    /// resuming a job that was actually further along than its last persisted
+    /// `completed_tasks` count just re-runs a handful of no-ops.
+    pub async fn resume(
+        &self,
+        repo: &Repository,
+        decompose: impl Fn(&JobReport) -> Vec<Box<dyn Task>>,
+    ) -> Result<Vec<JobReport>, FacadeError> {
+        let mut resumed = Vec::new();
+
+        for report in FacadeJob::list_running(repo).await? {
+            let tasks = decompose(&report);
+            let facade = FacadeJob::for_existing(report.id.clone());
+            let cancel = CancellationToken::new();
+
+            self.running
+                .lock()
+                .expect("job scheduler poisoned")
+                .insert(report.id.clone(), cancel.clone());
+
+            resumed.push(self.run_tasks(&facade, tasks, &cancel, repo).await?);
+
+            self.running
+                .lock()
+                .expect("job scheduler poisoned")
+                .remove(&report.id);
+        }
+
+        Ok(resumed)
+    }
+}
@@ -0,0 +1,213 @@
+//! Prometheus metrics for [`crate::endpoints::Context`]'s hot paths.
+//!
+//! [`Metrics`] is cheap to hold even when disabled: every recording method checks a single
+//! `Option` and returns immediately instead of touching a registry, so a deployment that
+//! doesn't scrape metrics pays only that branch per call. Recording always happens after
+//! the work it describes has already committed, never while a repo transaction is open.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use prometheus::{
+    Encoder, Histogram, HistogramVec, IntCounterVec, IntGaugeVec, Registry, TextEncoder,
+    histogram_opts, opts,
+};
+
+/// Labels shared by most of this module's metrics: the kind of resource being served and
+/// the ontology tag of the topic involved, so operators can attribute load per topic.
+const LABELS: &[&str] = &["resource_type", "ontology_tag"];
+
+pub type MetricsRef = Arc<Metrics>;
+
+struct Inner {
+    registry: Registry,
+    do_get_bytes_total: IntCounterVec,
+    do_get_batches_total: IntCounterVec,
+    do_get_batch_size: IntGaugeVec,
+    query_latency_seconds: HistogramVec,
+    stats_inserted_total: IntCounterVec,
+    chunk_created_total: IntCounterVec,
+    chunk_size_bytes: HistogramVec,
+    commit_latency_seconds: Histogram,
+}
+
+/// Records counters and histograms for `Context`'s endpoints and exposes them in
+/// Prometheus text format through the `metrics_export` action (see
+/// [`crate::endpoints::actions::metrics`]). Disabled instances (see [`Metrics::disabled`])
+/// skip every recording call.
+pub struct Metrics(Option<Inner>);
+
+impl Metrics {
+    /// Builds a fresh, enabled metric set registered on its own [`Registry`].
+    pub fn new() -> MetricsRef {
+        let registry = Registry::new();
+
+        let do_get_bytes_total = IntCounterVec::new(
+            opts!("mosaicod_do_get_bytes_total", "Bytes streamed by DoGet"),
+            LABELS,
+        )
+        .expect("metric definition is valid");
+        let do_get_batches_total = IntCounterVec::new(
+            opts!(
+                "mosaicod_do_get_batches_total",
+                "Record batches streamed by DoGet"
+            ),
+            LABELS,
+        )
+        .expect("metric definition is valid");
+        let do_get_batch_size = IntGaugeVec::new(
+            opts!(
+                "mosaicod_do_get_batch_size",
+                "Batch size chosen by compute_optimal_batch_size for the last DoGet"
+            ),
+            LABELS,
+        )
+        .expect("metric definition is valid");
+        let query_latency_seconds = HistogramVec::new(
+            histogram_opts!(
+                "mosaicod_query_latency_seconds",
+                "Latency of ts_engine.read calls"
+            ),
+            LABELS,
+        )
+        .expect("metric definition is valid");
+        let stats_inserted_total = IntCounterVec::new(
+            opts!(
+                "mosaicod_stats_inserted_total",
+                "Column stats inserted per chunk, labeled by stats kind"
+            ),
+            &["resource_type", "ontology_tag", "kind"],
+        )
+        .expect("metric definition is valid");
+        let chunk_created_total = IntCounterVec::new(
+            opts!("mosaicod_chunk_created_total", "Chunks created"),
+            LABELS,
+        )
+        .expect("metric definition is valid");
+        let chunk_size_bytes = HistogramVec::new(
+            histogram_opts!("mosaicod_chunk_size_bytes", "Size distribution of created chunks"),
+            LABELS,
+        )
+        .expect("metric definition is valid");
+        let commit_latency_seconds = Histogram::with_opts(histogram_opts!(
+            "mosaicod_commit_latency_seconds",
+            "Latency of FacadeChunk::finalize's transaction commit"
+        ))
+        .expect("metric definition is valid");
+
+        for collector in [
+            Box::new(do_get_bytes_total.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(do_get_batches_total.clone()),
+            Box::new(do_get_batch_size.clone()),
+            Box::new(query_latency_seconds.clone()),
+            Box::new(stats_inserted_total.clone()),
+            Box::new(chunk_created_total.clone()),
+            Box::new(chunk_size_bytes.clone()),
+            Box::new(commit_latency_seconds.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("metric name is unique within this registry");
+        }
+
+        Arc::new(Self(Some(Inner {
+            registry,
+            do_get_bytes_total,
+            do_get_batches_total,
+            do_get_batch_size,
+            query_latency_seconds,
+            stats_inserted_total,
+            chunk_created_total,
+            chunk_size_bytes,
+            commit_latency_seconds,
+        })))
+    }
+
+    /// A no-op instance: every recording call below returns immediately.
+    pub fn disabled() -> MetricsRef {
+        Arc::new(Self(None))
+    }
+
+    pub fn record_do_get_batch(&self, resource_type: &str, ontology_tag: &str, bytes: usize) {
+        let Some(inner) = &self.0 else { return };
+        inner
+            .do_get_bytes_total
+            .with_label_values(&[resource_type, ontology_tag])
+            .inc_by(bytes as u64);
+        inner
+            .do_get_batches_total
+            .with_label_values(&[resource_type, ontology_tag])
+            .inc();
+    }
+
+    pub fn record_batch_size(&self, resource_type: &str, ontology_tag: &str, batch_size: usize) {
+        let Some(inner) = &self.0 else { return };
+        inner
+            .do_get_batch_size
+            .with_label_values(&[resource_type, ontology_tag])
+            .set(batch_size as i64);
+    }
+
+    pub fn observe_query_latency(&self, resource_type: &str, ontology_tag: &str, latency: Duration) {
+        let Some(inner) = &self.0 else { return };
+        inner
+            .query_latency_seconds
+            .with_label_values(&[resource_type, ontology_tag])
+            .observe(latency.as_secs_f64());
+    }
+
+    pub fn record_stats_inserted(
+        &self,
+        resource_type: &str,
+        ontology_tag: &str,
+        numeric_count: usize,
+        textual_count: usize,
+    ) {
+        let Some(inner) = &self.0 else { return };
+        inner
+            .stats_inserted_total
+            .with_label_values(&[resource_type, ontology_tag, "numeric"])
+            .inc_by(numeric_count as u64);
+        inner
+            .stats_inserted_total
+            .with_label_values(&[resource_type, ontology_tag, "textual"])
+            .inc_by(textual_count as u64);
+    }
+
+    pub fn record_chunk_created(&self, resource_type: &str, ontology_tag: &str, size_bytes: i64) {
+        let Some(inner) = &self.0 else { return };
+        inner
+            .chunk_created_total
+            .with_label_values(&[resource_type, ontology_tag])
+            .inc();
+        inner
+            .chunk_size_bytes
+            .with_label_values(&[resource_type, ontology_tag])
+            .observe(size_bytes as f64);
+    }
+
+    pub fn observe_commit_latency(&self, latency: Duration) {
+        let Some(inner) = &self.0 else { return };
+        inner.commit_latency_seconds.observe(latency.as_secs_f64());
+    }
+
+    /// Renders every registered metric in Prometheus text exposition format.
+    pub fn encode(&self) -> Result<String, MetricsError> {
+        let Some(inner) = &self.0 else {
+            return Ok(String::new());
+        };
+
+        let mut buf = Vec::new();
+        TextEncoder::new()
+            .encode(&inner.registry.gather(), &mut buf)
+            .map_err(|e| MetricsError::EncodeFailed(e.to_string()))?;
+
+        String::from_utf8(buf).map_err(|e| MetricsError::EncodeFailed(e.to_string()))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MetricsError {
+    #[error("failed to encode metrics :: {0}")]
+    EncodeFailed(String),
+}
@@ -6,41 +6,337 @@ use serde::{Deserialize, Serialize};
 // GET FLIGHT INFO CMD
 // ////////////////////////////////////////////////////////////////////////////
 
+/// Builds a [`types::TimestampRange`] from a nullable `[start, end]` pair, the JSON wire
+/// representation shared by [`GetFlightInfoCmd`] and [`GetFlightInfoBatchEntry`]. Returns
+/// `None` when both ends are unbounded, i.e. no range was actually requested.
+fn build_timestamp_range(
+    start: Option<i64>,
+    end: Option<i64>,
+) -> Option<types::TimestampRange> {
+    let up = end.map_or_else(types::Timestamp::unbounded_pos, |e| e.into());
+    let lb = start.map_or_else(types::Timestamp::unbounded_neg, |e| e.into());
+
+    if lb.is_unbounded() && up.is_unbounded() {
+        None
+    } else {
+        Some(types::TimestampRange::between(lb, up))
+    }
+}
+
+/// Non-exported type for deserializing one `{ column, op, value }` predicate shared by
+/// [`GetFlightInfoCmd`] and [`GetFlightInfoBatchEntry`]'s `filters` array.
+#[derive(Deserialize)]
+struct JsonValueFilter {
+    column: String,
+    op: String,
+    value: serde_json::Value,
+}
+
+/// Converts a single JSON scalar into a [`types::flight::FilterValue`].
+fn json_scalar_to_filter_value(
+    v: &serde_json::Value,
+) -> Result<types::flight::FilterValue, super::Error> {
+    match v {
+        serde_json::Value::Bool(b) => Ok(types::flight::FilterValue::Boolean(*b)),
+        serde_json::Value::String(s) => Ok(types::flight::FilterValue::Text(s.clone())),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Ok(types::flight::FilterValue::Integer(i))
+            } else if let Some(f) = n.as_f64() {
+                Ok(types::flight::FilterValue::Float(f))
+            } else {
+                Err(super::Error::DeserializationError(format!(
+                    "unsupported filter value `{n}`"
+                )))
+            }
+        }
+        other => Err(super::Error::DeserializationError(format!(
+            "unsupported filter value `{other}`, expected a string, number, or boolean"
+        ))),
+    }
+}
+
+impl TryFrom<JsonValueFilter> for types::flight::ValueFilter {
+    type Error = super::Error;
+
+    fn try_from(value: JsonValueFilter) -> Result<Self, Self::Error> {
+        let (op, values) = match value.op.as_str() {
+            "eq" => (
+                types::flight::FilterOp::Eq,
+                vec![json_scalar_to_filter_value(&value.value)?],
+            ),
+            "neq" => (
+                types::flight::FilterOp::Neq,
+                vec![json_scalar_to_filter_value(&value.value)?],
+            ),
+            "lt" => (
+                types::flight::FilterOp::Lt,
+                vec![json_scalar_to_filter_value(&value.value)?],
+            ),
+            "lte" => (
+                types::flight::FilterOp::Lte,
+                vec![json_scalar_to_filter_value(&value.value)?],
+            ),
+            "gt" => (
+                types::flight::FilterOp::Gt,
+                vec![json_scalar_to_filter_value(&value.value)?],
+            ),
+            "gte" => (
+                types::flight::FilterOp::Gte,
+                vec![json_scalar_to_filter_value(&value.value)?],
+            ),
+            "in" => {
+                let items = value.value.as_array().ok_or_else(|| {
+                    super::Error::DeserializationError(
+                        "`in` filter requires an array value".to_string(),
+                    )
+                })?;
+                let values = items
+                    .iter()
+                    .map(json_scalar_to_filter_value)
+                    .collect::<Result<Vec<_>, _>>()?;
+                (types::flight::FilterOp::In, values)
+            }
+            other => {
+                return Err(super::Error::DeserializationError(format!(
+                    "unsupported filter operator `{other}`"
+                )));
+            }
+        };
+
+        Ok(types::flight::ValueFilter {
+            column: value.column,
+            op,
+            values,
+        })
+    }
+}
+
+/// Converts the optional `filters` array shared by [`GetFlightInfoCmd`] and
+/// [`GetFlightInfoBatchEntry`], defaulting to no filters when absent.
+fn parse_filters(
+    filters: Option<Vec<JsonValueFilter>>,
+) -> Result<Vec<types::flight::ValueFilter>, super::Error> {
+    filters.unwrap_or_default().into_iter().map(TryInto::try_into).collect()
+}
+
 /// Non-exported type for deserialize [`GetFlightInfoCmd`]
 #[derive(Deserialize)]
 struct GetFlightInfoCmd {
     resource_locator: String,
     timestamp_ns_start: Option<i64>,
     timestamp_ns_end: Option<i64>,
+    follow: Option<bool>,
+    follow_timeout_secs: Option<u64>,
+    columns: Option<Vec<String>>,
+    filters: Option<Vec<JsonValueFilter>>,
 }
 
-impl From<GetFlightInfoCmd> for types::flight::GetFlightInfoCmd {
-    fn from(value: GetFlightInfoCmd) -> Self {
-        let up = value
-            .timestamp_ns_end
-            .map_or_else(types::Timestamp::unbounded_pos, |e| e.into());
-
-        let lb = value
-            .timestamp_ns_start
-            .map_or_else(types::Timestamp::unbounded_neg, |e| e.into());
+impl TryFrom<GetFlightInfoCmd> for types::flight::GetFlightInfoCmd {
+    type Error = super::Error;
 
-        let mut ts_range: Option<types::TimestampRange> = None;
-        if !lb.is_unbounded() || !up.is_unbounded() {
-            ts_range = Some(types::TimestampRange::between(lb, up));
-        }
-
-        types::flight::GetFlightInfoCmd {
+    fn try_from(value: GetFlightInfoCmd) -> Result<Self, Self::Error> {
+        Ok(types::flight::GetFlightInfoCmd {
             resource_locator: value.resource_locator,
-            timestamp_range: ts_range,
-        }
+            timestamp_range: build_timestamp_range(value.timestamp_ns_start, value.timestamp_ns_end),
+            follow: value.follow.unwrap_or(false),
+            follow_timeout_secs: value.follow_timeout_secs,
+            columns: value.columns.unwrap_or_default(),
+            filters: parse_filters(value.filters)?,
+        })
     }
 }
 
 /// Convert a raw flight command into a [`GetFlightInfoCmd`]
 pub fn get_flight_info_cmd(v: &[u8]) -> Result<types::flight::GetFlightInfoCmd, super::Error> {
     serde_json::from_slice::<GetFlightInfoCmd>(v)
-        .map_err(|e| super::Error::DeserializationError(e.to_string()))
-        .map(|v| v.into())
+        .map_err(|e| super::Error::DeserializationError(e.to_string()))?
+        .try_into()
+}
+
+// ////////////////////////////////////////////////////////////////////////////
+// GET FLIGHT INFO BATCH CMD
+// ////////////////////////////////////////////////////////////////////////////
+
+/// Non-exported type for deserializing one entry of a [`GetFlightInfoBatchCmd`]
+#[derive(Deserialize)]
+struct GetFlightInfoBatchEntry {
+    resource_locator: String,
+    timestamp_ns_start: Option<i64>,
+    timestamp_ns_end: Option<i64>,
+    follow: Option<bool>,
+    follow_timeout_secs: Option<u64>,
+    columns: Option<Vec<String>>,
+    filters: Option<Vec<JsonValueFilter>>,
+}
+
+impl TryFrom<GetFlightInfoBatchEntry> for types::flight::GetFlightInfoBatchEntry {
+    type Error = super::Error;
+
+    fn try_from(value: GetFlightInfoBatchEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            resource_locator: value.resource_locator,
+            timestamp_range: build_timestamp_range(value.timestamp_ns_start, value.timestamp_ns_end),
+            follow: value.follow.unwrap_or(false),
+            follow_timeout_secs: value.follow_timeout_secs,
+            columns: value.columns.unwrap_or_default(),
+            filters: parse_filters(value.filters)?,
+        })
+    }
+}
+
+/// Non-exported type for deserialize [`GetFlightInfoBatchCmd`]
+#[derive(Deserialize)]
+struct GetFlightInfoBatchCmd {
+    resource_locators: Vec<GetFlightInfoBatchEntry>,
+}
+
+impl TryFrom<GetFlightInfoBatchCmd> for types::flight::GetFlightInfoBatchCmd {
+    type Error = super::Error;
+
+    fn try_from(value: GetFlightInfoBatchCmd) -> Result<Self, Self::Error> {
+        Ok(Self {
+            entries: value
+                .resource_locators
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Returns true if `v` looks like a batched `GetFlightInfo` command (i.e. carries a
+/// `resource_locators` array) rather than a single-resource one.
+pub fn is_get_flight_info_batch_cmd(v: &[u8]) -> bool {
+    serde_json::from_slice::<serde_json::Value>(v)
+        .ok()
+        .and_then(|value| value.get("resource_locators").cloned())
+        .is_some()
+}
+
+/// Convert a raw flight command into a [`GetFlightInfoBatchCmd`]
+pub fn get_flight_info_batch_cmd(
+    v: &[u8],
+) -> Result<types::flight::GetFlightInfoBatchCmd, super::Error> {
+    serde_json::from_slice::<GetFlightInfoBatchCmd>(v)
+        .map_err(|e| super::Error::DeserializationError(e.to_string()))?
+        .try_into()
+}
+
+// ////////////////////////////////////////////////////////////////////////////
+// FLIGHTSQL STATEMENT QUERY
+// ////////////////////////////////////////////////////////////////////////////
+
+/// Splits `s` on occurrences of `sep_upper` (already uppercase), matched case-insensitively
+/// against `s`. Assumes both are ASCII, which holds for the handful of SQL keywords this
+/// module looks for.
+fn split_ci<'a>(s: &'a str, sep_upper: &str) -> Vec<&'a str> {
+    let upper = s.to_uppercase();
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    while let Some(rel) = upper[start..].find(sep_upper) {
+        let pos = start + rel;
+        parts.push(&s[start..pos]);
+        start = pos + sep_upper.len();
+    }
+    parts.push(&s[start..]);
+
+    parts
+}
+
+/// Parses an `AND`-joined conjunction of `timestamp <op> <value>` clauses into a single
+/// [`types::TimestampRange`], narrowing the range with each clause encountered. Returns
+/// `None` if `predicate` is blank.
+fn parse_timestamp_predicate(predicate: &str) -> Result<Option<types::TimestampRange>, super::Error> {
+    let mut range = types::TimestampRange::between(
+        types::Timestamp::unbounded_neg(),
+        types::Timestamp::unbounded_pos(),
+    );
+
+    for clause in split_ci(predicate, "AND") {
+        let clause = clause.trim();
+        if clause.is_empty() {
+            continue;
+        }
+
+        let tokens: Vec<&str> = clause.split_whitespace().collect();
+        let [field, op, value] = tokens[..] else {
+            return Err(super::Error::DeserializationError(format!(
+                "unsupported predicate clause `{clause}`, expected `timestamp <op> <value>`"
+            )));
+        };
+
+        if !field.eq_ignore_ascii_case("timestamp") {
+            return Err(super::Error::DeserializationError(format!(
+                "unsupported predicate column `{field}`, only `timestamp` is supported"
+            )));
+        }
+
+        let value: i64 = value.parse().map_err(|_| {
+            super::Error::DeserializationError(format!("invalid timestamp value `{value}`"))
+        })?;
+        let value: types::Timestamp = value.into();
+
+        let clause_range = match op {
+            ">=" => types::TimestampRange::starting_at(value),
+            ">" => {
+                types::TimestampRange::starting_at(value).with_start_bound(types::Bound::Exclusive)
+            }
+            "<=" => types::TimestampRange::ending_at(value),
+            "<" => types::TimestampRange::ending_at(value).with_end_bound(types::Bound::Exclusive),
+            "=" => types::TimestampRange::between(value, value),
+            other => {
+                return Err(super::Error::DeserializationError(format!(
+                    "unsupported predicate operator `{other}`"
+                )));
+            }
+        };
+
+        range = range.intersection(&clause_range).ok_or_else(|| {
+            super::Error::DeserializationError(
+                "predicate clauses produce an empty timestamp range".to_string(),
+            )
+        })?;
+    }
+
+    Ok(if range.is_unbounded() { None } else { Some(range) })
+}
+
+/// Parses the small `SELECT ... FROM <resource> [WHERE timestamp <op> <value> [AND ...]]`
+/// dialect FlightSQL's `CommandStatementQuery` carries, translating it into the same
+/// [`types::flight::GetFlightInfoCmd`] the JSON cmd format produces so both share a single
+/// `GetFlightInfo` resolver. Only conjunctions of `timestamp <op> <value>` predicates are
+/// understood; anything else (joins, non-timestamp predicates, aggregates) is rejected
+/// rather than silently ignored.
+pub fn statement_query_cmd(query: &str) -> Result<types::flight::GetFlightInfoCmd, super::Error> {
+    let upper = query.to_uppercase();
+    let from_at = upper
+        .find("FROM")
+        .ok_or_else(|| super::Error::DeserializationError("query has no FROM clause".to_string()))?;
+    let where_at = upper[from_at..].find("WHERE").map(|rel| from_at + rel);
+
+    let table_part = &query[from_at + 4..where_at.unwrap_or(query.len())];
+    let resource_locator = table_part.trim().trim_matches('"').to_string();
+    if resource_locator.is_empty() {
+        return Err(super::Error::DeserializationError(
+            "query has an empty FROM clause".to_string(),
+        ));
+    }
+
+    let timestamp_range = where_at
+        .map(|pos| parse_timestamp_predicate(&query[pos + 5..]))
+        .transpose()?
+        .flatten();
+
+    Ok(types::flight::GetFlightInfoCmd {
+        resource_locator,
+        timestamp_range,
+        follow: false,
+        follow_timeout_secs: None,
+        columns: Vec::new(),
+        filters: Vec::new(),
+    })
 }
 
 // ////////////////////////////////////////////////////////////////////////////
@@ -67,6 +363,105 @@ pub fn do_put_cmd(v: &[u8]) -> Result<types::flight::DoPutCmd, super::Error> {
         .map(|v| v.into())
 }
 
+/// Bincode-encodable mirror of [`types::flight::FilterOp`].
+#[derive(Encode, Decode)]
+enum TicketFilterOp {
+    Eq,
+    Neq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    In,
+}
+
+impl From<types::flight::FilterOp> for TicketFilterOp {
+    fn from(value: types::flight::FilterOp) -> Self {
+        match value {
+            types::flight::FilterOp::Eq => Self::Eq,
+            types::flight::FilterOp::Neq => Self::Neq,
+            types::flight::FilterOp::Lt => Self::Lt,
+            types::flight::FilterOp::Lte => Self::Lte,
+            types::flight::FilterOp::Gt => Self::Gt,
+            types::flight::FilterOp::Gte => Self::Gte,
+            types::flight::FilterOp::In => Self::In,
+        }
+    }
+}
+
+impl From<TicketFilterOp> for types::flight::FilterOp {
+    fn from(value: TicketFilterOp) -> Self {
+        match value {
+            TicketFilterOp::Eq => Self::Eq,
+            TicketFilterOp::Neq => Self::Neq,
+            TicketFilterOp::Lt => Self::Lt,
+            TicketFilterOp::Lte => Self::Lte,
+            TicketFilterOp::Gt => Self::Gt,
+            TicketFilterOp::Gte => Self::Gte,
+            TicketFilterOp::In => Self::In,
+        }
+    }
+}
+
+/// Bincode-encodable mirror of [`types::flight::FilterValue`].
+#[derive(Encode, Decode)]
+enum TicketFilterValue {
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Boolean(bool),
+}
+
+impl From<types::flight::FilterValue> for TicketFilterValue {
+    fn from(value: types::flight::FilterValue) -> Self {
+        match value {
+            types::flight::FilterValue::Integer(v) => Self::Integer(v),
+            types::flight::FilterValue::Float(v) => Self::Float(v),
+            types::flight::FilterValue::Text(v) => Self::Text(v),
+            types::flight::FilterValue::Boolean(v) => Self::Boolean(v),
+        }
+    }
+}
+
+impl From<TicketFilterValue> for types::flight::FilterValue {
+    fn from(value: TicketFilterValue) -> Self {
+        match value {
+            TicketFilterValue::Integer(v) => Self::Integer(v),
+            TicketFilterValue::Float(v) => Self::Float(v),
+            TicketFilterValue::Text(v) => Self::Text(v),
+            TicketFilterValue::Boolean(v) => Self::Boolean(v),
+        }
+    }
+}
+
+/// Bincode-encodable mirror of [`types::flight::ValueFilter`].
+#[derive(Encode, Decode)]
+struct TicketValueFilter {
+    column: String,
+    op: TicketFilterOp,
+    values: Vec<TicketFilterValue>,
+}
+
+impl From<types::flight::ValueFilter> for TicketValueFilter {
+    fn from(value: types::flight::ValueFilter) -> Self {
+        Self {
+            column: value.column,
+            op: value.op.into(),
+            values: value.values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TicketValueFilter> for types::flight::ValueFilter {
+    fn from(value: TicketValueFilter) -> Self {
+        Self {
+            column: value.column,
+            op: value.op.into(),
+            values: value.values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 // ////////////////////////////////////////////////////////////////////////////
 // TICKET TOPIC
 // ////////////////////////////////////////////////////////////////////////////
@@ -75,6 +470,10 @@ struct TicketTopic {
     locator: String,
     timestamp_range_start: Option<i64>,
     timestamp_range_end: Option<i64>,
+    follow: bool,
+    follow_timeout_secs: Option<u64>,
+    columns: Vec<String>,
+    filters: Vec<TicketValueFilter>,
 }
 
 impl From<types::flight::TicketTopic> for TicketTopic {
@@ -83,6 +482,10 @@ impl From<types::flight::TicketTopic> for TicketTopic {
             locator: value.locator,
             timestamp_range_start: value.timestamp_range.as_ref().map(|tsr| tsr.start.into()),
             timestamp_range_end: value.timestamp_range.map(|tsr| tsr.end.into()),
+            follow: value.follow,
+            follow_timeout_secs: value.follow_timeout_secs,
+            columns: value.columns,
+            filters: value.filters.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -103,6 +506,10 @@ impl From<TicketTopic> for types::flight::TicketTopic {
         Self {
             locator: value.locator,
             timestamp_range,
+            follow: value.follow,
+            follow_timeout_secs: value.follow_timeout_secs,
+            columns: value.columns,
+            filters: value.filters.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -123,6 +530,84 @@ pub fn ticket_topic_from_binary(v: &[u8]) -> Result<types::flight::TicketTopic,
     Ok(ticket.into())
 }
 
+// ////////////////////////////////////////////////////////////////////////////
+// ERROR DETAIL
+// ////////////////////////////////////////////////////////////////////////////
+
+/// Bincode-encodable mirror of [`types::flight::ErrorCode`].
+#[derive(Encode, Decode)]
+enum ErrorCode {
+    NotFound,
+    InvalidArgument,
+    Unavailable,
+    Internal,
+}
+
+impl From<types::flight::ErrorCode> for ErrorCode {
+    fn from(value: types::flight::ErrorCode) -> Self {
+        match value {
+            types::flight::ErrorCode::NotFound => Self::NotFound,
+            types::flight::ErrorCode::InvalidArgument => Self::InvalidArgument,
+            types::flight::ErrorCode::Unavailable => Self::Unavailable,
+            types::flight::ErrorCode::Internal => Self::Internal,
+        }
+    }
+}
+
+impl From<ErrorCode> for types::flight::ErrorCode {
+    fn from(value: ErrorCode) -> Self {
+        match value {
+            ErrorCode::NotFound => Self::NotFound,
+            ErrorCode::InvalidArgument => Self::InvalidArgument,
+            ErrorCode::Unavailable => Self::Unavailable,
+            ErrorCode::Internal => Self::Internal,
+        }
+    }
+}
+
+/// Bincode-encodable mirror of [`types::flight::ErrorDetail`], carried in a `tonic::Status`'s
+/// `details` payload the same way [`TicketTopic`] is carried in a Flight ticket.
+#[derive(Encode, Decode)]
+struct ErrorDetail {
+    code: ErrorCode,
+    retryable: bool,
+}
+
+impl From<types::flight::ErrorDetail> for ErrorDetail {
+    fn from(value: types::flight::ErrorDetail) -> Self {
+        Self {
+            code: value.code.into(),
+            retryable: value.retryable,
+        }
+    }
+}
+
+impl From<ErrorDetail> for types::flight::ErrorDetail {
+    fn from(value: ErrorDetail) -> Self {
+        Self {
+            code: value.code.into(),
+            retryable: value.retryable,
+        }
+    }
+}
+
+pub fn error_detail_to_binary(detail: types::flight::ErrorDetail) -> Result<Vec<u8>, super::Error> {
+    let detail: ErrorDetail = detail.into();
+    let config = bincode::config::standard();
+
+    bincode::encode_to_vec(detail, config)
+        .map_err(|e| super::Error::SerializationError(e.to_string()))
+}
+
+pub fn error_detail_from_binary(v: &[u8]) -> Result<types::flight::ErrorDetail, super::Error> {
+    let config = bincode::config::standard();
+
+    let (detail, _): (ErrorDetail, usize) = bincode::decode_from_slice(v, config)
+        .map_err(|e| super::Error::DeserializationError(e.to_string()))?;
+
+    Ok(detail.into())
+}
+
 // ////////////////////////////////////////////////////////////////////////////
 // TOPIC APP METADATA
 // ////////////////////////////////////////////////////////////////////////////
@@ -179,13 +664,17 @@ mod tests {
             resource_locator: "test_sequence/topic/a".to_owned(),
             timestamp_ns_start: Some(100000),
             timestamp_ns_end: Some(110000),
+            follow: None,
+            follow_timeout_secs: None,
+            columns: None,
+            filters: None,
         };
 
         let name = src.resource_locator.clone();
         let start = src.timestamp_ns_start.unwrap();
         let end = src.timestamp_ns_end.unwrap();
 
-        let dest: types::flight::GetFlightInfoCmd = src.into();
+        let dest: types::flight::GetFlightInfoCmd = src.try_into().expect("valid filters");
 
         assert_eq!(dest.resource_locator, name);
         assert_eq!(dest.timestamp_range.as_ref().unwrap().start.as_i64(), start);
@@ -200,12 +689,16 @@ mod tests {
             resource_locator: "test_sequence/topic/a".to_owned(),
             timestamp_ns_start: Some(100000),
             timestamp_ns_end: None,
+            follow: None,
+            follow_timeout_secs: None,
+            columns: None,
+            filters: None,
         };
 
         let name = src.resource_locator.clone();
         let start = src.timestamp_ns_start.unwrap();
 
-        let dest: types::flight::GetFlightInfoCmd = src.into();
+        let dest: types::flight::GetFlightInfoCmd = src.try_into().expect("valid filters");
 
         assert_eq!(dest.resource_locator, name);
         assert_eq!(dest.timestamp_range.as_ref().unwrap().start.as_i64(), start);
@@ -220,12 +713,16 @@ mod tests {
             resource_locator: "test_sequence/topic/a".to_owned(),
             timestamp_ns_start: None,
             timestamp_ns_end: Some(110000),
+            follow: None,
+            follow_timeout_secs: None,
+            columns: None,
+            filters: None,
         };
 
         let name = src.resource_locator.clone();
         let end = src.timestamp_ns_end.unwrap();
 
-        let dest: types::flight::GetFlightInfoCmd = src.into();
+        let dest: types::flight::GetFlightInfoCmd = src.try_into().expect("valid filters");
 
         assert_eq!(dest.resource_locator, name);
         assert!(dest.timestamp_range.as_ref().unwrap().start.is_unbounded());
@@ -240,12 +737,152 @@ mod tests {
             resource_locator: "test_sequence/topic/a".to_owned(),
             timestamp_ns_start: None,
             timestamp_ns_end: None,
+            follow: None,
+            follow_timeout_secs: None,
+            columns: None,
+            filters: None,
         };
 
         let name = src.resource_locator.clone();
-        let dest: types::flight::GetFlightInfoCmd = src.into();
+        let dest: types::flight::GetFlightInfoCmd = src.try_into().expect("valid filters");
 
         assert_eq!(dest.resource_locator, name);
         assert!(dest.timestamp_range.is_none());
     }
+
+    /// Check that `follow` defaults to `false` when absent from the request.
+    #[test]
+    fn get_flight_info_cmd_follow_defaults_false() {
+        let src = super::GetFlightInfoCmd {
+            resource_locator: "test_sequence/topic/a".to_owned(),
+            timestamp_ns_start: None,
+            timestamp_ns_end: None,
+            follow: None,
+            follow_timeout_secs: None,
+            columns: None,
+            filters: None,
+        };
+
+        let dest: types::flight::GetFlightInfoCmd = src.try_into().expect("valid filters");
+        assert!(!dest.follow);
+    }
+
+    /// Check that a [`types::flight::TicketTopic`] round-trips through its binary
+    /// encoding, including the `follow` flag.
+    #[test]
+    fn ticket_topic_roundtrip_preserves_follow() {
+        let ticket = types::flight::TicketTopic {
+            locator: "test_sequence/topic/a".to_owned(),
+            timestamp_range: Some(types::TimestampRange::starting_at(100000.into())),
+            follow: true,
+            follow_timeout_secs: None,
+            columns: Vec::new(),
+            filters: Vec::new(),
+        };
+
+        let encoded = super::ticket_topic_to_binary(ticket).expect("encodes");
+        let decoded = super::ticket_topic_from_binary(&encoded).expect("decodes");
+
+        assert!(decoded.follow);
+    }
+
+    /// Check that a [`types::flight::TicketTopic`] round-trips its `follow_timeout_secs`
+    /// watermark/deadline override.
+    #[test]
+    fn ticket_topic_roundtrip_preserves_follow_timeout_secs() {
+        let ticket = types::flight::TicketTopic {
+            locator: "test_sequence/topic/a".to_owned(),
+            timestamp_range: None,
+            follow: true,
+            follow_timeout_secs: Some(30),
+            columns: Vec::new(),
+            filters: Vec::new(),
+        };
+
+        let encoded = super::ticket_topic_to_binary(ticket).expect("encodes");
+        let decoded = super::ticket_topic_from_binary(&encoded).expect("decodes");
+
+        assert_eq!(decoded.follow_timeout_secs, Some(30));
+    }
+
+    /// Check that a [`types::flight::ErrorDetail`] round-trips through its binary encoding.
+    #[test]
+    fn error_detail_roundtrip() {
+        let detail = types::flight::ErrorDetail {
+            code: types::flight::ErrorCode::Unavailable,
+            retryable: true,
+        };
+
+        let encoded = super::error_detail_to_binary(detail).expect("encodes");
+        let decoded = super::error_detail_from_binary(&encoded).expect("decodes");
+
+        assert!(matches!(decoded.code, types::flight::ErrorCode::Unavailable));
+        assert!(decoded.retryable);
+    }
+
+    /// Check that `resource_locators` is the marker field distinguishing a batch command
+    /// from a single-resource one.
+    #[test]
+    fn is_get_flight_info_batch_cmd_detects_batch_shape() {
+        let batch = br#"{"resource_locators":[{"resource_locator":"a"}]}"#;
+        let single = br#"{"resource_locator":"a"}"#;
+
+        assert!(super::is_get_flight_info_batch_cmd(batch));
+        assert!(!super::is_get_flight_info_batch_cmd(single));
+    }
+
+    /// Check that a batched request deserializes into one entry per resource locator.
+    #[test]
+    fn get_flight_info_batch_cmd_parses_entries() {
+        let raw = br#"{"resource_locators":[
+            {"resource_locator":"a/topic", "timestamp_ns_start": 1000},
+            {"resource_locator":"b/topic", "follow": true}
+        ]}"#;
+
+        let cmd = super::get_flight_info_batch_cmd(raw).expect("parses");
+
+        assert_eq!(cmd.entries.len(), 2);
+        assert_eq!(cmd.entries[0].resource_locator, "a/topic");
+        assert!(!cmd.entries[0].follow);
+        assert_eq!(cmd.entries[1].resource_locator, "b/topic");
+        assert!(cmd.entries[1].follow);
+    }
+
+    /// Check that a `WHERE`-less statement resolves only the resource locator.
+    #[test]
+    fn statement_query_cmd_parses_bare_from() {
+        let cmd = super::statement_query_cmd("SELECT * FROM my_topic").expect("parses");
+
+        assert_eq!(cmd.resource_locator, "my_topic");
+        assert!(cmd.timestamp_range.is_none());
+        assert!(!cmd.follow);
+    }
+
+    /// Check that an `AND`-joined timestamp predicate narrows down to a single range.
+    #[test]
+    fn statement_query_cmd_parses_timestamp_predicate() {
+        let cmd = super::statement_query_cmd(
+            "select * from my_topic where timestamp >= 1000 AND timestamp < 2000",
+        )
+        .expect("parses");
+
+        assert_eq!(cmd.resource_locator, "my_topic");
+        let range = cmd.timestamp_range.expect("predicate produced a range");
+        assert_eq!(range.start, 1000.into());
+        assert_eq!(range.end, 2000.into());
+        assert_eq!(range.end_bound, types::Bound::Exclusive);
+    }
+
+    /// Check that a query missing `FROM` is rejected rather than silently resolving nothing.
+    #[test]
+    fn statement_query_cmd_rejects_missing_from() {
+        assert!(super::statement_query_cmd("SELECT 1").is_err());
+    }
+
+    /// Check that a predicate on a column other than `timestamp` is rejected rather than
+    /// silently ignored.
+    #[test]
+    fn statement_query_cmd_rejects_non_timestamp_predicate() {
+        assert!(super::statement_query_cmd("SELECT * FROM my_topic WHERE value > 1").is_err());
+    }
 }
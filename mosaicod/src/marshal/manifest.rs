@@ -4,12 +4,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TopicManifest {
     timestamp: Option<TopicManifestTimestamp>,
+    /// Per-chunk timestamp bounds and byte offsets, read from each chunk's Parquet footer
+    /// during `FacadeTopic::finalize`. Empty for manifests written before this field existed.
+    #[serde(default)]
+    chunks: Vec<ManifestChunkEntry>,
 }
 
 impl From<types::TopicManifest> for TopicManifest {
     fn from(value: types::TopicManifest) -> Self {
         Self {
             timestamp: value.timestamp.map(|v| v.into()),
+            chunks: value.chunks.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -18,6 +23,7 @@ impl From<TopicManifest> for types::TopicManifest {
     fn from(value: TopicManifest) -> Self {
         Self {
             timestamp: value.timestamp.map(|v| v.into()),
+            chunks: value.chunks.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -58,3 +64,38 @@ impl From<TopicManifestTimestamp> for types::TopicManifestTimestamp {
         }
     }
 }
+
+/// A single chunk's entry in [`TopicManifest::chunks`]: its timestamp bounds and its byte
+/// range within the chunk's data file, so a querier can prune whole chunks, and seek directly
+/// to the relevant bytes of the ones it keeps, without touching every chunk's footer itself.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManifestChunkEntry {
+    index: usize,
+    ts_min: i64,
+    ts_max: i64,
+    byte_offset: u64,
+    byte_length: u64,
+}
+
+impl From<types::ChunkManifestEntry> for ManifestChunkEntry {
+    fn from(value: types::ChunkManifestEntry) -> Self {
+        Self {
+            index: value.index,
+            ts_min: value.timestamp.start.as_i64(),
+            ts_max: value.timestamp.end.as_i64(),
+            byte_offset: value.byte_offset,
+            byte_length: value.byte_length,
+        }
+    }
+}
+
+impl From<ManifestChunkEntry> for types::ChunkManifestEntry {
+    fn from(value: ManifestChunkEntry) -> Self {
+        Self {
+            index: value.index,
+            timestamp: types::TimestampRange::between(value.ts_min.into(), value.ts_max.into()),
+            byte_offset: value.byte_offset,
+            byte_length: value.byte_length,
+        }
+    }
+}
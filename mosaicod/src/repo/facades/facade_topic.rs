@@ -6,12 +6,36 @@ use crate::{
     types::{self, Resource},
 };
 use arrow::datatypes::SchemaRef;
-use log::trace;
+use futures::TryStreamExt;
+use log::{trace, warn};
 use std::sync::Arc;
 
 /// Define topic metadata type contaning JSON user metadata
 type TopicMetadata = types::TopicMetadata<marshal::JsonMetadataBlob>;
 
+/// Outcome of a [`FacadeTopic::compact`] run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CompactionSummary {
+    /// Number of existing segments overlapping the requested range.
+    pub segments_examined: usize,
+    /// Number of those segments superseded by a merged file.
+    pub segments_merged: usize,
+    /// Number of merged files written.
+    pub files_written: usize,
+}
+
+/// Returns true if two timestamp ranges share at least one instant.
+fn ranges_overlap(a: &types::TimestampRange, b: &types::TimestampRange) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
+/// Returns the smallest timestamp range covering both `a` and `b`.
+fn range_union(a: &types::TimestampRange, b: &types::TimestampRange) -> types::TimestampRange {
+    let start = if a.start <= b.start { a.start } else { b.start };
+    let end = if a.end >= b.end { a.end } else { b.end };
+    types::TimestampRange::between(start, end)
+}
+
 pub struct FacadeTopic {
     pub locator: types::TopicResourceLocator,
     store: store::StoreRef,
@@ -77,9 +101,56 @@ impl FacadeTopic {
 
         tx.commit().await?;
 
+        // Starts this topic's write-ahead journal (see `repo::journal`) fresh, recording that
+        // the repository side of `create` has landed; `finalize` appends to it as the write
+        // session progresses and clears it once everything has committed.
+        self.journal_write(&[repo::journal::JournalRecord::TopicCreate {
+            locator: self.locator.name().clone(),
+        }])
+        .await?;
+
         Ok(record.into())
     }
 
+    /// Resolves this topic's [`types::crypto::DataEncryptionKey`], returning `None` for
+    /// topics that don't have `metadata.properties.encrypted` set.
+    ///
+    /// If encryption is enabled but no key has been wrapped yet (the topic's first write), a
+    /// fresh DEK is generated, wrapped under the `encryption_master_key` configurable, and
+    /// persisted into `metadata.properties.encryption_key` via [`FacadeTopic::update`] before
+    /// being returned; subsequent calls just unwrap the already-persisted key. The DEK itself
+    /// is never written to the repository or object store unwrapped.
+    ///
+    /// Chunk data read directly by this facade (the footer reads backing
+    /// [`FacadeTopic::arrow_schema`] and [`FacadeTopic::chunk_manifest_entries`]) is
+    /// decrypted transparently using the key this returns. `DoGet`'s bulk record-batch reads
+    /// go through `query::TimeseriesRef` instead, which doesn't yet consult this key, so
+    /// `do_put` rejects writes to an encrypted topic outright (rather than letting chunks land
+    /// that `DoGet` could never decrypt) until that engine applies the matching decryption.
+    pub async fn dek_for_topic(
+        &self,
+        metadata: &TopicMetadata,
+    ) -> Result<Option<types::crypto::DataEncryptionKey>, FacadeError> {
+        if !metadata.properties.encrypted {
+            return Ok(None);
+        }
+
+        let master_key = rw::crypto::MasterKey::from_configurable(&params::configurables().encryption_master_key)?;
+
+        if let Some(wrapped) = &metadata.properties.encryption_key {
+            return Ok(Some(master_key.unwrap(wrapped)?));
+        }
+
+        let dek = types::crypto::DataEncryptionKey::generate();
+        let wrapped = master_key.wrap(&dek)?;
+
+        let mut updated = metadata.clone();
+        updated.properties.encryption_key = Some(wrapped);
+        self.update(updated).await?;
+
+        Ok(Some(dek))
+    }
+
     pub async fn is_locked(&self) -> Result<bool, FacadeError> {
         let mut cx = self.repo.connection();
 
@@ -160,27 +231,196 @@ impl FacadeTopic {
     /// Finalize the write procedure of the topic. The topic is locked and additional data are
     /// consolidated (e.g. manifest, timestamp bounds). This function is intended to be called by
     /// [`FacadeTopicWriterGuard`] to finilize the writing process.
+    ///
+    /// Each step (the chunks discovered, the manifest write, the lock) is recorded in this
+    /// topic's journal (see `repo::journal`) as it completes, and the journal is cleared only
+    /// once every step below has landed — so a crash partway through leaves enough of a trail
+    /// for [`FacadeTopic::recover`] to re-drive finalize to completion instead of leaving the
+    /// topic in a half-finalized state. This function is idempotent for that reason: re-running
+    /// it against a topic that's already partway finalized just re-derives the same manifest
+    /// from whatever chunks exist in the store and (re-)locks it.
     async fn finalize(
         &mut self,
         timeseries_querier: query::TimeseriesRef,
         format: rw::Format,
     ) -> Result<(), FacadeError> {
         let res = timeseries_querier
-            .read(self.locator.path(), format, None)
+            .read(self.locator.path(), format.clone(), None)
             .await?;
 
         let ts_range = res.timestamp_range().await?;
 
+        let metadata = self.metadata().await?;
+        let dek = self.dek_for_topic(&metadata).await?;
+        let chunks = self.chunk_manifest_entries(&format, dek.as_ref()).await?;
+
+        let mut journal = vec![repo::journal::JournalRecord::TopicCreate {
+            locator: self.locator.name().clone(),
+        }];
+        journal.extend(chunks.iter().map(|entry| repo::journal::JournalRecord::ChunkWritten {
+            idx: entry.index,
+            path: self.locator.path_data(entry.index, &format).to_string_lossy().into_owned(),
+        }));
+        self.journal_write(&journal).await?;
+
         let manifest = types::TopicManifest::new()
-            .with_timestamp(types::TopicManifestTimestamp::new(ts_range));
+            .with_timestamp(types::TopicManifestTimestamp::new(ts_range))
+            .with_chunks(chunks);
 
         self.manifest_write_to_store(manifest).await?;
+        self.journal_append(repo::journal::JournalRecord::ManifestWritten).await?;
+
+        // Guarded by `is_locked` rather than called unconditionally, so replaying this function
+        // from `recover` against a topic that crashed right after locking (but before the
+        // journal recorded it) doesn't depend on `lock` being safe to call twice.
+        if !self.is_locked().await? {
+            self.lock().await?;
+        }
+        self.journal_append(repo::journal::JournalRecord::TopicLock).await?;
+
+        self.journal_clear().await?;
+
+        Ok(())
+    }
+
+    /// Replays this topic's journal (see `repo::journal`), re-driving `finalize` to completion
+    /// if it recorded `finalize` actually starting (a `ChunkWritten`, `ManifestWritten` or
+    /// `TopicLock` record) without also recording the journal being cleared — i.e. a previous
+    /// `finalize` crashed after journaling the chunks it found and before the final lock. A bare
+    /// `TopicCreate` record with nothing after it means `finalize` never started (chunk writes
+    /// themselves aren't journaled), so nothing is replayed. Returns `true` if recovery work was
+    /// needed, `false` otherwise.
+    ///
+    /// Called by `do_put` right before a topic is handed a new write session (see
+    /// `server::endpoints::do_put`), since that's this crate's only real entry point that
+    /// resolves a topic handle before mutating it — there's no process-startup hook that
+    /// enumerates every topic up front to call this for all of them proactively, so a topic
+    /// that's crashed mid-finalize and never written to again stays unrecovered until its next
+    /// write.
+    pub async fn recover(
+        &mut self,
+        timeseries_querier: query::TimeseriesRef,
+        format: rw::Format,
+    ) -> Result<bool, FacadeError> {
+        let finalize_started = self
+            .journal_read()
+            .await?
+            .iter()
+            .any(|record| !matches!(record, repo::journal::JournalRecord::TopicCreate { .. }));
+
+        if !finalize_started {
+            return Ok(false);
+        }
+
+        self.finalize(timeseries_querier, format).await?;
+
+        Ok(true)
+    }
+
+    /// Returns the path of this topic's write-ahead journal.
+    fn journal_path(&self) -> std::path::PathBuf {
+        self.locator.path_journal()
+    }
+
+    /// Reads and decodes this topic's journal, returning an empty list if it doesn't exist yet
+    /// (a topic that hasn't started a write session, or one whose journal was already cleared).
+    async fn journal_read(&self) -> Result<Vec<repo::journal::JournalRecord>, FacadeError> {
+        let path = self.journal_path();
+
+        if !self.store.exists(&path).await? {
+            return Ok(Vec::new());
+        }
+
+        let bytes = self.store.read_bytes(&path).await?;
+
+        Ok(repo::journal::decode(&bytes))
+    }
+
+    /// Overwrites this topic's journal with `records`. The object store backing this facade has
+    /// no append primitive, so every journal write rewrites the file in full rather than
+    /// appending to it incrementally.
+    async fn journal_write(&self, records: &[repo::journal::JournalRecord]) -> Result<(), FacadeError> {
+        let path = self.journal_path();
+        self.store.write_bytes(&path, repo::journal::encode(records)).await?;
+
+        Ok(())
+    }
+
+    /// Appends a single record to this topic's journal, by reading back whatever's already
+    /// there and rewriting the whole file with `record` added.
+    async fn journal_append(&self, record: repo::journal::JournalRecord) -> Result<(), FacadeError> {
+        let mut records = self.journal_read().await?;
+        records.push(record);
+        self.journal_write(&records).await
+    }
+
+    /// Clears this topic's journal once a write session has fully committed, so a later
+    /// [`FacadeTopic::recover`] has nothing left to replay.
+    async fn journal_clear(&self) -> Result<(), FacadeError> {
+        let path = self.journal_path();
 
-        self.lock().await?;
+        if self.store.exists(&path).await? {
+            self.store.delete(&path).await?;
+        }
 
         Ok(())
     }
 
+    /// Reads every chunk's Parquet footer (see [`rw::footer`]) to build the per-chunk
+    /// timestamp-bounds/byte-offset entries `finalize` persists in the manifest, so a querier
+    /// can prune whole chunks before issuing reads instead of scanning the full topic.
+    async fn chunk_manifest_entries(
+        &self,
+        format: &rw::Format,
+        dek: Option<&types::crypto::DataEncryptionKey>,
+    ) -> Result<Vec<types::ChunkManifestEntry>, FacadeError> {
+        let mut entries = Vec::new();
+
+        for index in 0usize.. {
+            let path = self.locator.path_data(index, format);
+
+            if !self.store.exists(&path).await? {
+                break;
+            }
+
+            let byte_length = self.store.size(&path).await?;
+            let footer = self.chunk_footer(&path, byte_length, dek, index as i64).await?;
+            let timestamp = rw::footer::timestamp_range_from_footer(&footer)?;
+
+            entries.push(types::ChunkManifestEntry {
+                index,
+                timestamp,
+                byte_offset: 0,
+                byte_length,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    /// Reads a chunk's Parquet footer, transparently decrypting first if `dek` is set.
+    ///
+    /// Plaintext chunks go through [`rw::footer::read_footer`]'s ranged read of just the
+    /// trailing bytes. An encrypted chunk's ciphertext isn't independently seekable the same
+    /// way, so this falls back to fetching (and decrypting) the whole file before parsing its
+    /// footer out of the resulting plaintext.
+    async fn chunk_footer(
+        &self,
+        path: &std::path::Path,
+        byte_length: u64,
+        dek: Option<&types::crypto::DataEncryptionKey>,
+        chunk_id: i64,
+    ) -> Result<parquet::file::metadata::ParquetMetaData, FacadeError> {
+        match dek {
+            None => Ok(rw::footer::read_footer(&self.store, path, byte_length).await?),
+            Some(dek) => {
+                let ciphertext = self.store.read_bytes(path).await?;
+                let plaintext = rw::crypto::decrypt_chunk(dek, chunk_id, &ciphertext)?;
+                Ok(rw::footer::footer_from_plaintext(path, &plaintext)?)
+            }
+        }
+    }
+
     /// Reads [`TopicMetadata`] associated with this topic.
     ///
     /// # Errors
@@ -216,7 +456,17 @@ impl FacadeTopic {
     /// The serialization format is required to extract the schema, can be retrieved using [`TopicHandle::metadata`] function.
     ///
     /// If no arrow_schema is found a [`FacadeError::NotFound`] error is returned
+    ///
+    /// Reads only chunk 0's Parquet footer (see [`rw::footer`]) rather than the whole file,
+    /// and serves repeated lookups for the same topic out of [`rw::schema_cache`] instead of
+    /// re-reading the store at all.
     pub async fn arrow_schema(&self, format: rw::Format) -> Result<SchemaRef, FacadeError> {
+        let cache_key = self.locator.name().to_string();
+
+        if let Some(schema) = rw::schema_cache().get(&cache_key) {
+            return Ok(schema);
+        }
+
         // Get chunk 0 since this chunk needs to exist always
         let path = self.locator.path_data(0, &format);
 
@@ -224,11 +474,16 @@ impl FacadeTopic {
             return Err(FacadeError::NotFound(path.to_string_lossy().to_string()));
         }
 
-        // Build a chunk reader reading in memory a file
-        // (cabba) TODO: avoid reading the whole file, get from store only the header
-        let buffer = self.store.read_bytes(path).await?;
-        let reader = rw::ChunkReader::new(format, bytes::Bytes::from_owner(buffer))?;
-        Ok(reader.schema())
+        let metadata = self.metadata().await?;
+        let dek = self.dek_for_topic(&metadata).await?;
+
+        let file_size = self.store.size(&path).await?;
+        let footer = self.chunk_footer(&path, file_size, dek.as_ref(), 0).await?;
+        let schema = rw::footer::arrow_schema_from_footer(&footer)?;
+
+        rw::schema_cache().put(cache_key, schema.clone());
+
+        Ok(schema)
     }
 
     /// Serializes and writes [`TopicMetadata`] to the object store.
@@ -266,27 +521,36 @@ impl FacadeTopic {
 
     /// Returns a writer used to write chunked record batches using a specified serialization
     /// format `format`.
+    ///
+    /// `dek` is this topic's data-encryption key (see [`FacadeTopic::dek_for_topic`]), if
+    /// encryption is enabled; callers fetch it ahead of time since resolving it is async while
+    /// building the writer isn't.
     pub fn writer(
         &mut self,
         querier: query::TimeseriesRef,
         format: rw::Format,
+        dek: Option<types::crypto::DataEncryptionKey>,
     ) -> FacadeTopicWriterGuard<'_> {
-        let max_chunk_size = {
-            let config_value = params::configurables().max_chunk_size_in_bytes;
-            if config_value == 0 {
-                None // 0 means unlimited (no automatic splitting)
-            } else {
-                Some(config_value)
-            }
-        };
+        // Readable size (e.g. "64MB") so the in-memory write buffer stays bounded
+        // independent of total upload size; empty means unlimited (no automatic splitting).
+        let max_buffer_size = params::configurables().write_sst_max_buffer_size;
+        let max_buffer_size = (!max_buffer_size.is_empty()).then_some(max_buffer_size.as_str());
+
+        // How many chunk finalize+upload operations are allowed to overlap; 0 means disabled
+        // (each chunk is fully finalized and uploaded before the next is encoded).
+        let max_inflight_chunks = params::configurables().write_max_inflight_chunks;
+        let max_inflight_chunks = (max_inflight_chunks > 0).then_some(max_inflight_chunks);
 
         let cw = rw::ChunkedWriter::new(
             self.store.clone(),
             self.path(),
-            format,
+            format.clone(),
             |path, format, idx| types::TopicResourceLocator::from(path).path_data(idx, format),
         )
-        .with_max_chunk_size(max_chunk_size);
+        .with_max_buffer_size_readable(max_buffer_size)
+        .expect("write_sst_max_buffer_size configurable must be a valid readable size")
+        .with_encryption_key(dek)
+        .with_max_inflight_chunks(max_inflight_chunks);
 
         FacadeTopicWriterGuard {
             facade: self,
@@ -307,6 +571,8 @@ impl FacadeTopic {
 
         tx.commit().await?;
 
+        rw::schema_cache().invalidate(self.locator.name().as_str());
+
         Ok(())
     }
 
@@ -322,6 +588,8 @@ impl FacadeTopic {
 
         tx.commit().await?;
 
+        rw::schema_cache().invalidate(self.locator.name().as_str());
+
         Ok(())
     }
 
@@ -400,6 +668,235 @@ impl FacadeTopic {
         })
     }
 
+    /// Compacts the segment files overlapping `range` into a smaller number of larger
+    /// files, preserving record order by timestamp.
+    ///
+    /// Existing chunks are enumerated from the repository and each one's covering
+    /// timestamp range is resolved from its data file; only chunks overlapping `range`
+    /// are touched. Rewriting uses the union of those superseded chunks' own covering
+    /// ranges rather than the caller-supplied `range` itself, so a chunk that merely
+    /// overlaps `range` still has all of its rows — including the part outside
+    /// `range` — carried into the new file before its record is deleted. The data is
+    /// then rewritten, in timestamp order, through a fresh [`rw::ChunkedWriter`] bounded by
+    /// `params::configurables().compaction_target_size_in_bytes`, so the writer's own
+    /// size-triggered auto-split produces the "smaller number of larger files" the
+    /// caller asked for. New chunk records are created (and committed) for the rewritten
+    /// files *before* the superseded chunk records are deleted in their own transaction,
+    /// and the superseded data files are removed from the store only after that
+    /// transaction commits — so a concurrent reader always sees either the old files or
+    /// the new ones, never a gap.
+    pub async fn compact(
+        &self,
+        range: types::TimestampRange,
+        format: rw::Format,
+        timeseries_querier: query::TimeseriesRef,
+    ) -> Result<CompactionSummary, FacadeError> {
+        let mut cx = self.repo.connection();
+        let record = repo::topic_find_by_locator(&mut cx, &self.locator).await?;
+
+        let mut superseded = Vec::new();
+        let mut rewrite_range = None;
+        for chunk in repo::chunk_list_by_topic(&mut cx, record.topic_id).await? {
+            let covering = timeseries_querier
+                .read(&chunk.datafile, format.clone(), None)
+                .await?
+                .timestamp_range()
+                .await?;
+
+            if ranges_overlap(&covering, &range) {
+                rewrite_range = Some(match rewrite_range {
+                    Some(acc) => range_union(&acc, &covering),
+                    None => covering,
+                });
+                superseded.push(chunk);
+            }
+        }
+
+        let mut summary = CompactionSummary {
+            segments_examined: superseded.len(),
+            ..Default::default()
+        };
+
+        // Nothing worth rewriting if there's at most one overlapping segment already.
+        if superseded.len() < 2 {
+            return Ok(summary);
+        }
+
+        let target_size = params::configurables().compaction_target_size_in_bytes;
+        let run_id = types::Timestamp::now().as_i64() as u128;
+
+        let metadata = self.metadata().await?;
+        let dek = self.dek_for_topic(&metadata).await?;
+
+        // `rewrite_range` covers every superseded chunk in full; it's `Some` here since
+        // we already returned above when fewer than two chunks were superseded.
+        let rewrite_range = rewrite_range.unwrap_or(range);
+
+        let result = timeseries_querier
+            .read(self.path(), format, None)
+            .await?
+            .filter_by_timestamp_range(rewrite_range)?;
+
+        let mut stream = result.stream().await?;
+
+        let mut writer = rw::ChunkedWriter::new(
+            self.store.clone(),
+            self.path(),
+            format,
+            move |path, format, idx| {
+                types::TopicResourceLocator::from(path).path_compacted(run_id, idx, format)
+            },
+        )
+        .with_max_chunk_size(Some(target_size))
+        .with_encryption_key(dek);
+
+        {
+            let topic_id = record.topic_id;
+            let repo = self.repo.clone();
+
+            writer.on_chunk_created(move |target_path, _cols_stats, chunk_metadata, checksum| {
+                let repo = repo.clone();
+
+                async move {
+                    repo::FacadeChunk::create(
+                        topic_id,
+                        &target_path,
+                        chunk_metadata.size_bytes as i64,
+                        chunk_metadata.row_count as i64,
+                        checksum,
+                        &repo,
+                    )
+                    .await?
+                    .finalize()
+                    .await?;
+
+                    Ok(())
+                }
+            });
+        }
+
+        while let Some(batch) = stream.try_next().await.map_err(query::Error::from)? {
+            writer.write::<store::Store>(&batch).await?;
+        }
+
+        let write_summary = writer.finalize::<store::Store>().await?;
+        summary.files_written = write_summary.number_of_chunks_created;
+
+        if summary.files_written == 0 {
+            // The requested range matched no rows; leave the superseded chunks alone.
+            return Ok(summary);
+        }
+
+        // Swap the superseded chunk records out in a single transaction. The new chunk
+        // records were already committed above, so readers never see a window with
+        // neither the old nor the new data present.
+        let mut tx = self.repo.transaction().await?;
+        for chunk in &superseded {
+            repo::chunk_delete(&mut tx, chunk.chunk_id).await?;
+        }
+        tx.commit().await?;
+
+        summary.segments_merged = superseded.len();
+
+        // Only now remove the superseded files from the store.
+        for chunk in &superseded {
+            self.store.delete(&chunk.datafile).await?;
+        }
+
+        // Keep the Iceberg catalog view in sync with the file set this compaction just
+        // committed (see `FacadeTopic::sync_iceberg_snapshot`).
+        self.sync_iceberg_snapshot(format, timeseries_querier).await?;
+
+        Ok(summary)
+    }
+
+    /// Builds an Iceberg snapshot reflecting this topic's current file set.
+    ///
+    /// Called by [`FacadeTopic::sync_iceberg_snapshot`] right after [`FacadeTopic::compact`]
+    /// changes the topic's file set, so the Iceberg catalog view served by `mosaicod_iceberg`
+    /// stays in sync with committed state instead of only refreshing on demand. `create`
+    /// has no data files yet to snapshot, and `delete` removes any persisted snapshot as
+    /// part of deleting the topic's whole directory tree, so neither needs its own call here.
+    pub async fn iceberg_snapshot(
+        &self,
+        format: rw::Format,
+        timeseries_querier: query::TimeseriesRef,
+        base: mosaicod_iceberg::TableMetadata,
+    ) -> Result<mosaicod_iceberg::TableMetadata, FacadeError> {
+        let mut cx = self.repo.connection();
+        let record = repo::topic_find_by_locator(&mut cx, &self.locator).await?;
+
+        let mut data_files = Vec::new();
+        for chunk in repo::chunk_list_by_topic(&mut cx, record.topic_id).await? {
+            let range = timeseries_querier
+                .read(&chunk.datafile, format.clone(), None)
+                .await?
+                .timestamp_range()
+                .await?;
+
+            data_files.push(mosaicod_iceberg::DataFile::new(
+                chunk.datafile.to_string_lossy(),
+                chunk.row_count,
+                chunk.size_bytes,
+                &range,
+            ));
+        }
+
+        let timestamp_ms = types::Timestamp::now().as_i64() / 1_000_000;
+
+        Ok(base.with_new_snapshot(data_files, timestamp_ms))
+    }
+
+    /// Reads this topic's persisted Iceberg table metadata, if a snapshot has ever been
+    /// recorded for it.
+    async fn iceberg_metadata_read(&self) -> Result<Option<mosaicod_iceberg::TableMetadata>, FacadeError> {
+        let path = self.locator.path_iceberg_metadata();
+
+        if !self.store.exists(&path).await? {
+            return Ok(None);
+        }
+
+        let bytes = self.store.read_bytes(&path).await?;
+        let metadata = serde_json::from_slice(&bytes)
+            .map_err(|e| FacadeError::missing_data(format!("corrupt Iceberg table metadata for `{}` :: {e}", self.locator)))?;
+
+        Ok(Some(metadata))
+    }
+
+    /// Writes this topic's Iceberg table metadata to the object store, alongside the manifest.
+    async fn iceberg_metadata_write(&self, metadata: &mosaicod_iceberg::TableMetadata) -> Result<(), FacadeError> {
+        let path = self.locator.path_iceberg_metadata();
+        let bytes = serde_json::to_vec(metadata)
+            .map_err(|e| FacadeError::missing_data(format!("unable to serialize Iceberg table metadata for `{}` :: {e}", self.locator)))?;
+
+        self.store.write_bytes(&path, bytes).await?;
+
+        Ok(())
+    }
+
+    /// Refreshes this topic's Iceberg table snapshot and persists it, so the catalog view
+    /// served by `mosaicod_iceberg` stays in sync with committed state.
+    ///
+    /// Bootstraps a fresh table (`location` rooted at this topic's own path) the first time
+    /// it's called for a topic with no prior snapshot on record; every later call builds on
+    /// the previously persisted metadata via [`FacadeTopic::iceberg_snapshot`].
+    async fn sync_iceberg_snapshot(
+        &self,
+        format: rw::Format,
+        timeseries_querier: query::TimeseriesRef,
+    ) -> Result<(), FacadeError> {
+        let base = match self.iceberg_metadata_read().await? {
+            Some(base) => base,
+            None => {
+                let schema = self.arrow_schema(format.clone()).await?;
+                mosaicod_iceberg::TableMetadata::new(self.locator.name(), &schema)
+            }
+        };
+
+        let updated = self.iceberg_snapshot(format, timeseries_querier, base).await?;
+        self.iceberg_metadata_write(&updated).await
+    }
+
     /// Computes the optimal batch size based on topic statistics from the database.
     ///
     /// Returns `Some(batch_size)` if statistics are available, `None` otherwise
@@ -418,6 +915,126 @@ impl FacadeTopic {
 
         Ok(batch_size as usize)
     }
+
+    /// Re-reads every chunk recorded for this topic, recomputes its CRC32C checksum (see
+    /// [`rw::checksum::crc32c`]), and compares it against the checksum [`rw::ChunkedWriter`]
+    /// recorded for that chunk at write time. A mismatch is surfaced as a
+    /// [`types::NotifyType::Error`] notification (see [`FacadeTopic::notify`]) and the chunk is
+    /// marked corrupted in the repository, for [`FacadeTopic::chunks_stats`] and
+    /// [`FacadeTopic::repair`] to act on.
+    ///
+    /// Meant to run periodically in the background against topics on remote/unreliable object
+    /// stores, independent of any reads or writes in flight; this crate doesn't yet have a
+    /// scheduler that calls it automatically.
+    pub async fn scrub(&self) -> Result<ScrubSummary, FacadeError> {
+        let mut cx = self.repo.connection();
+        let topic_id = self.resource_id().await?.id;
+        let chunks = repo::chunk_list_by_topic(&mut cx, topic_id).await?;
+
+        let mut summary = ScrubSummary::default();
+
+        for chunk in chunks {
+            summary.chunks_examined += 1;
+
+            let bytes = self.store.read_bytes(&chunk.datafile).await?;
+            let actual = rw::checksum::crc32c(&bytes);
+
+            if actual != chunk.checksum {
+                summary.chunks_corrupted += 1;
+
+                let mut tx = self.repo.transaction().await?;
+                repo::chunk_mark_corrupted(&mut tx, chunk.chunk_id).await?;
+                tx.commit().await?;
+
+                // A failure to record the notification shouldn't stop the sweep from covering
+                // the rest of the topic's chunks; the mismatch itself is already durably
+                // recorded above via `chunk_mark_corrupted`.
+                if let Err(e) = self
+                    .notify(
+                        types::NotifyType::Error,
+                        format!(
+                            "chunk `{}` failed checksum verification (expected {:08x}, got {:08x})",
+                            chunk.datafile.to_string_lossy(),
+                            chunk.checksum,
+                            actual
+                        ),
+                    )
+                    .await
+                {
+                    warn!("failed to record corruption notification for `{}` :: {e}", chunk.datafile.to_string_lossy());
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Repairs a chunk previously marked corrupted by [`FacadeTopic::scrub`].
+    ///
+    /// `replacement`, if given, is the chunk's plaintext replacement data (re-derived from
+    /// another replica or a reprocessed source, outside this function's concern) — it's
+    /// transparently re-encrypted under this topic's data-encryption key first, same as the
+    /// regular write path, if the topic has encryption enabled. The chunk's data file and
+    /// recorded checksum are then rewritten in place and its corrupted mark cleared. Without a
+    /// replacement, the chunk is dropped entirely: its data file is deleted and its repository
+    /// record removed, acknowledging that the data it held can't be recovered.
+    pub async fn repair(&self, chunk_id: i32, replacement: Option<Vec<u8>>) -> Result<(), FacadeError> {
+        let mut cx = self.repo.connection();
+        let chunk = repo::chunk_find_by_id(&mut cx, chunk_id).await?;
+
+        match replacement {
+            Some(plaintext) => {
+                let metadata = self.metadata().await?;
+                let dek = self.dek_for_topic(&metadata).await?;
+
+                // `chunk.index` was already used once, to encrypt the original (now-corrupted)
+                // bytes under this DEK — re-using it here would encrypt a *different* plaintext
+                // under the same (key, nonce) pair, which breaks AES-GCM's confidentiality
+                // guarantee for every other chunk under this DEK, not just this one.
+                // `chunk.version` (bumped on every repair by `repo::chunk_repair`) carves out a
+                // disjoint slice of the per-topic nonce space for each repair attempt instead.
+                let nonce_chunk_id =
+                    (chunk.version as i64 + 1) * REPAIR_NONCE_STRIDE + chunk.index as i64;
+
+                let bytes = match &dek {
+                    Some(dek) => rw::crypto::encrypt_chunk(dek, nonce_chunk_id, &plaintext)?,
+                    None => plaintext,
+                };
+                let checksum = rw::checksum::crc32c(&bytes);
+
+                self.store.write_bytes(&chunk.datafile, bytes).await?;
+
+                let mut tx = self.repo.transaction().await?;
+                // Clears the corrupted mark `scrub` set, in addition to recording the new
+                // checksum, so a repaired chunk doesn't keep reading back as corrupted.
+                repo::chunk_repair(&mut tx, chunk_id, checksum).await?;
+                tx.commit().await?;
+            }
+            None => {
+                self.store.delete(&chunk.datafile).await?;
+
+                let mut tx = self.repo.transaction().await?;
+                repo::chunk_delete(&mut tx, chunk_id).await?;
+                tx.commit().await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Stride separating the nonce-id space used by each successive [`FacadeTopic::repair`] of a
+/// given chunk from the original write's and every prior repair's, chosen comfortably larger
+/// than any topic will ever have chunks.
+const REPAIR_NONCE_STRIDE: i64 = 1 << 32;
+
+/// Outcome of a single [`FacadeTopic::scrub`] run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ScrubSummary {
+    /// Number of chunks whose stored bytes were re-read and checksummed.
+    pub chunks_examined: usize,
+    /// Number of those chunks whose checksum no longer matched what was recorded at write time.
+    pub chunks_corrupted: usize,
 }
 
 /// A guard ensuring exclusive write access to a [`FacadeTopic`].
@@ -457,6 +1074,16 @@ impl<'a> FacadeTopicWriterGuard<'a> {
 
         Ok(())
     }
+
+    /// Aborts the writer, purging every chunk it has already written to the store (per
+    /// [`rw::AbortPolicy`], defaults to purge) without consolidating the topic manifest or
+    /// locking the topic. Use this instead of just dropping the guard so that chunks already
+    /// uploaded for this attempt don't linger, unaccounted for, in the store.
+    pub async fn abort(self) -> Result<(), FacadeError> {
+        trace!("internal writer aborted");
+        self.writer.abort().await?;
+        Ok(())
+    }
 }
 
 impl<'a> std::ops::Deref for FacadeTopicWriterGuard<'a> {
@@ -0,0 +1,237 @@
+//! A write-ahead journal giving [`super::facades::FacadeTopic`] crash-safe finalize semantics
+//! across the store/repository boundary.
+//!
+//! `create`/`update`/`finalize` each perform a sequence of store writes and repository
+//! mutations that, individually, are fine, but whose combination isn't atomic: a process crash
+//! between, say, the manifest write and the topic lock leaves a topic that looks finalized on
+//! the store side but unlocked on the repository side. [`JournalRecord`] values recorded as each
+//! step completes let a restarted process tell which steps already landed and replay (or
+//! re-trigger) whatever didn't, instead of guessing from store/repository state alone.
+//!
+//! Records are packed into fixed-size blocks, each closed off with a checksum seeded from the
+//! previous block's checksum (the Fxfs journal design): a torn write only ever corrupts the
+//! block being written, and [`decode`] stops at the first block whose checksum doesn't chain,
+//! discarding that block and everything after it as not-yet-committed.
+
+/// A single journaled mutation, covering one step of [`super::facades::FacadeTopic`]'s
+/// create/finalize flow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalRecord {
+    /// The repository record for a topic has been created.
+    TopicCreate { locator: String },
+    /// A chunk data file at `path` (the `idx`-th chunk) has been written to the store.
+    ChunkWritten { idx: usize, path: String },
+    /// The topic manifest has been written to the store.
+    ManifestWritten,
+    /// The topic's repository record has been locked.
+    TopicLock,
+}
+
+/// Tags identifying a [`JournalRecord`] variant on the wire. `0` is reserved as the padding/end
+/// marker `decode_record` stops at, so real tags start from `1`.
+mod tag {
+    pub const TOPIC_CREATE: u8 = 1;
+    pub const CHUNK_WRITTEN: u8 = 2;
+    pub const MANIFEST_WRITTEN: u8 = 3;
+    pub const TOPIC_LOCK: u8 = 4;
+}
+
+/// Size, in bytes, of one journal block.
+const BLOCK_SIZE: usize = 4096;
+
+/// Size of the trailing chained checksum every block ends with.
+const CHECKSUM_SIZE: usize = 8;
+
+/// How many payload bytes a block carries alongside its checksum.
+const BLOCK_PAYLOAD_SIZE: usize = BLOCK_SIZE - CHECKSUM_SIZE;
+
+#[derive(Debug, thiserror::Error)]
+pub enum JournalError {
+    #[error("journal record has unknown tag `{0}`")]
+    UnknownTag(u8),
+    #[error("journal record is truncated")]
+    Truncated,
+}
+
+fn encode_record(record: &JournalRecord) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    match record {
+        JournalRecord::TopicCreate { locator } => {
+            out.push(tag::TOPIC_CREATE);
+            out.extend((locator.len() as u32).to_le_bytes());
+            out.extend(locator.as_bytes());
+        }
+        JournalRecord::ChunkWritten { idx, path } => {
+            out.push(tag::CHUNK_WRITTEN);
+            out.extend((*idx as u64).to_le_bytes());
+            out.extend((path.len() as u32).to_le_bytes());
+            out.extend(path.as_bytes());
+        }
+        JournalRecord::ManifestWritten => out.push(tag::MANIFEST_WRITTEN),
+        JournalRecord::TopicLock => out.push(tag::TOPIC_LOCK),
+    }
+
+    out
+}
+
+/// Decodes a single record from the front of `buf`, returning it alongside the number of bytes
+/// it consumed. Returns `Ok(None)` on a `0` tag (the padding every block is filled out with), and
+/// [`JournalError::Truncated`] if `buf` runs out before a fully-framed record does.
+fn decode_record(buf: &[u8]) -> Result<Option<(JournalRecord, usize)>, JournalError> {
+    let Some(&tag) = buf.first() else {
+        return Ok(None);
+    };
+
+    let read_u32 = |buf: &[u8], at: usize| -> Result<u32, JournalError> {
+        let slice = buf.get(at..at + 4).ok_or(JournalError::Truncated)?;
+        Ok(u32::from_le_bytes(slice.try_into().expect("slice is exactly 4 bytes")))
+    };
+
+    match tag {
+        0 => Ok(None),
+        tag::TOPIC_CREATE => {
+            let len = read_u32(buf, 1)? as usize;
+            let locator = buf.get(5..5 + len).ok_or(JournalError::Truncated)?;
+            let locator = String::from_utf8_lossy(locator).into_owned();
+            Ok(Some((JournalRecord::TopicCreate { locator }, 5 + len)))
+        }
+        tag::CHUNK_WRITTEN => {
+            let idx_bytes = buf.get(1..9).ok_or(JournalError::Truncated)?;
+            let idx = u64::from_le_bytes(idx_bytes.try_into().expect("slice is exactly 8 bytes")) as usize;
+            let len = read_u32(buf, 9)? as usize;
+            let path = buf.get(13..13 + len).ok_or(JournalError::Truncated)?;
+            let path = String::from_utf8_lossy(path).into_owned();
+            Ok(Some((JournalRecord::ChunkWritten { idx, path }, 13 + len)))
+        }
+        tag::MANIFEST_WRITTEN => Ok(Some((JournalRecord::ManifestWritten, 1))),
+        tag::TOPIC_LOCK => Ok(Some((JournalRecord::TopicLock, 1))),
+        other => Err(JournalError::UnknownTag(other)),
+    }
+}
+
+/// Chains `payload`'s checksum onto `prev`, the previous block's checksum (or `0` for the
+/// journal's first block), so a block can only validate if every block before it did too. This
+/// is a plain FNV-1a mixed with `prev`, not a cryptographic checksum: it exists to detect torn
+/// writes, not tampering.
+fn chain_checksum(prev: u64, payload: &[u8]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = prev ^ FNV_OFFSET_BASIS;
+    for &byte in payload {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    hash
+}
+
+/// Encodes `records` into a checksum-chained block stream ready to be written to the journal's
+/// store path as a whole (the object store this crate writes chunk/manifest data through has no
+/// append primitive, so a journal write is a full rewrite of the file, not an incremental one).
+pub fn encode(records: &[JournalRecord]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for record in records {
+        payload.extend(encode_record(record));
+    }
+
+    let mut out = Vec::with_capacity(payload.len().div_ceil(BLOCK_PAYLOAD_SIZE) * BLOCK_SIZE);
+    let mut prev = 0u64;
+
+    for chunk in payload.chunks(BLOCK_PAYLOAD_SIZE) {
+        let mut block_payload = chunk.to_vec();
+        block_payload.resize(BLOCK_PAYLOAD_SIZE, 0);
+
+        let checksum = chain_checksum(prev, &block_payload);
+        out.extend(&block_payload);
+        out.extend(checksum.to_le_bytes());
+        prev = checksum;
+    }
+
+    out
+}
+
+/// Decodes a checksum-chained block stream produced by [`encode`], stopping at (and discarding)
+/// the first block whose checksum doesn't chain from the one before it — an incomplete trailing
+/// block, left by a journal write that never finished, reads back as "no more valid records"
+/// rather than an error.
+pub fn decode(bytes: &[u8]) -> Vec<JournalRecord> {
+    let mut valid_payload = Vec::new();
+    let mut prev = 0u64;
+
+    for block in bytes.chunks(BLOCK_SIZE) {
+        if block.len() != BLOCK_SIZE {
+            break;
+        }
+
+        let (block_payload, checksum_bytes) = block.split_at(BLOCK_PAYLOAD_SIZE);
+        let stored = u64::from_le_bytes(checksum_bytes.try_into().expect("slice is exactly 8 bytes"));
+
+        if chain_checksum(prev, block_payload) != stored {
+            break;
+        }
+
+        prev = stored;
+        valid_payload.extend_from_slice(block_payload);
+    }
+
+    let mut records = Vec::new();
+    let mut offset = 0;
+
+    while let Ok(Some((record, consumed))) = decode_record(&valid_payload[offset..]) {
+        records.push(record);
+        offset += consumed;
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_reverses_encode_across_multiple_records() {
+        let records = vec![
+            JournalRecord::TopicCreate { locator: "seq/topic".to_owned() },
+            JournalRecord::ChunkWritten { idx: 0, path: "seq/topic/data-00000.parquet".to_owned() },
+            JournalRecord::ChunkWritten { idx: 1, path: "seq/topic/data-00001.parquet".to_owned() },
+            JournalRecord::ManifestWritten,
+            JournalRecord::TopicLock,
+        ];
+
+        assert_eq!(decode(&encode(&records)), records);
+    }
+
+    #[test]
+    fn decode_spans_a_record_across_a_block_boundary() {
+        // A locator long enough that its record can't fit in a single block's remaining
+        // payload, forcing `encode` to split it across two blocks.
+        let records = vec![JournalRecord::TopicCreate { locator: "x".repeat(BLOCK_PAYLOAD_SIZE) }];
+
+        assert_eq!(decode(&encode(&records)), records);
+    }
+
+    #[test]
+    fn decode_stops_at_first_corrupted_block() {
+        let records = vec![JournalRecord::TopicCreate { locator: "a".repeat(BLOCK_PAYLOAD_SIZE * 2) }];
+
+        let mut bytes = encode(&records);
+        // Flip a byte inside the second block's payload, simulating a torn write.
+        let corrupt_at = BLOCK_SIZE + 4;
+        bytes[corrupt_at] ^= 0xff;
+
+        assert!(decode(&bytes).is_empty());
+    }
+
+    #[test]
+    fn decode_of_empty_bytes_is_empty() {
+        assert!(decode(&[]).is_empty());
+    }
+
+    #[test]
+    fn encode_of_no_records_decodes_back_to_no_records() {
+        assert!(decode(&encode(&[])).is_empty());
+    }
+}
@@ -2,20 +2,35 @@ use std::path::PathBuf;
 use std::pin::Pin;
 
 use arrow::array::RecordBatch;
-use log::{debug, trace};
+use futures::stream::{self, FuturesOrdered};
+use futures::{Stream, StreamExt};
+use log::{debug, trace, warn};
 
 use crate::{traits, types};
+use crate::types::crypto::DataEncryptionKey;
 
 use super::Error;
 use super::Format;
+use super::checksum;
 use super::chunk_writer::{ChunkMetadata, ChunkWriter};
-
-/// Callback called just before file serialization
+use super::content_defined_chunking::{ChunkingStrategy, RollingHash};
+use super::crypto;
+use super::merkle;
+use super::parallel_row_groups;
+
+/// Callback called once a chunk has been written to `write_target`. The `u32` is the chunk's CRC32C checksum
+/// (see [`checksum::crc32c`]), computed over the exact bytes written to `write_target` (so over
+/// the ciphertext, for an encrypted topic), for the caller to persist alongside the chunk's
+/// other repository stats. `ChunkMetadata` additionally carries a Merkle root over those same
+/// bytes (see [`merkle::merkle_root`]), available for callers that want to record a
+/// finer-grained integrity manifest than a single flat checksum allows; none of this crate's own
+/// `on_chunk_created` callbacks persist it yet.
 type OnChunkCallback = Box<
     dyn Fn(
             std::path::PathBuf,
             types::OntologyModelStats,
             ChunkMetadata,
+            u32,
         ) -> Pin<Box<dyn Future<Output = Result<(), Box<dyn std::error::Error>>> + Send>>
         + Send
         + Sync,
@@ -31,6 +46,56 @@ pub struct ChunkedWriterSummary {
     pub number_of_chunks_created: usize,
 }
 
+/// Parses a human-readable byte size such as `"64MB"`, `"512KB"` or `"1GB"` into a raw byte
+/// count, for the `write_sst_max_buffer_size` configurable. A bare number (no suffix) is
+/// interpreted as bytes. Units are decimal (`1MB == 1_000_000` bytes) and case-insensitive.
+pub fn parse_buffer_size(input: &str) -> Result<usize, Error> {
+    let input = input.trim();
+    let invalid = || Error::InvalidBufferSize(input.to_owned());
+
+    let split_at = input
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split_at);
+
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+
+    let multiplier = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1.0,
+        "KB" => 1_000.0,
+        "MB" => 1_000_000.0,
+        "GB" => 1_000_000_000.0,
+        _ => return Err(invalid()),
+    };
+
+    Ok((number * multiplier) as usize)
+}
+
+/// How [`ChunkedWriter::abort`] (and the automatic rollback `finalize` runs when it fails)
+/// treats chunks already written to `write_target`.
+///
+/// Either variant only affects `write_target` itself. If an earlier chunk's
+/// `on_chunk_created` callback already committed a repository record for it (as
+/// `do_put.rs`'s does), that record is untouched either way — callers whose callback has
+/// side effects beyond the store are responsible for reconciling those separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbortPolicy {
+    /// Delete every chunk this writer has written so far, leaving the target with none of
+    /// its output. The default, since an abandoned writer otherwise leaves orphaned chunks
+    /// in the target with no record of them anywhere.
+    Purge,
+    /// Leave written chunks in place. For callers that retry a failed write idempotently
+    /// keyed on chunk path/index, where the already-uploaded chunks are harmless and
+    /// re-uploading them on retry would be wasted work.
+    Keep,
+}
+
+impl Default for AbortPolicy {
+    fn default() -> Self {
+        Self::Purge
+    }
+}
+
 /// Writes [`RecordBatch`] into multiple chunks to a location. A location is a path like structure.
 /// Internally the [`ChunkedWriter`] can subdivide the batches in multiple files.
 ///
@@ -69,6 +134,106 @@ pub struct ChunkedWriter<W> {
     /// the current chunk is finalized and a new one is started.
     /// `None` means no limit (current behavior preserved).
     max_chunk_size: Option<usize>,
+
+    /// Topic data-encryption key, if this topic has encryption enabled (see
+    /// [`crate::repo::facades::FacadeTopic::dek_for_topic`]). When set, each chunk's
+    /// finalized bytes are passed through [`crypto::encrypt_chunk`] before being handed to
+    /// `write_target`.
+    encryption_key: Option<DataEncryptionKey>,
+
+    /// How chunk boundaries are decided. Defaults to [`ChunkingStrategy::Fixed`], which
+    /// preserves the `max_chunk_size`-only behavior above.
+    chunking_strategy: ChunkingStrategy,
+
+    /// Rolling hash for the chunk currently being accumulated, when `chunking_strategy` is
+    /// [`ChunkingStrategy::ContentDefined`]. Lazily created on the first batch of each chunk and
+    /// reset to `None` once a boundary is cut, so each chunk starts with a fresh window.
+    rolling_hash: Option<RollingHash>,
+
+    /// When set, a [`RecordBatch`] whose estimated encoded size alone exceeds
+    /// `max_chunk_size` is sliced row-wise (see [`Self::with_slice_oversized_batches`])
+    /// instead of being written whole as a single oversized chunk.
+    slice_oversized_batches: bool,
+
+    /// Maximum number of chunk finalize+upload operations allowed to run concurrently (see
+    /// [`Self::with_max_inflight_chunks`]). `None` (the default) awaits each chunk to
+    /// completion before starting the next, exactly as before this option existed.
+    max_inflight_chunks: Option<usize>,
+
+    /// Finalize+upload tasks spawned but not yet awaited, when `max_inflight_chunks` is set.
+    /// Each task only finalizes, encrypts, checksums and uploads its chunk — it does *not*
+    /// call `on_chunk_created_clbk` itself, since `tokio::spawn`'d tasks can finish in any
+    /// order; the callback is only ever invoked from [`Self::await_next_inflight`], which
+    /// drains this queue (and so calls back) strictly in chunk-creation order.
+    inflight: FuturesOrdered<
+        tokio::task::JoinHandle<Result<(PathBuf, types::OntologyModelStats, ChunkMetadata, u32), Error>>,
+    >,
+
+    /// Abort handles mirroring every task ever pushed into `inflight`, so that dropping this
+    /// writer before all of them are awaited (e.g. because an earlier `write` call returned an
+    /// error) cancels the still-running ones instead of leaving them uploading chunks to the
+    /// store in the background with no corresponding `on_chunk_created` call. Aborting a task
+    /// that already completed is a harmless no-op.
+    inflight_abort_handles: Vec<tokio::task::AbortHandle>,
+
+    /// Paths of chunks this writer has confirmed written to `write_target` so far, in
+    /// creation order. Used by [`Self::abort`] (and `finalize`'s automatic rollback on
+    /// failure) to know what to purge under [`AbortPolicy::Purge`].
+    written_chunk_paths: Vec<PathBuf>,
+
+    /// Governs what [`Self::abort`] does with `written_chunk_paths`. Defaults to
+    /// [`AbortPolicy::Purge`]; see [`Self::with_abort_policy`].
+    abort_policy: AbortPolicy,
+
+    /// When set (and `max_chunk_size` is `None`), the whole write is encoded as a single
+    /// output file using this many blocking-pool workers to encode row groups concurrently
+    /// (see [`Self::with_parallel_row_groups`]), instead of through the normal per-chunk
+    /// `writer` path above.
+    parallel_row_groups: Option<usize>,
+
+    /// Batches accumulated so far while [`Self::with_parallel_row_groups`] is active, in place
+    /// of writing them incrementally to `writer`. Row-group parallelism needs the full batch
+    /// set up front to partition across workers, so nothing is encoded until `finalize`.
+    parallel_batches: Vec<RecordBatch>,
+}
+
+impl<W> Drop for ChunkedWriter<W> {
+    fn drop(&mut self) {
+        for handle in &self.inflight_abort_handles {
+            handle.abort();
+        }
+    }
+}
+
+/// Finalizes `writer` on the blocking thread pool: runs parquet finalization, optionally
+/// encrypts the result with `encryption_key`, and computes a Merkle root over the exact final
+/// bytes (see [`merkle::merkle_root`]), attaching it to the returned metadata. Shared by
+/// [`ChunkedWriter::finalize_chunk`], which additionally checksums those same bytes before
+/// upload, and [`ChunkedWriter::finalize_for_stream`], which hands the bytes straight back to
+/// its caller and has no checksum to compute.
+async fn finalize_writer_bytes(
+    writer: ChunkWriter,
+    chunk_id: i64,
+    encryption_key: Option<DataEncryptionKey>,
+) -> Result<(Vec<u8>, types::OntologyModelStats, ChunkMetadata), Error> {
+    tokio::task::spawn_blocking(move || {
+        let (buffer, om_stats, metadata) = writer.finalize()?;
+
+        let buffer = match &encryption_key {
+            Some(dek) => crypto::encrypt_chunk(dek, chunk_id, &buffer)?,
+            None => buffer,
+        };
+
+        let merkle_root = merkle::merkle_root(&buffer);
+        let metadata = ChunkMetadata {
+            merkle_root,
+            ..metadata
+        };
+
+        Ok::<_, Error>((buffer, om_stats, metadata))
+    })
+    .await
+    .map_err(|e| Error::BlockingOperationError(e.to_string()))?
 }
 
 impl<W> ChunkedWriter<W> {
@@ -91,6 +256,17 @@ impl<W> ChunkedWriter<W> {
             on_chunk_created_clbk: None,
             on_file_format: Box::new(format_callback),
             max_chunk_size: None,
+            encryption_key: None,
+            chunking_strategy: ChunkingStrategy::default(),
+            rolling_hash: None,
+            slice_oversized_batches: false,
+            max_inflight_chunks: None,
+            inflight: FuturesOrdered::new(),
+            inflight_abort_handles: Vec::new(),
+            written_chunk_paths: Vec::new(),
+            abort_policy: AbortPolicy::default(),
+            parallel_row_groups: None,
+            parallel_batches: Vec::new(),
         }
     }
 
@@ -107,18 +283,99 @@ impl<W> ChunkedWriter<W> {
         self
     }
 
+    /// Like [`with_max_chunk_size`](Self::with_max_chunk_size), but takes the threshold as a
+    /// human-readable size (e.g. `"64MB"`), as read from the `write_sst_max_buffer_size`
+    /// configurable. `None` leaves the chunk size unlimited.
+    pub fn with_max_buffer_size_readable(self, size: Option<&str>) -> Result<Self, Error> {
+        let size = size.map(parse_buffer_size).transpose()?;
+        Ok(self.with_max_chunk_size(size))
+    }
+
+    /// Sets the data-encryption key chunks are encrypted under before being written, if this
+    /// topic has encryption enabled. `None` (the default) writes chunks as plaintext.
+    pub fn with_encryption_key(mut self, dek: Option<DataEncryptionKey>) -> Self {
+        self.encryption_key = dek;
+        self
+    }
+
+    /// Sets how chunk boundaries are decided. Defaults to [`ChunkingStrategy::Fixed`].
+    ///
+    /// [`ChunkingStrategy::ContentDefined`] rolls a hash over each batch's raw column bytes and
+    /// cuts a chunk once the hash lands on a boundary, so that re-ingesting a topic with a small
+    /// edit shifts only the chunks around the edit instead of every chunk downstream of it —
+    /// letting the object store deduplicate unchanged chunks across versions.
+    pub fn with_chunking_strategy(mut self, strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = strategy;
+        self
+    }
+
+    /// Allows up to `n` chunk finalize+upload operations to run concurrently instead of fully
+    /// awaiting each one before starting the next, so encoding chunk N+1 overlaps uploading
+    /// chunk N on high-latency object stores. `None` (the default) awaits each chunk to
+    /// completion before starting the next, exactly as before this option existed.
+    pub fn with_max_inflight_chunks(mut self, n: Option<usize>) -> Self {
+        self.max_inflight_chunks = n;
+        self
+    }
+
+    /// Sets the policy [`Self::abort`] (and `finalize`'s automatic rollback on failure) uses
+    /// for chunks already written to `write_target`. Defaults to [`AbortPolicy::Purge`].
+    pub fn with_abort_policy(mut self, policy: AbortPolicy) -> Self {
+        self.abort_policy = policy;
+        self
+    }
+
+    /// When `enabled`, a [`RecordBatch`] whose estimated size alone exceeds `max_chunk_size`
+    /// is sliced row-wise into several smaller batches (fed through the normal chunk-boundary
+    /// logic) instead of being written whole as a single oversized chunk. Off by default,
+    /// preserving the historical "one big chunk" behavior.
+    ///
+    /// The estimate (`RecordBatch::get_array_memory_size`) is the batch's in-memory Arrow
+    /// footprint, not its actual encoded size, so highly compressible data may get sliced into
+    /// more, smaller chunks than strictly necessary — a worthwhile tradeoff against the
+    /// alternative of computing the real encoded size up front.
+    ///
+    /// A single row whose own size already exceeds `max_chunk_size` can't be sliced any
+    /// smaller, so it's still written alone, producing one oversized chunk for that row.
+    ///
+    /// Gated purely on `max_chunk_size`, independent of `chunking_strategy`. Combining this
+    /// with [`ChunkingStrategy::ContentDefined`] is not recommended: `should_finalize_chunk`'s
+    /// rolling hash reads each column's underlying buffers directly and doesn't account for a
+    /// slice's logical offset/length, so it rehashes bytes outside the slice too, producing
+    /// boundaries that don't stay tied to a slice's actual content.
+    pub fn with_slice_oversized_batches(mut self, enabled: bool) -> Self {
+        self.slice_oversized_batches = enabled;
+        self
+    }
+
+    /// Encodes the whole write as a single output file, spreading Parquet row-group encoding
+    /// across `n` blocking-pool workers instead of the normal single-threaded `writer` path (see
+    /// [`parallel_row_groups::write_parallel`]). Only takes effect when `max_chunk_size` is
+    /// `None`; if a chunk size limit is set, this is ignored and batches flow through the usual
+    /// per-chunk path unchanged, since "one large file" and "many size-bounded chunks" are
+    /// mutually exclusive goals.
+    ///
+    /// Bloom filters and column indexes both need a single writer's global view of a column
+    /// across the whole file, so neither is produced for a file written this way, regardless of
+    /// what the configured format's [`super::format::ParquetFormatStrategy::writer_properties`]
+    /// otherwise requests.
+    pub fn with_parallel_row_groups(mut self, n: usize) -> Self {
+        self.parallel_row_groups = Some(n);
+        self
+    }
+
     /// Sets a callback function that will be called every time a chunk is produced just before
     /// serialization.
     pub fn on_chunk_created<F1, Fut>(&mut self, clbk: F1)
     where
-        F1: Fn(std::path::PathBuf, types::OntologyModelStats, ChunkMetadata) -> Fut
+        F1: Fn(std::path::PathBuf, types::OntologyModelStats, ChunkMetadata, u32) -> Fut
             + Send
             + Sync
             + 'static,
         Fut: Future<Output = Result<(), Box<dyn std::error::Error>>> + Send + 'static,
     {
-        let wrapped = move |path, stats, metadata| {
-            let fut = clbk(path, stats, metadata);
+        let wrapped = move |path, stats, metadata, checksum| {
+            let fut = clbk(path, stats, metadata, checksum);
             Box::pin(fut)
                 as Pin<Box<dyn Future<Output = Result<(), Box<dyn std::error::Error>>> + Send>>
         };
@@ -132,12 +389,78 @@ impl<W> ChunkedWriter<W> {
     /// based on the serialization format and the maximum chunk size (if any).
     /// To perform custom actions when a chunk is produced, use the
     /// [`on_chunk_produced`] method to set a callback function.
+    ///
+    /// When [`Self::with_slice_oversized_batches`] is enabled and `batch`'s estimated size
+    /// alone would exceed `max_chunk_size`, `batch` is sliced row-wise into several smaller
+    /// batches first (see [`Self::write_sliced`]), each written in turn.
     pub async fn write<A>(&mut self, batch: &RecordBatch) -> Result<(), Error>
     where
-        A: traits::AsyncWriteToPath,
-        W: AsRef<A>,
+        A: traits::AsyncWriteToPath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
+    {
+        if self.parallel_row_groups.is_some() && self.max_chunk_size.is_none() {
+            self.parallel_batches.push(batch.clone());
+            return Ok(());
+        }
+
+        if self.slice_oversized_batches {
+            if let Some(max) = self.max_chunk_size {
+                let estimated_size = batch.get_array_memory_size();
+                if max > 0 && estimated_size > max && batch.num_rows() > 1 {
+                    return self.write_sliced::<A>(batch, estimated_size, max).await;
+                }
+            }
+        }
+
+        self.write_one::<A>(batch).await
+    }
+
+    /// Splits an oversized `batch` (whose `estimated_size` in bytes exceeds `max`) row-wise
+    /// into `ceil(estimated_size / max)` roughly-even pieces via [`RecordBatch::slice`], and
+    /// writes each one in turn through [`Self::write_one`]'s normal chunk-boundary logic, so a
+    /// single very large batch doesn't force one very large chunk.
+    ///
+    /// Pieces are divided evenly by row count, not by actual per-row size, so a batch with
+    /// wildly uneven row sizes (e.g. a handful of large blobs among mostly-small rows) can still
+    /// land one oversized piece in the same slice — same tradeoff as the single-row case above,
+    /// just spread across a few rows instead of one.
+    async fn write_sliced<A>(
+        &mut self,
+        batch: &RecordBatch,
+        estimated_size: usize,
+        max: usize,
+    ) -> Result<(), Error>
+    where
+        A: traits::AsyncWriteToPath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
+    {
+        let num_rows = batch.num_rows();
+        let num_slices = estimated_size.div_ceil(max).max(1);
+        let rows_per_slice = num_rows.div_ceil(num_slices).max(1);
+
+        trace!(
+            "batch of {num_rows} rows (~{estimated_size} bytes) exceeds max_chunk_size {max}; \
+             slicing into ~{rows_per_slice}-row pieces"
+        );
+
+        let mut offset = 0;
+        while offset < num_rows {
+            let len = rows_per_slice.min(num_rows - offset);
+            let slice = batch.slice(offset, len);
+            self.write_one::<A>(&slice).await?;
+            offset += len;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single batch (already sliced down to size, if [`Self::write`] decided to), as
+    /// `write` always did before slicing existed.
+    async fn write_one<A>(&mut self, batch: &RecordBatch) -> Result<(), Error>
+    where
+        A: traits::AsyncWriteToPath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
     {
-        trace!("AAAAAAAAAAA");
         // Take the writer and if not inizialized creates a new one.
         // At the end the writer will be put back.
         //
@@ -146,37 +469,33 @@ impl<W> ChunkedWriter<W> {
         // chunk produced callback will be triggered
         let mut writer = match self.writer.take() {
             Some(w) => w,
-            None => ChunkWriter::try_new(batch.schema(), self.format)?,
+            None => ChunkWriter::try_new(batch.schema(), self.format.clone())?,
         };
 
         // Clone batch for spawn_blocking (requires 'static)
-        let batch = batch.clone();
+        let batch_owned = batch.clone();
 
         // Offload CPU-intensive parquet encoding/compression to blocking thread pool
         writer = tokio::task::spawn_blocking(move || {
-            writer.write(&batch)?;
+            writer.write(&batch_owned)?;
             Ok::<_, Error>(writer)
         })
         .await
         .map_err(|e| Error::BlockingOperationError(e.to_string()))??;
 
-        // Check if we should auto-finalize based on chunk size threshold
-        if let Some(max) = self.max_chunk_size {
-            let current_size = writer.memory_size();
-
-            if current_size >= max {
-                trace!(
-                    "chunk size {} bytes exceeds max {} bytes, auto-finalizing chunk {}",
-                    current_size, max, self.chunk_serialized_number
-                );
-
-                // Put the writer back for finalize() to consume it
-                self.writer = Some(writer);
-                self.finalize_chunk().await?;
-                // After finalize(), self.writer is None, ready for next chunk
-            } else {
-                self.writer = Some(writer);
-            }
+        // Check if we should auto-finalize based on the configured chunking strategy
+        let current_size = writer.memory_size();
+
+        if self.should_finalize_chunk(current_size, batch) {
+            trace!(
+                "chunk size {} bytes reached a boundary, auto-finalizing chunk {}",
+                current_size, self.chunk_serialized_number
+            );
+
+            // Put the writer back for finalize() to consume it
+            self.writer = Some(writer);
+            self.finalize_chunk().await?;
+            // After finalize(), self.writer is None, ready for next chunk
         } else {
             self.writer = Some(writer);
         }
@@ -184,27 +503,215 @@ impl<W> ChunkedWriter<W> {
         Ok(())
     }
 
+    /// Decides whether the chunk currently being accumulated should be cut off, given its
+    /// `current_size` in bytes (post-write) and the `batch` just written to it.
+    ///
+    /// For [`ChunkingStrategy::ContentDefined`], the hash is checked after every byte rather than
+    /// once at the end of the batch, cutting as soon as a boundary is found instead of only when
+    /// one happens to land on the batch's last byte — batches are still the smallest unit this
+    /// writer can cut at, but within that constraint the boundary itself stays tied to the
+    /// content that produced it, not to wherever upstream batching drew its lines.
+    fn should_finalize_chunk(&mut self, current_size: usize, batch: &RecordBatch) -> bool {
+        match self.chunking_strategy {
+            ChunkingStrategy::Fixed => self.max_chunk_size.is_some_and(|max| current_size >= max),
+            ChunkingStrategy::ContentDefined { avg, min, max } => {
+                if current_size >= max {
+                    return true;
+                }
+
+                if current_size < min {
+                    return false;
+                }
+
+                let hash = self.rolling_hash.get_or_insert_with(|| RollingHash::new(avg));
+
+                for column in batch.columns() {
+                    let data = column.to_data();
+                    for buffer in data.buffers() {
+                        for &byte in buffer.as_slice() {
+                            hash.roll(byte);
+                            if hash.is_boundary() {
+                                return true;
+                            }
+                        }
+                    }
+                }
+
+                false
+            }
+        }
+    }
+
     /// Finalizes any pending reading, writing operation.
     ///
     /// It is important to call this method to ensure that an open chunk is properly finalized
     /// and written.
+    ///
+    /// If finalization fails, this automatically rolls back per `abort_policy` (see
+    /// [`AbortPolicy`]) before returning the error, same as calling [`Self::abort`] explicitly.
     pub async fn finalize<A>(mut self) -> Result<ChunkedWriterSummary, Error>
     where
-        A: traits::AsyncWriteToPath,
-        W: AsRef<A>,
+        A: traits::AsyncWriteToPath + traits::AsyncDeletePath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
     {
-        self.finalize_chunk().await?;
+        let result = self.finalize_inner::<A>().await;
+
+        if result.is_err() {
+            // Mirror the `complete_block`/`abort_block` contract of a multipart/block
+            // writer: a failed finalize leaves nothing usable behind, so purge whatever made
+            // it to `write_target` before surfacing the original error.
+            self.purge_written_chunks::<A>().await;
+        }
+
+        result
+    }
+
+    async fn finalize_inner<A>(&mut self) -> Result<ChunkedWriterSummary, Error>
+    where
+        A: traits::AsyncWriteToPath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
+    {
+        if self.parallel_row_groups.is_some() && self.max_chunk_size.is_none() {
+            self.finalize_parallel::<A>().await?;
+            return Ok(ChunkedWriterSummary {
+                number_of_chunks_created: self.chunk_serialized_number,
+            });
+        }
+
+        // `finalize_chunk` itself may await (and fail on) an older inflight task when
+        // `max_inflight_chunks` is set; captured as `first_err` rather than propagated
+        // immediately so the drain loop below still runs and records every other inflight
+        // chunk's path — otherwise the caller's rollback (see `finalize`) would miss chunks
+        // that uploaded successfully but were never drained into `written_chunk_paths`.
+        let mut first_err = self.finalize_chunk::<A>().await.err();
+
+        // Drain every outstanding finalize+upload task (see `with_max_inflight_chunks`),
+        // invoking the callback for each in creation order. Every task is awaited even after
+        // the first error, so a failure partway through doesn't leave later tasks dangling;
+        // only the first error is surfaced.
+        while !self.inflight.is_empty() {
+            if let Err(e) = self.await_next_inflight().await {
+                first_err.get_or_insert(e);
+            }
+        }
+
+        if let Some(e) = first_err {
+            return Err(e);
+        }
 
         Ok(ChunkedWriterSummary {
             number_of_chunks_created: self.chunk_serialized_number,
         })
     }
 
+    /// Aborts this writer: discards the chunk currently being accumulated (it was never
+    /// written to `write_target`, so there's nothing to clean up for it), waits for every
+    /// outstanding finalize+upload task to finish, and then — per `abort_policy` (see
+    /// [`AbortPolicy`], defaults to `Purge`) — deletes every chunk this writer successfully
+    /// wrote to `write_target`. Once called, none of this writer's previously-written chunks
+    /// should be assumed usable.
+    pub async fn abort<A>(mut self) -> Result<(), Error>
+    where
+        A: traits::AsyncDeletePath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
+    {
+        self.writer = None;
+
+        while let Some(result) = self.drain_next_inflight().await {
+            match result {
+                Ok((path, ..)) => self.written_chunk_paths.push(path),
+                // The task itself failed (e.g. a transient upload error) rather than simply
+                // having nothing to purge; log it so a genuine failure isn't indistinguishable
+                // from a no-op drain during incident triage.
+                Err(e) => warn!("inflight chunk task failed during abort: {e}"),
+            }
+        }
+
+        self.purge_written_chunks::<A>().await;
+
+        Ok(())
+    }
+
+    /// Deletes every path in `written_chunk_paths` from `write_target`, per `abort_policy`.
+    ///
+    /// Deletions run concurrently, bounded to the same `max_inflight_chunks` limit as the
+    /// upload side (or a small fixed default if pipelining wasn't configured), rather than one
+    /// round-trip at a time, since a topic that auto-split into many chunks would otherwise
+    /// make the caller wait out a full serial pass over the store just to surface an unrelated
+    /// error — but also rather than unboundedly, since a store that auto-split into hundreds of
+    /// chunks shouldn't turn a single abort into an unthrottled burst of delete requests.
+    /// Best-effort: a deletion failure is logged and doesn't stop the rest of the purge, since
+    /// this already runs on an error/abort path where there's no further recovery to attempt.
+    ///
+    /// Note: this only purges `write_target`. Any repository records already committed for
+    /// earlier, successfully-finalized chunks in this same write (see `on_chunk_created`) are
+    /// left untouched — reconciling those against the store is out of scope here.
+    async fn purge_written_chunks<A>(&mut self)
+    where
+        A: traits::AsyncDeletePath,
+        W: AsRef<A>,
+    {
+        if self.abort_policy != AbortPolicy::Purge {
+            return;
+        }
+
+        const DEFAULT_PURGE_CONCURRENCY: usize = 8;
+        let concurrency = self.max_inflight_chunks.unwrap_or(DEFAULT_PURGE_CONCURRENCY);
+
+        let target = self.write_target.as_ref();
+        futures::stream::iter(self.written_chunk_paths.drain(..))
+            .for_each_concurrent(concurrency, |path| async move {
+                if let Err(e) = target.delete_path(&path).await {
+                    warn!(
+                        "failed to purge chunk `{}` during abort: {e}",
+                        path.to_string_lossy()
+                    );
+                }
+            })
+            .await;
+    }
+
+    /// Awaits the oldest outstanding finalize+upload task, without invoking the
+    /// `on_chunk_created` callback. Shared by [`Self::await_next_inflight`] (which adds the
+    /// callback invocation) and [`Self::abort`] (which only needs the resulting path).
+    async fn drain_next_inflight(
+        &mut self,
+    ) -> Option<Result<(PathBuf, types::OntologyModelStats, ChunkMetadata, u32), Error>> {
+        let joined = self.inflight.next().await?;
+        Some(
+            joined
+                .map_err(|e| Error::BlockingOperationError(e.to_string()))
+                .and_then(|r| r),
+        )
+    }
+
+    /// Awaits the oldest outstanding finalize+upload task and invokes
+    /// `on_chunk_created_clbk` with its result, so the callback always fires in
+    /// chunk-creation order even though the tasks themselves may finish in any order.
+    async fn await_next_inflight(&mut self) -> Result<(), Error> {
+        let Some(result) = self.drain_next_inflight().await else {
+            return Ok(());
+        };
+
+        let (path, om_stats, metadata, checksum) = result?;
+        self.written_chunk_paths.push(path.clone());
+
+        let clbk = self
+            .on_chunk_created_clbk
+            .as_ref()
+            .expect("on_chunk_created callback must be set before writing");
+
+        debug!("calling chunk serialization callback");
+        clbk(path, om_stats, metadata, checksum)
+            .await
+            .map_err(|e| Error::ChunkCreationCallbackError(e.to_string()))
+    }
+
     /// Finalize the writing process of a single chunk
     async fn finalize_chunk<A>(&mut self) -> Result<(), Error>
     where
-        A: traits::AsyncWriteToPath,
-        W: AsRef<A>,
+        A: traits::AsyncWriteToPath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
     {
         // Calling this function will "consume" the current writer.
         // If another write_batch will be called after this function call
@@ -212,37 +719,252 @@ impl<W> ChunkedWriter<W> {
         if let Some(writer) = self.writer.take() {
             let path =
                 (self.on_file_format)(&self.path, &writer.format, self.chunk_serialized_number);
+            let chunk_id = self.chunk_serialized_number as i64;
             self.chunk_serialized_number += 1;
+            self.rolling_hash = None;
+
+            let encryption_key = self.encryption_key.clone();
+            let write_target = self.write_target.clone();
+
+            // Only finalization/encryption/checksumming/upload happens in this task; the
+            // `on_chunk_created` callback is invoked later, from `await_next_inflight`, so
+            // that its side effects (e.g. persisting chunk metadata to the repo) always land
+            // in chunk-creation order even when multiple tasks run concurrently.
+            let task = tokio::spawn(async move {
+                // Stats used for query pruning are computed on the plaintext batches inside
+                // `finalize_writer_bytes`, so encrypting the finalized bytes there never affects
+                // row-group pruning.
+                let (buffer, om_stats, metadata) =
+                    finalize_writer_bytes(writer, chunk_id, encryption_key).await?;
+
+                // Computed over the exact bytes handed to `write_target` below, so a later
+                // `FacadeTopic::scrub` re-reading those same bytes from the store can recompute
+                // this checksum and compare without needing to know whether the chunk is
+                // encrypted. Run on the blocking thread pool since, like the finalization and
+                // encryption above, it's a CPU-bound full-buffer pass over a potentially large
+                // chunk.
+                let (buffer, checksum) = tokio::task::spawn_blocking(move || {
+                    let checksum = checksum::crc32c(&buffer);
+                    (buffer, checksum)
+                })
+                .await
+                .map_err(|e| Error::BlockingOperationError(e.to_string()))?;
+
+                write_target.as_ref().write_to_path(&path, buffer).await?;
+
+                Ok::<_, Error>((path, om_stats, metadata, checksum))
+            });
+
+            self.inflight_abort_handles.push(task.abort_handle());
+            self.inflight.push_back(task);
+
+            match self.max_inflight_chunks {
+                // No pipelining configured: await this chunk fully before returning, exactly
+                // as before this option existed.
+                None => self.await_next_inflight().await?,
+                Some(max) => {
+                    // Keep at most `max` finalize+upload tasks outstanding; block on the
+                    // oldest one until the queue drops back under the limit before letting
+                    // the caller start encoding the next chunk.
+                    if self.inflight.len() > max {
+                        self.await_next_inflight().await?;
+                    }
+                }
+            }
+        }
 
-            // Offload CPU-intensive parquet finalization to blocking thread pool
-            let (buffer, om_stats, metadata) =
-                tokio::task::spawn_blocking(move || writer.finalize())
-                    .await
-                    .map_err(|e| Error::BlockingOperationError(e.to_string()))??;
+        Ok(())
+    }
 
-            self.write_target
-                .as_ref()
-                .write_to_path(&path, buffer)
-                .await?;
+    /// Finalizes the whole write as a single file via [`parallel_row_groups::write_parallel`]
+    /// (see [`Self::with_parallel_row_groups`]), in place of [`Self::finalize_chunk`]'s
+    /// per-chunk path.
+    ///
+    /// Row-group encoding for the output bytes runs across `n` blocking-pool workers, but
+    /// [`ChunkMetadata`] and [`types::OntologyModelStats`] still need the real per-batch stats an
+    /// ordinary [`ChunkWriter`] computes, and this writer has no way to merge those across
+    /// workers without knowing their exact (elided) field layout. So this also runs one ordinary,
+    /// single-threaded `ChunkWriter` pass over every batch — concurrently with the parallel
+    /// encoding, via `tokio::join!`, so it doesn't add to the wall-clock cost — purely to obtain
+    /// correct stats and metadata; that pass's own encoded bytes are discarded in favor of the
+    /// parallel-stitched ones. This means the stats pass, not the row-group encoding, is the
+    /// bottleneck this option doesn't help with; it's still a win whenever encoding (not stats
+    /// collection) dominates the write.
+    async fn finalize_parallel<A>(&mut self) -> Result<(), Error>
+    where
+        A: traits::AsyncWriteToPath + Send + Sync + 'static,
+        W: AsRef<A> + Clone + Send + 'static,
+    {
+        let batches = std::mem::take(&mut self.parallel_batches);
+        let Some(first_batch) = batches.first() else {
+            return Ok(());
+        };
+        let schema = first_batch.schema();
+
+        let num_workers = self
+            .parallel_row_groups
+            .expect("finalize_parallel is only called when parallel_row_groups is set");
+        let properties = self
+            .format
+            .as_parquet()
+            .ok_or_else(|| Error::UnsupportedParallelRowGroupsFormat(self.format.to_string()))?
+            .writer_properties();
+
+        let path = (self.on_file_format)(&self.path, &self.format, self.chunk_serialized_number);
+        let chunk_id = self.chunk_serialized_number as i64;
+        self.chunk_serialized_number += 1;
+
+        let stats_batches = batches.clone();
+        let stats_format = self.format.clone();
+        let stats_task = async move {
+            tokio::task::spawn_blocking(move || {
+                let mut writer = ChunkWriter::try_new(stats_batches[0].schema(), stats_format)?;
+                for batch in &stats_batches {
+                    writer.write(batch)?;
+                }
+                writer.finalize()
+            })
+            .await
+            .map_err(|e| Error::BlockingOperationError(e.to_string()))?
+        };
 
-            trace!(
-                "chunked writer callback: {}",
-                self.on_chunk_created_clbk.is_some()
-            );
+        let row_groups_task = parallel_row_groups::write_parallel(schema, properties, batches, num_workers);
+
+        let (stats_result, buffer) = tokio::join!(stats_task, row_groups_task);
+        let (_discarded_buffer, om_stats, metadata) = stats_result?;
+        let buffer = buffer?;
+
+        let encryption_key = self.encryption_key.clone();
+        let (buffer, checksum, merkle_root) = tokio::task::spawn_blocking(move || {
+            let buffer = match &encryption_key {
+                Some(dek) => crypto::encrypt_chunk(dek, chunk_id, &buffer)?,
+                None => buffer,
+            };
+            let checksum = checksum::crc32c(&buffer);
+            let merkle_root = merkle::merkle_root(&buffer);
+            Ok::<_, Error>((buffer, checksum, merkle_root))
+        })
+        .await
+        .map_err(|e| Error::BlockingOperationError(e.to_string()))??;
 
-            return self
-                .on_chunk_created_clbk
-                .as_ref()
-                .map(async move |clbk| {
-                    debug!("calling chunk serialization callback");
-                    return clbk(path, om_stats, metadata).await;
-                })
-                .unwrap()
-                .await
-                .map_err(|e| Error::ChunkCreationCallbackError(e.to_string()));
+        // `size_bytes` from the stats pass describes its own (discarded) single-threaded
+        // encoding, which has different page/footer overhead than the stitched, multi-row-group
+        // file actually written below — so it's overridden here too, same as `merkle_root`.
+        let metadata = ChunkMetadata {
+            merkle_root,
+            size_bytes: buffer.len() as _,
+            ..metadata
+        };
+
+        self.write_target.as_ref().write_to_path(&path, buffer).await?;
+        self.written_chunk_paths.push(path.clone());
+
+        let clbk = self
+            .on_chunk_created_clbk
+            .as_ref()
+            .expect("on_chunk_created callback must be set before writing");
+
+        clbk(path, om_stats, metadata, checksum)
+            .await
+            .map_err(|e| Error::ChunkCreationCallbackError(e.to_string()))
+    }
+
+    /// Converts this writer into a pull-based [`Stream`] of finalized chunks, pulling
+    /// `RecordBatch`es from `batches` until it's exhausted instead of being driven by external
+    /// [`Self::write`] calls. Each item is a chunk's own finalized (and, if configured,
+    /// encrypted) bytes plus its stats and metadata, for a caller that wants to compose its own
+    /// upload, fan-out, or tee logic downstream instead of going through `write_target` and
+    /// `on_chunk_created`. The trailing, possibly under-threshold chunk is flushed once
+    /// `batches` ends, same as a terminal [`Self::finalize`] would, but chunks are yielded
+    /// incrementally as each boundary is hit rather than only at the very end.
+    ///
+    /// `write_target`, `on_chunk_created`, `max_inflight_chunks`/pipelining, and `abort_policy`
+    /// are all irrelevant to this mode (there's no upload to pipeline or abort) and are ignored.
+    /// `with_slice_oversized_batches` is not supported here; an oversized batch is still emitted
+    /// as one oversized chunk regardless of that setting.
+    pub fn into_stream<S>(
+        self,
+        batches: S,
+    ) -> impl Stream<Item = Result<(PathBuf, bytes::Bytes, types::OntologyModelStats, ChunkMetadata), Error>>
+    where
+        S: Stream<Item = RecordBatch> + Send + 'static,
+    {
+        stream::unfold(
+            (Some(self), Box::pin(batches)),
+            |(writer, mut batches)| async move {
+                let mut writer = writer?;
+                loop {
+                    match batches.next().await {
+                        Some(batch) => match writer.write_one_for_stream(&batch).await {
+                            Ok(Some(chunk)) => return Some((Ok(chunk), (Some(writer), batches))),
+                            Ok(None) => continue,
+                            Err(e) => return Some((Err(e), (None, batches))),
+                        },
+                        None => {
+                            return match writer.finalize_for_stream().await {
+                                Ok(Some(chunk)) => Some((Ok(chunk), (None, batches))),
+                                Ok(None) => None,
+                                Err(e) => Some((Err(e), (None, batches))),
+                            };
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Like [`Self::write_one`], but for [`Self::into_stream`]: encodes `batch` into the
+    /// in-progress chunk and, if a boundary is hit, finalizes it and returns its data directly
+    /// instead of uploading it to a write target.
+    async fn write_one_for_stream(
+        &mut self,
+        batch: &RecordBatch,
+    ) -> Result<Option<(PathBuf, bytes::Bytes, types::OntologyModelStats, ChunkMetadata)>, Error> {
+        let mut writer = match self.writer.take() {
+            Some(w) => w,
+            None => ChunkWriter::try_new(batch.schema(), self.format.clone())?,
+        };
+
+        let batch_owned = batch.clone();
+        writer = tokio::task::spawn_blocking(move || {
+            writer.write(&batch_owned)?;
+            Ok::<_, Error>(writer)
+        })
+        .await
+        .map_err(|e| Error::BlockingOperationError(e.to_string()))??;
+
+        let current_size = writer.memory_size();
+        let should_finalize = self.should_finalize_chunk(current_size, batch);
+        self.writer = Some(writer);
+
+        if should_finalize {
+            self.finalize_for_stream().await
+        } else {
+            Ok(None)
         }
+    }
 
-        Ok(())
+    /// Like [`Self::finalize_chunk`], but for [`Self::into_stream`]: finalizes the in-progress
+    /// chunk (if any) and returns its path, bytes, and metadata directly instead of uploading
+    /// them to a write target and invoking `on_chunk_created`.
+    async fn finalize_for_stream(
+        &mut self,
+    ) -> Result<Option<(PathBuf, bytes::Bytes, types::OntologyModelStats, ChunkMetadata)>, Error> {
+        let Some(writer) = self.writer.take() else {
+            return Ok(None);
+        };
+
+        let path = (self.on_file_format)(&self.path, &writer.format, self.chunk_serialized_number);
+        let chunk_id = self.chunk_serialized_number as i64;
+        self.chunk_serialized_number += 1;
+        self.rolling_hash = None;
+
+        let encryption_key = self.encryption_key.clone();
+
+        let (buffer, om_stats, metadata) =
+            finalize_writer_bytes(writer, chunk_id, encryption_key).await?;
+
+        Ok(Some((path, bytes::Bytes::from(buffer), om_stats, metadata)))
     }
 }
 
@@ -251,24 +973,31 @@ mod tests {
     use super::*;
     use arrow::array::{ArrayRef, BinaryArray, Int64Array};
     use arrow::datatypes::{Field, Schema};
+    use futures::TryStreamExt;
     use std::sync::Arc;
     use std::sync::atomic::{AtomicUsize, Ordering};
 
-    /// Mock store that counts write operations
+    /// Mock store that counts write and delete operations
     struct MockStore {
         write_count: Arc<AtomicUsize>,
+        deleted_paths: Arc<std::sync::Mutex<Vec<PathBuf>>>,
     }
 
     impl MockStore {
         fn new() -> Self {
             Self {
                 write_count: Arc::new(AtomicUsize::new(0)),
+                deleted_paths: Arc::new(std::sync::Mutex::new(Vec::new())),
             }
         }
 
         fn get_write_count(&self) -> usize {
             self.write_count.load(Ordering::SeqCst)
         }
+
+        fn get_deleted_paths(&self) -> Vec<PathBuf> {
+            self.deleted_paths.lock().unwrap().clone()
+        }
     }
 
     impl traits::AsyncWriteToPath for MockStore {
@@ -282,6 +1011,19 @@ mod tests {
         }
     }
 
+    impl traits::AsyncDeletePath for MockStore {
+        fn delete_path(
+            &self,
+            path: impl AsRef<std::path::Path>,
+        ) -> impl Future<Output = std::io::Result<()>> {
+            self.deleted_paths
+                .lock()
+                .unwrap()
+                .push(path.as_ref().to_path_buf());
+            async { Ok(()) }
+        }
+    }
+
     /// Create a test batch with binary data to inflate the size
     fn create_test_batch_with_size(rows: usize, blob_size: usize) -> RecordBatch {
         use rand::Rng;
@@ -319,7 +1061,7 @@ mod tests {
         )
         .with_max_chunk_size(Some(1024)); // 1 KiB threshold
 
-        writer.on_chunk_created(|_, _, _| async { Ok(()) });
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
 
         // Write multiple batches with enough data to exceed threshold multiple times
         // Each batch ~500 bytes of binary data + overhead
@@ -346,12 +1088,12 @@ mod tests {
 
         // Use a large max_chunk_size that won't be exceeded
         let mut writer =
-            ChunkedWriter::new(&store, "test/path", Format::Default, |path, _, idx| {
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
                 path.join(format!("chunk_{}.parquet", idx))
             })
             .with_max_chunk_size(Some(100 * 1024 * 1024)); // 100 MiB threshold
 
-        writer.on_chunk_created(|_, _, _| async { Ok(()) });
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
 
         // Write small batches
         for _ in 0..5 {
@@ -375,12 +1117,12 @@ mod tests {
 
         // No max_chunk_size (unlimited)
         let mut writer =
-            ChunkedWriter::new(&store, "test/path", Format::Default, |path, _, idx| {
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
                 path.join(format!("chunk_{}.parquet", idx))
             })
             .with_max_chunk_size(None); // Unlimited
 
-        writer.on_chunk_created(|_, _, _| async { Ok(()) });
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
 
         // Write many batches
         for _ in 0..20 {
@@ -406,12 +1148,12 @@ mod tests {
         let max_chunk_size = 5 * 1024 * 1024;
 
         let mut writer =
-            ChunkedWriter::new(&store, "test/path", Format::Default, |path, _, idx| {
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
                 path.join(format!("chunk_{}.parquet", idx))
             })
             .with_max_chunk_size(Some(max_chunk_size));
 
-        writer.on_chunk_created(|_, _, _| async { Ok(()) });
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
 
         // Write 10 batches of ~1 MiB each (10 rows * 100KB random blob)
         // Random data doesn't compress, so total ~10 MiB
@@ -453,12 +1195,12 @@ mod tests {
         let max_chunk_size = 512 * 1024;
 
         let mut writer =
-            ChunkedWriter::new(&store, "test/path", Format::Default, |path, _, idx| {
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
                 path.join(format!("chunk_{}.parquet", idx))
             })
             .with_max_chunk_size(Some(max_chunk_size));
 
-        writer.on_chunk_created(|_, _, _| async { Ok(()) });
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
 
         // Write a single batch that exceeds the threshold (~1 MiB)
         // This tests that we handle the case where a single batch > max_chunk_size
@@ -474,4 +1216,432 @@ mod tests {
             "Single batch exceeding threshold should still create 1 chunk"
         );
     }
+
+    #[tokio::test]
+    async fn with_slice_oversized_batches_splits_a_single_large_batch() {
+        let store = Arc::new(MockStore::new());
+
+        // Same setup as `test_single_large_batch_exceeds_threshold`, but with slicing on.
+        let max_chunk_size = 512 * 1024;
+
+        let mut writer =
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+                path.join(format!("chunk_{}.parquet", idx))
+            })
+            .with_max_chunk_size(Some(max_chunk_size))
+            .with_slice_oversized_batches(true);
+
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
+
+        let batch = create_test_batch_with_size(10, 100 * 1024); // ~1 MiB
+        writer.write(&batch).await.expect("Write failed");
+
+        writer.finalize().await.expect("Finalize failed");
+
+        assert!(
+            store.get_write_count() > 1,
+            "Slicing should have split the oversized batch into multiple chunks"
+        );
+    }
+
+    #[tokio::test]
+    async fn with_slice_oversized_batches_falls_back_for_a_single_unsplittable_row() {
+        let store = Arc::new(MockStore::new());
+
+        // A single row already exceeds the threshold on its own, so there's nothing to slice.
+        let max_chunk_size = 512 * 1024;
+
+        let mut writer =
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+                path.join(format!("chunk_{}.parquet", idx))
+            })
+            .with_max_chunk_size(Some(max_chunk_size))
+            .with_slice_oversized_batches(true);
+
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
+
+        let batch = create_test_batch_with_size(1, 1024 * 1024); // single ~1 MiB row
+        writer.write(&batch).await.expect("Write failed");
+
+        writer.finalize().await.expect("Finalize failed");
+
+        assert_eq!(
+            store.get_write_count(),
+            1,
+            "A single oversized row can't be sliced smaller, so it's still written alone"
+        );
+    }
+
+    #[test]
+    fn parse_buffer_size_accepts_decimal_units() {
+        assert_eq!(parse_buffer_size("64MB").unwrap(), 64_000_000);
+        assert_eq!(parse_buffer_size("512KB").unwrap(), 512_000);
+        assert_eq!(parse_buffer_size("1GB").unwrap(), 1_000_000_000);
+        assert_eq!(parse_buffer_size("1024").unwrap(), 1024);
+        assert_eq!(parse_buffer_size("64mb").unwrap(), 64_000_000);
+    }
+
+    #[test]
+    fn parse_buffer_size_rejects_unknown_unit() {
+        assert!(parse_buffer_size("64TB").is_err());
+        assert!(parse_buffer_size("not-a-size").is_err());
+    }
+
+    #[tokio::test]
+    async fn with_max_buffer_size_readable_applies_parsed_threshold() {
+        let store = Arc::new(MockStore::new());
+
+        let mut writer = ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        })
+        .with_max_buffer_size_readable(Some("1KB"))
+        .expect("valid buffer size");
+
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("Write failed");
+        }
+        writer.finalize().await.expect("Finalize failed");
+
+        assert!(
+            store.get_write_count() > 1,
+            "Expected multiple chunks from the parsed buffer size threshold"
+        );
+    }
+
+    #[tokio::test]
+    async fn with_max_inflight_chunks_preserves_chunk_index_order() {
+        let store = Arc::new(MockStore::new());
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut writer = ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        })
+        .with_max_chunk_size(Some(1024))
+        .with_max_inflight_chunks(Some(4));
+
+        let seen_clbk = seen.clone();
+        writer.on_chunk_created(move |path, _, _, _| {
+            let seen_clbk = seen_clbk.clone();
+            async move {
+                seen_clbk.lock().unwrap().push(path);
+                Ok(())
+            }
+        });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("Write failed");
+        }
+        writer.finalize().await.expect("Finalize failed");
+
+        let paths = seen.lock().unwrap().clone();
+        let mut sorted = paths.clone();
+        sorted.sort();
+
+        assert_eq!(
+            paths, sorted,
+            "on_chunk_created must fire in chunk-creation order even with pipelining enabled"
+        );
+    }
+
+    #[tokio::test]
+    async fn on_chunk_created_receives_a_nonzero_merkle_root() {
+        let store = Arc::new(MockStore::new());
+        let roots = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut writer = ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        });
+
+        let roots_clbk = roots.clone();
+        writer.on_chunk_created(move |_, _, chunk_metadata, _| {
+            let roots_clbk = roots_clbk.clone();
+            async move {
+                roots_clbk.lock().unwrap().push(chunk_metadata.merkle_root);
+                Ok(())
+            }
+        });
+
+        let batch = create_test_batch_with_size(5, 1024);
+        writer.write(&batch).await.expect("Write failed");
+        writer.finalize().await.expect("Finalize failed");
+
+        let roots = roots.lock().unwrap().clone();
+        assert_eq!(roots.len(), 1);
+        assert_ne!(roots[0], [0u8; 32], "a non-empty chunk must not hash to the all-zero root");
+    }
+
+    #[tokio::test]
+    async fn with_parallel_row_groups_produces_a_single_chunk_spanning_every_batch() {
+        let store = Arc::new(MockStore::new());
+        let created = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut writer = ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        })
+        .with_parallel_row_groups(4);
+
+        let created_clbk = created.clone();
+        writer.on_chunk_created(move |path, _, metadata, _| {
+            let created_clbk = created_clbk.clone();
+            async move {
+                created_clbk.lock().unwrap().push((path, metadata));
+                Ok(())
+            }
+        });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("write failed");
+        }
+        let summary = writer.finalize().await.expect("finalize failed");
+
+        assert_eq!(summary.number_of_chunks_created, 1);
+        let created = created.lock().unwrap().clone();
+        assert_eq!(created.len(), 1, "every batch should stitch into a single chunk");
+        assert_eq!(store.get_write_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn with_parallel_row_groups_is_ignored_when_max_chunk_size_is_set() {
+        let store = Arc::new(MockStore::new());
+
+        let mut writer = ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        })
+        .with_max_chunk_size(Some(1024))
+        .with_parallel_row_groups(4);
+
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("write failed");
+        }
+        let summary = writer.finalize().await.expect("finalize failed");
+
+        assert!(
+            summary.number_of_chunks_created > 1,
+            "max_chunk_size should still take precedence, splitting into several chunks"
+        );
+    }
+
+    #[tokio::test]
+    async fn abort_purges_chunks_already_written_by_default() {
+        let store = Arc::new(MockStore::new());
+
+        let mut writer =
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+                path.join(format!("chunk_{}.parquet", idx))
+            })
+            .with_max_chunk_size(Some(1024));
+
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("Write failed");
+        }
+
+        let written = store.get_write_count();
+        assert!(written > 1, "expected multiple chunks written before abort");
+
+        writer.abort().await.expect("Abort failed");
+
+        assert_eq!(
+            store.get_deleted_paths().len(),
+            written,
+            "abort should purge every chunk written so far under the default Purge policy"
+        );
+    }
+
+    #[tokio::test]
+    async fn abort_with_keep_policy_leaves_written_chunks_in_place() {
+        let store = Arc::new(MockStore::new());
+
+        let mut writer =
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+                path.join(format!("chunk_{}.parquet", idx))
+            })
+            .with_max_chunk_size(Some(1024))
+            .with_abort_policy(AbortPolicy::Keep);
+
+        writer.on_chunk_created(|_, _, _, _| async { Ok(()) });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("Write failed");
+        }
+
+        writer.abort().await.expect("Abort failed");
+
+        assert!(
+            store.get_deleted_paths().is_empty(),
+            "AbortPolicy::Keep should leave already-written chunks untouched"
+        );
+    }
+
+    #[tokio::test]
+    async fn finalize_failure_rolls_back_written_chunks() {
+        let store = Arc::new(MockStore::new());
+        let fail_callbacks = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let mut writer =
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+                path.join(format!("chunk_{}.parquet", idx))
+            })
+            .with_max_chunk_size(Some(1024));
+
+        // Chunks finalized mid-`write` (via auto-split) succeed normally; only the trailing
+        // chunk finalized by the explicit `finalize` call below fails, so the test exercises
+        // rollback of a mix of already-acknowledged chunks plus the one whose callback failed.
+        let should_fail = fail_callbacks.clone();
+        writer.on_chunk_created(move |_, _, _, _| {
+            let should_fail = should_fail.clone();
+            async move {
+                if should_fail.load(std::sync::atomic::Ordering::SeqCst) {
+                    return Err(Box::<dyn std::error::Error>::from("simulated callback failure"));
+                }
+                Ok(())
+            }
+        });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("Write failed");
+        }
+
+        fail_callbacks.store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let written = store.get_write_count();
+        assert!(written > 1, "expected multiple chunks written before finalize");
+
+        let err = writer.finalize().await;
+        assert!(err.is_err(), "finalize should surface the callback error");
+
+        // `finalize` uploads the trailing chunk before its callback fails, so that chunk is
+        // purged too.
+        assert_eq!(
+            store.get_deleted_paths().len(),
+            store.get_write_count(),
+            "a failed finalize should purge every chunk already written, same as abort"
+        );
+    }
+
+    #[tokio::test]
+    async fn finalize_failure_with_pipelining_still_purges_every_inflight_chunk() {
+        let store = Arc::new(MockStore::new());
+        let fail_callbacks = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let mut writer =
+            ChunkedWriter::new(store.clone(), "test/path", Format::Default, |path, _, idx| {
+                path.join(format!("chunk_{}.parquet", idx))
+            })
+            .with_max_chunk_size(Some(1024))
+            .with_max_inflight_chunks(Some(2));
+
+        // With pipelining enabled, `finalize_chunk` itself awaits an older inflight task
+        // (rather than the most-recently-pushed one) whenever more than `max` are
+        // outstanding, so the failure here is detected inside `finalize_chunk`, before the
+        // remaining-inflight drain loop in `finalize` ever runs.
+        let should_fail = fail_callbacks.clone();
+        writer.on_chunk_created(move |_, _, _, _| {
+            let should_fail = should_fail.clone();
+            async move {
+                if should_fail.load(std::sync::atomic::Ordering::SeqCst) {
+                    return Err(Box::<dyn std::error::Error>::from("simulated callback failure"));
+                }
+                Ok(())
+            }
+        });
+
+        for _ in 0..10 {
+            let batch = create_test_batch_with_size(5, 100);
+            writer.write(&batch).await.expect("Write failed");
+        }
+
+        fail_callbacks.store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let written = store.get_write_count();
+        assert!(written > 2, "expected several chunks written before finalize");
+
+        let err = writer.finalize().await;
+        assert!(err.is_err(), "finalize should surface the callback error");
+
+        assert_eq!(
+            store.get_deleted_paths().len(),
+            store.get_write_count(),
+            "rollback must purge every uploaded chunk, including ones still queued when \
+             finalize_chunk's own wait-for-an-older-task failed"
+        );
+    }
+
+    #[tokio::test]
+    async fn into_stream_yields_one_chunk_per_boundary_plus_a_trailing_chunk() {
+        let store = Arc::new(MockStore::new());
+
+        let writer = ChunkedWriter::new(store, "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        })
+        .with_max_chunk_size(Some(1024));
+
+        let batches = futures::stream::iter((0..10).map(|_| create_test_batch_with_size(5, 100)));
+        let chunks: Vec<_> = writer
+            .into_stream(batches)
+            .try_collect()
+            .await
+            .expect("into_stream should not error");
+
+        assert!(
+            chunks.len() > 1,
+            "expected the small max_chunk_size to split input across multiple streamed chunks"
+        );
+        for (_, bytes, _, _) in &chunks {
+            assert!(!bytes.is_empty(), "a streamed chunk's bytes must not be empty");
+        }
+    }
+
+    #[tokio::test]
+    async fn into_stream_emits_a_trailing_chunk_below_threshold() {
+        let store = Arc::new(MockStore::new());
+
+        let writer = ChunkedWriter::new(store, "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        })
+        .with_max_chunk_size(Some(100 * 1024 * 1024)); // won't be hit by this tiny input
+
+        let batches = futures::stream::iter(vec![create_test_batch_with_size(3, 10)]);
+        let chunks: Vec<_> = writer
+            .into_stream(batches)
+            .try_collect()
+            .await
+            .expect("into_stream should not error");
+
+        assert_eq!(
+            chunks.len(),
+            1,
+            "a single small batch under threshold should still be flushed once the input ends"
+        );
+    }
+
+    #[tokio::test]
+    async fn into_stream_with_no_batches_yields_nothing() {
+        let store = Arc::new(MockStore::new());
+
+        let writer = ChunkedWriter::new(store, "test/path", Format::Default, |path, _, idx| {
+            path.join(format!("chunk_{}.parquet", idx))
+        });
+
+        let batches = futures::stream::iter(std::iter::empty::<RecordBatch>());
+        let chunks: Vec<_> = writer
+            .into_stream(batches)
+            .try_collect()
+            .await
+            .expect("into_stream should not error");
+
+        assert!(chunks.is_empty(), "no batches written means no chunk to flush");
+    }
 }
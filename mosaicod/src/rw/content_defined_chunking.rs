@@ -0,0 +1,152 @@
+//! Content-defined chunk boundaries for [`super::chunked_writer::ChunkedWriter`].
+//!
+//! [`ChunkingStrategy::Fixed`] (the default) cuts a chunk purely once it reaches
+//! `with_max_chunk_size`'s threshold, so re-ingesting a topic with even a small edit shifts
+//! every downstream chunk's boundary and defeats object-store deduplication across versions.
+//! [`ChunkingStrategy::ContentDefined`] instead rolls a [`RollingHash`] (Gear hashing) over the
+//! bytes written to the current chunk and declares a boundary once the hash satisfies
+//! [`RollingHash::is_boundary`] — a condition derived purely from the recently-seen bytes, so
+//! identical byte runs land on the same boundary regardless of what precedes or follows them
+//! elsewhere in the stream. `min`/`max` bound the result: boundary checks are skipped below
+//! `min`, and a cut is forced at `max` regardless of the hash.
+
+/// Gear hashing's per-byte table: 256 arbitrary-but-fixed 64-bit values. Generated at compile
+/// time from a fixed seed with [`splitmix64`] rather than pulled in from `rand`, since the table
+/// just needs to look unstructured to byte values, not be unpredictable to an adversary.
+const GEAR: [u64; 256] = generate_gear_table();
+
+const fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+const fn generate_gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut seed = 0x2545_F491_4F6C_DD1D;
+    let mut i = 0;
+
+    while i < 256 {
+        seed = splitmix64(seed);
+        table[i] = seed;
+        i += 1;
+    }
+
+    table
+}
+
+/// A Gear-hash rolling hash over the bytes written to the current chunk.
+///
+/// Unlike a checksum, this isn't reset per-write: every byte rolled in shifts out the influence
+/// of bytes more than 64 positions back (the hash is only 64 bits wide), giving it a sliding
+/// window over roughly the last 64 bytes without needing to buffer them.
+pub struct RollingHash {
+    /// Selects how many low bits of `hash` must be zero to mark a boundary. Derived from the
+    /// target average chunk size so that, for uniformly random input, a boundary occurs on
+    /// average once every `2^popcount(mask)` bytes.
+    mask: u64,
+    hash: u64,
+}
+
+impl RollingHash {
+    /// Builds a rolling hash targeting an average chunk size of roughly `avg_size` bytes.
+    pub fn new(avg_size: usize) -> Self {
+        let bits = avg_size.max(2).next_power_of_two().trailing_zeros();
+        let mask = (1u64 << bits) - 1;
+
+        Self { mask, hash: 0 }
+    }
+
+    /// Rolls `byte` into the hash.
+    pub fn roll(&mut self, byte: u8) {
+        self.hash = (self.hash << 1).wrapping_add(GEAR[byte as usize]);
+    }
+
+    /// Returns whether the hash's current value marks a chunk boundary.
+    pub fn is_boundary(&self) -> bool {
+        self.hash & self.mask == 0
+    }
+}
+
+/// How [`super::chunked_writer::ChunkedWriter`] decides where to cut a chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkingStrategy {
+    /// Cut purely on accumulated size, via `ChunkedWriter::with_max_chunk_size`.
+    Fixed,
+    /// Cut on content-defined boundaries (see the module docs), bounded by `min`/`max` sizes.
+    ContentDefined {
+        /// Target average chunk size in bytes.
+        avg: usize,
+        /// Minimum chunk size; boundary checks are skipped until the current chunk has
+        /// accumulated at least this many bytes.
+        min: usize,
+        /// Maximum chunk size; a boundary is forced once the current chunk reaches this many
+        /// bytes, regardless of the rolling hash.
+        max: usize,
+    },
+}
+
+impl Default for ChunkingStrategy {
+    fn default() -> Self {
+        Self::Fixed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_byte_runs_produce_identical_boundary_verdicts() {
+        let mut a = RollingHash::new(64);
+        let mut b = RollingHash::new(64);
+        let prefix = b"the quick brown fox jumps over the lazy dog, repeated to build up state";
+
+        for &byte in prefix {
+            a.roll(byte);
+            b.roll(byte);
+        }
+
+        assert_eq!(a.is_boundary(), b.is_boundary());
+    }
+
+    #[test]
+    fn larger_average_produces_a_wider_mask() {
+        let small = RollingHash::new(64);
+        let large = RollingHash::new(4096);
+
+        assert!(large.mask > small.mask);
+    }
+
+    #[test]
+    fn chunking_strategy_defaults_to_fixed() {
+        assert_eq!(ChunkingStrategy::default(), ChunkingStrategy::Fixed);
+    }
+
+    #[test]
+    fn boundary_rate_over_pseudorandom_bytes_is_roughly_one_in_avg_size() {
+        let avg = 256usize;
+        let mut hash = RollingHash::new(avg);
+        let mut boundaries = 0usize;
+
+        // A tiny xorshift PRNG so this test doesn't need to pull in `rand` for input data.
+        let mut x = 0x1234_5678_9abc_def0u64;
+        let total = 200_000;
+
+        for _ in 0..total {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+
+            hash.roll((x & 0xff) as u8);
+            if hash.is_boundary() {
+                boundaries += 1;
+            }
+        }
+
+        let expected = total / avg;
+        let ratio = boundaries as f64 / expected as f64;
+        assert!((0.5..1.5).contains(&ratio), "boundaries={boundaries} expected~={expected}");
+    }
+}
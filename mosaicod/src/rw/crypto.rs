@@ -0,0 +1,147 @@
+//! Transparent AEAD encryption of chunk data files at rest, layered on top of the finalized
+//! Parquet bytes a [`ChunkWriter`](super::chunk_writer::ChunkWriter) produces and before
+//! [`ChunkedWriter`](super::chunked_writer::ChunkedWriter) hands them to its write target.
+//!
+//! Stats used for query pruning (see `repo::FacadeChunk::push_ontology_model_stats`) are
+//! always computed from the plaintext batches before [`encrypt_chunk`] runs, so enabling
+//! encryption never affects row-group pruning.
+//!
+//! The key-encryption key (KEK) wrapping a topic's [`DataEncryptionKey`] is sourced from the
+//! `encryption_master_key` configurable (see [`MasterKey::from_configurable`]) rather than
+//! threaded in as a trait object, so any deployment with the configurable set gets working
+//! envelope encryption without supplying its own [`KeyEncryptionKey`] implementation.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use rand::Rng;
+
+use crate::types::crypto::{CryptoError, DataEncryptionKey, KeyEncryptionKey, WrappedKey};
+
+use super::Error;
+
+/// Encrypts `plaintext` (a finalized chunk data file) under `dek`, using a nonce derived from
+/// `chunk_id` so the same DEK is safe to reuse across every chunk in a topic. The nonce isn't
+/// secret; it's implicitly recoverable from the chunk id already recorded for this file, so it
+/// isn't prefixed to the returned ciphertext.
+pub fn encrypt_chunk(dek: &DataEncryptionKey, chunk_id: i64, plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(dek.as_bytes()));
+    let nonce = Nonce::from(dek.nonce_for_chunk(chunk_id));
+
+    cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|e| CryptoError::EncryptFailed(e.to_string()).into())
+}
+
+/// Reverses [`encrypt_chunk`], returning the plaintext chunk data file.
+pub fn decrypt_chunk(dek: &DataEncryptionKey, chunk_id: i64, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(dek.as_bytes()));
+    let nonce = Nonce::from(dek.nonce_for_chunk(chunk_id));
+
+    cipher
+        .decrypt(&nonce, ciphertext)
+        .map_err(|e| CryptoError::DecryptFailed(e.to_string()).into())
+}
+
+/// A [`KeyEncryptionKey`] backed by a single AES-256 key read from the `encryption_master_key`
+/// configurable. Unlike [`DataEncryptionKey`]'s per-chunk nonces, each [`MasterKey::wrap`] call
+/// draws a fresh random nonce (this key wraps at most a handful of DEKs per topic, so nonce
+/// reuse isn't a practical concern) and prefixes it to the returned [`WrappedKey`].
+pub struct MasterKey([u8; 32]);
+
+impl MasterKey {
+    /// Parses the `encryption_master_key` configurable: a 64-character hex string encoding a
+    /// raw 32-byte AES-256 key.
+    pub fn from_configurable(hex: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidMasterKey(hex.to_owned());
+
+        if hex.len() != 64 {
+            return Err(invalid());
+        }
+
+        let mut bytes = [0u8; 32];
+        for (i, chunk) in bytes.iter_mut().zip(0..32) {
+            let byte = u8::from_str_radix(&hex[chunk * 2..chunk * 2 + 2], 16).map_err(|_| invalid())?;
+            *i = byte;
+        }
+
+        Ok(Self(bytes))
+    }
+}
+
+impl KeyEncryptionKey for MasterKey {
+    fn wrap(&self, dek: &DataEncryptionKey) -> Result<WrappedKey, CryptoError> {
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.0));
+        let nonce_bytes = rand::rng().random::<[u8; 12]>();
+        let nonce = Nonce::from(nonce_bytes);
+
+        let mut wrapped = nonce_bytes.to_vec();
+        wrapped.extend(
+            cipher
+                .encrypt(&nonce, dek.as_bytes().as_slice())
+                .map_err(|e| CryptoError::WrapFailed(e.to_string()))?,
+        );
+
+        Ok(WrappedKey(wrapped))
+    }
+
+    fn unwrap(&self, wrapped: &WrappedKey) -> Result<DataEncryptionKey, CryptoError> {
+        if wrapped.0.len() < 12 {
+            return Err(CryptoError::UnwrapFailed("wrapped key shorter than nonce".to_owned()));
+        }
+
+        let (nonce_bytes, ciphertext) = wrapped.0.split_at(12);
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.0));
+        let nonce = Nonce::from_slice(nonce_bytes);
+
+        let plaintext = cipher
+            .decrypt(nonce, ciphertext)
+            .map_err(|e| CryptoError::UnwrapFailed(e.to_string()))?;
+
+        let bytes: [u8; 32] = plaintext
+            .try_into()
+            .map_err(|_| CryptoError::UnwrapFailed("unwrapped key isn't 32 bytes".to_owned()))?;
+
+        Ok(DataEncryptionKey::from_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decrypt_chunk_reverses_encrypt_chunk() {
+        let dek = DataEncryptionKey::generate();
+        let plaintext = b"parquet bytes go here";
+
+        let ciphertext = encrypt_chunk(&dek, 7, plaintext).unwrap();
+        assert_ne!(ciphertext, plaintext);
+
+        let roundtripped = decrypt_chunk(&dek, 7, &ciphertext).unwrap();
+        assert_eq!(roundtripped, plaintext);
+    }
+
+    #[test]
+    fn decrypt_chunk_fails_with_wrong_chunk_id() {
+        let dek = DataEncryptionKey::generate();
+        let ciphertext = encrypt_chunk(&dek, 7, b"data").unwrap();
+
+        assert!(decrypt_chunk(&dek, 8, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn master_key_wrap_unwrap_roundtrips() {
+        let master = MasterKey::from_configurable(&"ab".repeat(32)).unwrap();
+        let dek = DataEncryptionKey::generate();
+
+        let wrapped = master.wrap(&dek).unwrap();
+        let unwrapped = master.unwrap(&wrapped).unwrap();
+
+        assert_eq!(dek.as_bytes(), unwrapped.as_bytes());
+    }
+
+    #[test]
+    fn master_key_from_configurable_rejects_wrong_length() {
+        assert!(MasterKey::from_configurable("abcd").is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! Footer-only Parquet metadata reads.
+//!
+//! A chunk's Arrow schema (and its row-group statistics) live entirely in the trailing
+//! Parquet footer: the file ends with the 4-byte magic `PAR1`, preceded by a 4-byte
+//! little-endian `FileMetaData` length, preceded by the Thrift-encoded `FileMetaData` itself.
+//! [`read_footer`] issues a ranged `store` read over just that tail instead of fetching the
+//! whole chunk, re-fetching a wider window only if the footer didn't fit in the first read.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use parquet::arrow::parquet_to_arrow_schema;
+use parquet::file::footer;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::statistics::Statistics;
+
+use crate::{params, rw::Error, store, types::TimestampRange};
+
+/// Parquet's fixed footer trailer: the 4-byte little-endian `FileMetaData` length, followed
+/// by the 4-byte `PAR1` magic.
+const FOOTER_TRAILER_SIZE: u64 = 8;
+
+/// Magic bytes every valid Parquet file ends with.
+const PARQUET_MAGIC: &[u8; 4] = b"PAR1";
+
+/// How much of the file's tail to fetch on the first read. Comfortably covers the footer of
+/// most chunks (a handful of columns and row groups) in a single round trip; wider footers
+/// fall back to a second, exactly-sized read instead of growing this constant for everyone.
+const INITIAL_FOOTER_FETCH_SIZE: u64 = 64 * 1024;
+
+/// Validates `tail`'s trailing [`FOOTER_TRAILER_SIZE`] bytes against the Parquet spec and
+/// returns the `FileMetaData` length they claim, without touching the store. Split out from
+/// [`read_footer`] so the trailer-parsing logic can be exercised with synthetic buffers.
+fn parse_trailer(tail: &[u8], path: &Path, file_size: u64) -> Result<u64, Error> {
+    let invalid = || Error::InvalidFooter(path.to_string_lossy().to_string());
+
+    if (tail.len() as u64) < FOOTER_TRAILER_SIZE {
+        return Err(invalid());
+    }
+
+    let trailer = &tail[tail.len() - FOOTER_TRAILER_SIZE as usize..];
+
+    if &trailer[4..8] != PARQUET_MAGIC.as_slice() {
+        return Err(invalid());
+    }
+
+    let footer_len =
+        u32::from_le_bytes(trailer[0..4].try_into().expect("trailer slice is exactly 4 bytes")) as u64;
+
+    if footer_len + FOOTER_TRAILER_SIZE > file_size {
+        return Err(invalid());
+    }
+
+    Ok(footer_len)
+}
+
+/// Reads and decodes the Parquet footer at `path`, whose total size is `file_size`, fetching
+/// only the trailing bytes that contain it rather than the whole file.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidFooter`] if `path` is smaller than a valid footer trailer, doesn't
+/// end with the `PAR1` magic, or claims a footer larger than the file itself, and
+/// [`Error::FooterDecode`] if the Thrift-encoded `FileMetaData` fails to decode.
+pub async fn read_footer(
+    store: &store::StoreRef,
+    path: &Path,
+    file_size: u64,
+) -> Result<ParquetMetaData, Error> {
+    let first_read_len = INITIAL_FOOTER_FETCH_SIZE.min(file_size);
+    let first_read_start = file_size - first_read_len;
+
+    let tail = store
+        .read_range(path, first_read_start, first_read_len)
+        .await?;
+
+    let footer_len = parse_trailer(&tail, path, file_size)?;
+    let trailer_start = tail.len() - FOOTER_TRAILER_SIZE as usize;
+
+    let metadata_bytes = if footer_len + FOOTER_TRAILER_SIZE <= first_read_len {
+        // Common case: the tail we already fetched covers the whole FileMetaData.
+        let start = trailer_start - footer_len as usize;
+        tail[start..trailer_start].to_vec()
+    } else {
+        // Wide footer (many row groups/columns) that didn't fit in the initial window:
+        // re-fetch exactly the bytes we're missing instead of growing the first read for
+        // every chunk.
+        let exact_start = file_size - footer_len - FOOTER_TRAILER_SIZE;
+        store.read_range(path, exact_start, footer_len).await?
+    };
+
+    footer::decode_metadata(&metadata_bytes)
+        .map_err(|e| Error::FooterDecode(path.to_string_lossy().to_string(), e.to_string()))
+}
+
+/// Decodes the Parquet footer directly out of a fully-buffered, already-decrypted chunk file.
+///
+/// An encrypted chunk's ciphertext isn't independently seekable the way [`read_footer`]'s
+/// ranged reads need, so callers reading an encrypted chunk fetch (and decrypt) the whole
+/// file up front and parse its footer out of the resulting plaintext through this function
+/// instead.
+///
+/// # Errors
+///
+/// Same conditions as [`read_footer`], applied to `plaintext` in place of a ranged read.
+pub fn footer_from_plaintext(path: &Path, plaintext: &[u8]) -> Result<ParquetMetaData, Error> {
+    let file_size = plaintext.len() as u64;
+    let footer_len = parse_trailer(plaintext, path, file_size)?;
+    let trailer_start = plaintext.len() - FOOTER_TRAILER_SIZE as usize;
+    let start = trailer_start - footer_len as usize;
+
+    footer::decode_metadata(&plaintext[start..trailer_start])
+        .map_err(|e| Error::FooterDecode(path.to_string_lossy().to_string(), e.to_string()))
+}
+
+/// Converts a decoded footer's schema into the Arrow [`SchemaRef`] callers actually want.
+pub fn arrow_schema_from_footer(metadata: &ParquetMetaData) -> Result<SchemaRef, Error> {
+    let schema = parquet_to_arrow_schema(
+        metadata.file_metadata().schema_descr(),
+        metadata.file_metadata().key_value_metadata(),
+    )
+    .map_err(|e| Error::FooterDecode("<footer>".to_owned(), e.to_string()))?;
+
+    Ok(Arc::new(schema))
+}
+
+/// Aggregates a decoded footer's row-group statistics on
+/// [`params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP`] into this chunk's overall timestamp
+/// bounds, for the per-chunk manifest entries `FacadeTopic::finalize` persists so queriers can
+/// prune whole chunks before issuing reads.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidFooter`] if the schema has no timestamp column, if any row group is
+/// missing statistics for it, or if those statistics aren't `Int64` (the timestamp column's
+/// Arrow/Parquet physical type).
+pub fn timestamp_range_from_footer(metadata: &ParquetMetaData) -> Result<TimestampRange, Error> {
+    let ts_column_index = metadata
+        .file_metadata()
+        .schema_descr()
+        .columns()
+        .iter()
+        .position(|col| col.name() == params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP)
+        .ok_or_else(|| Error::InvalidFooter("schema has no timestamp column".to_owned()))?;
+
+    let mut bounds: Option<(i64, i64)> = None;
+
+    for row_group in metadata.row_groups() {
+        let stats = row_group
+            .column(ts_column_index)
+            .statistics()
+            .ok_or_else(|| Error::InvalidFooter("row group missing timestamp statistics".to_owned()))?;
+
+        let Statistics::Int64(stats) = stats else {
+            return Err(Error::InvalidFooter(
+                "timestamp column statistics aren't Int64".to_owned(),
+            ));
+        };
+
+        let row_min = *stats
+            .min_opt()
+            .ok_or_else(|| Error::InvalidFooter("row group missing timestamp min".to_owned()))?;
+        let row_max = *stats
+            .max_opt()
+            .ok_or_else(|| Error::InvalidFooter("row group missing timestamp max".to_owned()))?;
+
+        bounds = Some(match bounds {
+            Some((min, max)) => (min.min(row_min), max.max(row_max)),
+            None => (row_min, row_max),
+        });
+    }
+
+    let (min, max) =
+        bounds.ok_or_else(|| Error::InvalidFooter("chunk has no row groups".to_owned()))?;
+
+    Ok(TimestampRange::between(min.into(), max.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a minimal valid tail: `footer_len` bytes of placeholder metadata, the
+    /// little-endian length, and the `PAR1` magic.
+    fn tail_with(footer_len: u32, magic: &[u8; 4]) -> Vec<u8> {
+        let mut tail = vec![0u8; footer_len as usize];
+        tail.extend_from_slice(&footer_len.to_le_bytes());
+        tail.extend_from_slice(magic);
+        tail
+    }
+
+    #[test]
+    fn parse_trailer_accepts_well_formed_footer() {
+        let tail = tail_with(16, PARQUET_MAGIC);
+        let file_size = tail.len() as u64;
+
+        assert_eq!(
+            parse_trailer(&tail, Path::new("chunk.parquet"), file_size).unwrap(),
+            16
+        );
+    }
+
+    #[test]
+    fn parse_trailer_rejects_wrong_magic() {
+        let tail = tail_with(16, b"PAR2");
+        let file_size = tail.len() as u64;
+
+        assert!(parse_trailer(&tail, Path::new("chunk.parquet"), file_size).is_err());
+    }
+
+    #[test]
+    fn parse_trailer_rejects_footer_len_larger_than_file() {
+        let tail = tail_with(16, PARQUET_MAGIC);
+
+        // `file_size` is smaller than the footer the trailer claims, which is impossible.
+        assert!(parse_trailer(&tail, Path::new("chunk.parquet"), 4).is_err());
+    }
+
+    #[test]
+    fn parse_trailer_rejects_buffer_shorter_than_trailer() {
+        let tail = vec![0u8; 4];
+
+        assert!(parse_trailer(&tail, Path::new("chunk.parquet"), 4).is_err());
+    }
+
+    #[test]
+    fn footer_from_plaintext_rejects_wrong_magic() {
+        let plaintext = tail_with(16, b"PAR2");
+
+        assert!(footer_from_plaintext(Path::new("chunk.parquet"), &plaintext).is_err());
+    }
+}
@@ -4,13 +4,16 @@
 //! configuration for Parquet serialization. Each format variant has its own
 //! strategy that defines compression settings, file extensions, and reading options.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
 
 use datafusion::datasource::file_format::parquet::ParquetFormat;
 use datafusion::datasource::listing::ListingOptions;
+use datafusion_orc::OrcFormat as OrcFileFormat;
 use parquet::{
-    basic::{Compression, ZstdLevel},
-    file::properties::{EnabledStatistics, WriterProperties, WriterVersion},
+    basic::{BrotliLevel, Compression, GzipLevel, ZstdLevel},
+    file::properties::{EnabledStatistics, WriterProperties, WriterPropertiesBuilder, WriterVersion},
+    format::KeyValue,
     schema::types::ColumnPath,
 };
 use serde::{Deserialize, Serialize};
@@ -43,11 +46,85 @@ pub trait FormatStrategy: Send + Sync {
 /// statistics, and DataFusion integration. Formats that store data as Parquet
 /// files should implement this trait.
 pub trait ParquetFormatStrategy: FormatStrategy {
-    /// Returns the Parquet writer properties configured for this format.
-    fn writer_properties(&self) -> WriterProperties;
+    /// Returns the Parquet writer properties configured for this format, with no overrides.
+    fn writer_properties(&self) -> WriterProperties {
+        self.writer_properties_with_options(&ParquetWriteOptions::default())
+            .expect("default options contain no compression/writer-version spec to parse")
+    }
+
+    /// Returns this format's writer properties with `overrides` layered on top of its own
+    /// defaults, and no file-level key/value metadata beyond the reserved format-name entry
+    /// (see [`writer_properties_with_options_and_metadata`](Self::writer_properties_with_options_and_metadata)).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `overrides` contains a malformed `compression` or `writer_version`
+    /// spec.
+    fn writer_properties_with_options(&self, overrides: &ParquetWriteOptions) -> Result<WriterProperties, Error> {
+        self.writer_properties_with_options_and_metadata(overrides, &[])
+    }
+
+    /// Returns this format's writer properties (no overrides) with `kv` attached as Parquet
+    /// file-level key/value metadata, alongside the reserved [`FORMAT_METADATA_KEY`] entry
+    /// recording this strategy's [`FormatStrategy::name`] so readers can recover which
+    /// strategy produced a file without relying on the file extension.
+    fn writer_properties_with_metadata(&self, kv: &[(String, String)]) -> WriterProperties {
+        self.writer_properties_with_options_and_metadata(&ParquetWriteOptions::default(), kv)
+            .expect("default options contain no compression/writer-version spec to parse")
+    }
+
+    /// Returns this format's writer properties with `overrides` layered on top of its own
+    /// defaults. A field left unset in `overrides` falls back to the format's default;
+    /// `overrides.column_overrides` is applied last, so it can tune (or leave alone) a
+    /// per-column default a format already sets up, such as the timestamp-column bloom
+    /// filter/uncompressed behavior on `Ragged`/`Image`. `kv` is attached as Parquet
+    /// file-level key/value metadata, alongside the reserved [`FORMAT_METADATA_KEY`] entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `overrides` contains a malformed `compression` or `writer_version`
+    /// spec.
+    fn writer_properties_with_options_and_metadata(
+        &self,
+        overrides: &ParquetWriteOptions,
+        kv: &[(String, String)],
+    ) -> Result<WriterProperties, Error>;
 
     /// Returns DataFusion ListingOptions configured for reading files in this format.
     fn listing_options(&self) -> ListingOptions;
+
+    /// Returns the options this format uses when converting its Arrow schema into the
+    /// Parquet schema written to disk. Defaults to Arrow's own conversion behavior.
+    fn arrow_schema_options(&self) -> ArrowSchemaOptions {
+        ArrowSchemaOptions::default()
+    }
+}
+
+/// Options controlling how a [`ParquetFormatStrategy`]'s Arrow schema is converted into the
+/// Parquet schema written to disk.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArrowSchemaOptions {
+    /// When `true`, nested list/map group names follow the Parquet spec (`element`,
+    /// `key_value`) instead of Arrow's own (`item`), matching e.g. ClickHouse's
+    /// `output_format_parquet_compliant_nested_types`, for portability with engines that
+    /// expect the spec-compliant names rather than Arrow's.
+    pub compliant_nested_types: bool,
+}
+
+/// Reserved [`WriterProperties`] key/value metadata key under which every [`ParquetFormatStrategy`]
+/// records its own [`FormatStrategy::name`], so readers can recover which strategy produced a
+/// file without relying on the (shared, `.parquet`) file extension.
+pub const FORMAT_METADATA_KEY: &str = "mosaico.format";
+
+/// Builds the [`KeyValue`] list passed to [`WriterPropertiesBuilder::set_key_value_metadata`]:
+/// `kv` plus the reserved [`FORMAT_METADATA_KEY`] entry for `format_name`.
+fn format_metadata(format_name: &str, kv: &[(String, String)]) -> Vec<KeyValue> {
+    let mut entries: Vec<KeyValue> = kv
+        .iter()
+        .map(|(key, value)| KeyValue::new(key.clone(), Some(value.clone())))
+        .collect();
+    entries.push(KeyValue::new(FORMAT_METADATA_KEY.to_owned(), Some(format_name.to_owned())));
+    entries
 }
 
 // ============================================================================
@@ -69,10 +146,23 @@ impl FormatStrategy for DefaultFormatStrategy {
 }
 
 impl ParquetFormatStrategy for DefaultFormatStrategy {
-    fn writer_properties(&self) -> WriterProperties {
-        WriterProperties::builder()
+    fn writer_properties_with_options_and_metadata(
+        &self,
+        overrides: &ParquetWriteOptions,
+        kv: &[(String, String)],
+    ) -> Result<WriterProperties, Error> {
+        let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
+
+        let builder = WriterProperties::builder()
             .set_writer_version(WriterVersion::PARQUET_2_0)
-            .build()
+            // Page-level statistics on the timestamp column, so a column/offset index is
+            // written for it and `finalize`'s per-chunk manifest entries can prune on
+            // row-group bounds instead of scanning every chunk in full.
+            .set_column_statistics_enabled(ts_path, EnabledStatistics::Page);
+
+        Ok(apply_overrides(builder, overrides)?
+            .set_key_value_metadata(Some(format_metadata(self.name(), kv)))
+            .build())
     }
 
     fn listing_options(&self) -> ListingOptions {
@@ -87,12 +177,49 @@ impl ParquetFormatStrategy for DefaultFormatStrategy {
 /// - Timestamp column is uncompressed for fast range queries
 /// - Bloom filters enabled on timestamp for efficient filtering
 /// - Page-level statistics on timestamp for predicate pushdown
-pub struct RaggedFormatStrategy;
+/// - Smaller, row-count-bounded data pages for finer-grained predicate pushdown on seeks
+pub struct RaggedFormatStrategy {
+    /// Whether Arrow→Parquet schema conversion should emit spec-compliant list/map group
+    /// names (`element`/`key_value`) instead of Arrow's own (`item`). See
+    /// [`compliant_nested_types`](Self::compliant_nested_types).
+    compliant_nested_types: bool,
+}
 
 impl RaggedFormatStrategy {
     /// ZSTD compression level 5 provides good balance between compression ratio
     /// and speed for variable-length data structures.
     const COMPRESSION_LEVEL: i32 = 5;
+
+    /// Target encoded data page size, smaller than the 1 MiB `parquet` default so a seek only
+    /// has to decode a short page to reach a row.
+    const DATA_PAGE_SIZE_LIMIT: usize = 256 * 1024;
+
+    /// Row count a data page is capped at, regardless of `DATA_PAGE_SIZE_LIMIT`, so bursts of
+    /// small ragged records still yield fine-grained pages for timestamp predicate pushdown.
+    const DATA_PAGE_ROW_COUNT_LIMIT: usize = 2_000;
+
+    /// Builds the strategy with `compliant_nested_types` set explicitly. Use this over
+    /// [`RaggedFormatStrategy::default`] to keep writing Arrow's own `item`/`key_value`
+    /// nested-type naming (`compliant_nested_types: false`) for backward compatibility with
+    /// datasets written before this option existed.
+    pub fn new(compliant_nested_types: bool) -> Self {
+        Self { compliant_nested_types }
+    }
+
+    /// Whether Arrow→Parquet schema conversion emits spec-compliant list/map group names
+    /// (`element`/`key_value`) rather than Arrow's own (`item`), for portability with
+    /// ClickHouse, Spark, and other engines that expect the spec-compliant names. See
+    /// [`ArrowSchemaOptions::compliant_nested_types`].
+    pub fn compliant_nested_types(&self) -> bool {
+        self.compliant_nested_types
+    }
+}
+
+impl Default for RaggedFormatStrategy {
+    /// Defaults to spec-compliant nested-type naming for new writes.
+    fn default() -> Self {
+        Self::new(true)
+    }
 }
 
 impl FormatStrategy for RaggedFormatStrategy {
@@ -106,10 +233,14 @@ impl FormatStrategy for RaggedFormatStrategy {
 }
 
 impl ParquetFormatStrategy for RaggedFormatStrategy {
-    fn writer_properties(&self) -> WriterProperties {
+    fn writer_properties_with_options_and_metadata(
+        &self,
+        overrides: &ParquetWriteOptions,
+        kv: &[(String, String)],
+    ) -> Result<WriterProperties, Error> {
         let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
 
-        WriterProperties::builder()
+        let builder = WriterProperties::builder()
             .set_writer_version(WriterVersion::PARQUET_2_0)
             .set_compression(Compression::ZSTD(
                 ZstdLevel::try_new(Self::COMPRESSION_LEVEL).expect("valid ZSTD compression level"),
@@ -120,13 +251,24 @@ impl ParquetFormatStrategy for RaggedFormatStrategy {
             .set_column_compression(ts_path.clone(), Compression::UNCOMPRESSED)
             .set_column_statistics_enabled(ts_path.clone(), EnabledStatistics::Page)
             .set_column_bloom_filter_enabled(ts_path, true)
-            .build()
+            .set_data_page_size_limit(Self::DATA_PAGE_SIZE_LIMIT)
+            .set_data_page_row_count_limit(Self::DATA_PAGE_ROW_COUNT_LIMIT);
+
+        Ok(apply_overrides(builder, overrides)?
+            .set_key_value_metadata(Some(format_metadata(self.name(), kv)))
+            .build())
     }
 
     fn listing_options(&self) -> ListingOptions {
         ListingOptions::new(Arc::new(ParquetFormat::default()))
             .with_file_extension(format!(".{}", self.file_extension()))
     }
+
+    fn arrow_schema_options(&self) -> ArrowSchemaOptions {
+        ArrowSchemaOptions {
+            compliant_nested_types: self.compliant_nested_types,
+        }
+    }
 }
 
 /// Strategy for images and dense multi-dimensional arrays.
@@ -135,12 +277,22 @@ impl ParquetFormatStrategy for RaggedFormatStrategy {
 /// - Image data is written once and read many times
 /// - Higher compression ratio reduces storage costs
 /// - Decompression speed is less critical than compression ratio
+///
+/// Uses larger data pages than the `parquet` default, since larger pages compress denser
+/// arrays better and this format isn't optimized for fine-grained seeking.
 pub struct ImageFormatStrategy;
 
 impl ImageFormatStrategy {
     /// Maximum ZSTD compression level for best compression ratio.
     /// Suitable for write-once, read-many image data.
     const COMPRESSION_LEVEL: i32 = 22;
+
+    /// Target encoded data page size, well above the 1 MiB `parquet` default, since larger
+    /// pages compress dense image data better and this format is read far more than sought.
+    const DATA_PAGE_SIZE_LIMIT: usize = 8 * 1024 * 1024;
+
+    /// Row count a data page is capped at, regardless of `DATA_PAGE_SIZE_LIMIT`.
+    const DATA_PAGE_ROW_COUNT_LIMIT: usize = 200_000;
 }
 
 impl FormatStrategy for ImageFormatStrategy {
@@ -154,10 +306,14 @@ impl FormatStrategy for ImageFormatStrategy {
 }
 
 impl ParquetFormatStrategy for ImageFormatStrategy {
-    fn writer_properties(&self) -> WriterProperties {
+    fn writer_properties_with_options_and_metadata(
+        &self,
+        overrides: &ParquetWriteOptions,
+        kv: &[(String, String)],
+    ) -> Result<WriterProperties, Error> {
         let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
 
-        WriterProperties::builder()
+        let builder = WriterProperties::builder()
             .set_writer_version(WriterVersion::PARQUET_2_0)
             .set_compression(Compression::ZSTD(
                 ZstdLevel::try_new(Self::COMPRESSION_LEVEL).expect("valid ZSTD compression level"),
@@ -168,7 +324,12 @@ impl ParquetFormatStrategy for ImageFormatStrategy {
             .set_column_compression(ts_path.clone(), Compression::UNCOMPRESSED)
             .set_column_statistics_enabled(ts_path.clone(), EnabledStatistics::Page)
             .set_column_bloom_filter_enabled(ts_path, true)
-            .build()
+            .set_data_page_size_limit(Self::DATA_PAGE_SIZE_LIMIT)
+            .set_data_page_row_count_limit(Self::DATA_PAGE_ROW_COUNT_LIMIT);
+
+        Ok(apply_overrides(builder, overrides)?
+            .set_key_value_metadata(Some(format_metadata(self.name(), kv)))
+            .build())
     }
 
     fn listing_options(&self) -> ListingOptions {
@@ -177,13 +338,377 @@ impl ParquetFormatStrategy for ImageFormatStrategy {
     }
 }
 
+// ============================================================================
+// Parquet Write Options
+// ============================================================================
+
+/// A per-column override layered on top of a format's default Parquet writer properties,
+/// keyed by column name in [`ParquetWriteOptions::column_overrides`]. Applied after the
+/// format-wide fields on `ParquetWriteOptions`, so it can tune (or leave untouched) a column
+/// a format already treats specially, such as a timestamp index.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ParquetColumnWriteOptions {
+    /// See [`ParquetWriteOptions::compression`].
+    pub compression: Option<String>,
+    /// See [`ParquetWriteOptions::compression_level`].
+    pub compression_level: Option<i32>,
+    pub dictionary_enabled: Option<bool>,
+    pub statistics_enabled: Option<bool>,
+    pub bloom_filter_enabled: Option<bool>,
+}
+
+/// Overrides layered on top of a [`ParquetFormatStrategy`]'s default writer properties via
+/// [`ParquetFormatStrategy::writer_properties_with_options`]. Any field left unset keeps the
+/// format's own default, so overriding only `compression`, say, doesn't disturb a format's
+/// other tuning (like the timestamp-column bloom filter `Ragged`/`Image` set up by default).
+///
+/// `compression` follows DataFusion's `ParquetOptions` string grammar: a bare codec name
+/// (`"uncompressed"`, `"snappy"`, `"lzo"`, `"lz4"`, `"lz4_raw"`) or one with an embedded level
+/// (`"zstd(5)"`, `"gzip(6)"`, `"brotli(1)"`). `compression_level` overrides an embedded level
+/// if both are given.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ParquetWriteOptions {
+    pub compression: Option<String>,
+    pub compression_level: Option<i32>,
+    /// `"1.0"` or `"2.0"`.
+    pub writer_version: Option<String>,
+    pub dictionary_enabled: Option<bool>,
+    pub data_page_size_limit: Option<usize>,
+    pub data_page_row_count_limit: Option<usize>,
+    pub write_batch_size: Option<usize>,
+    /// Overrides for individual columns, keyed by column name. Applied after the fields
+    /// above, on top of whatever they (or the format's defaults) already set.
+    pub column_overrides: HashMap<String, ParquetColumnWriteOptions>,
+}
+
+/// Default ZSTD level used when `compression` names `zstd` without an embedded or explicit
+/// level. Matches the `zstd` crate's own default.
+const DEFAULT_ZSTD_LEVEL: i32 = 1;
+
+/// Parses a DataFusion-style compression spec (`"snappy"`, `"zstd(5)"`, `"gzip(6)"`, ...).
+/// `level` overrides any level embedded in `spec`, if given.
+fn parse_compression(spec: &str, level: Option<i32>) -> Result<Compression, Error> {
+    let (codec, embedded_level) = match spec.split_once('(') {
+        Some((codec, rest)) => {
+            let digits = rest
+                .strip_suffix(')')
+                .ok_or_else(|| Error::InvalidParquetWriteOption(format!("compression `{spec}`")))?;
+            let parsed = digits
+                .parse::<i32>()
+                .map_err(|_| Error::InvalidParquetWriteOption(format!("compression `{spec}`")))?;
+            (codec, Some(parsed))
+        }
+        None => (spec, None),
+    };
+    let level = level.or(embedded_level);
+
+    match codec {
+        "uncompressed" => Ok(Compression::UNCOMPRESSED),
+        "snappy" => Ok(Compression::SNAPPY),
+        "lzo" => Ok(Compression::LZO),
+        "lz4" => Ok(Compression::LZ4),
+        "lz4_raw" => Ok(Compression::LZ4_RAW),
+        "gzip" => {
+            let level = u32::try_from(level.unwrap_or(6))
+                .map_err(|_| Error::InvalidParquetWriteOption(format!("compression `{spec}`")))?;
+            Ok(Compression::GZIP(GzipLevel::try_new(level).map_err(|_| {
+                Error::InvalidParquetWriteOption(format!("compression `{spec}`"))
+            })?))
+        }
+        "brotli" => {
+            let level = u32::try_from(level.unwrap_or(1))
+                .map_err(|_| Error::InvalidParquetWriteOption(format!("compression `{spec}`")))?;
+            Ok(Compression::BROTLI(BrotliLevel::try_new(level).map_err(|_| {
+                Error::InvalidParquetWriteOption(format!("compression `{spec}`"))
+            })?))
+        }
+        "zstd" => Ok(Compression::ZSTD(
+            ZstdLevel::try_new(level.unwrap_or(DEFAULT_ZSTD_LEVEL))
+                .map_err(|_| Error::InvalidParquetWriteOption(format!("compression `{spec}`")))?,
+        )),
+        _ => Err(Error::InvalidParquetWriteOption(format!("compression `{spec}`"))),
+    }
+}
+
+/// Parses `"1.0"`/`"2.0"` into a [`WriterVersion`].
+fn parse_writer_version(spec: &str) -> Result<WriterVersion, Error> {
+    match spec {
+        "1.0" => Ok(WriterVersion::PARQUET_1_0),
+        "2.0" => Ok(WriterVersion::PARQUET_2_0),
+        _ => Err(Error::InvalidParquetWriteOption(format!("writer_version `{spec}`"))),
+    }
+}
+
+/// Layers `overrides` on top of `builder`'s existing (format-default) settings, leaving
+/// anything `overrides` doesn't mention untouched. Per-column overrides are applied last, so
+/// they take precedence over both the format-wide fields above and whatever per-column
+/// default the format's own builder already configured.
+fn apply_overrides(
+    mut builder: WriterPropertiesBuilder,
+    overrides: &ParquetWriteOptions,
+) -> Result<WriterPropertiesBuilder, Error> {
+    if let Some(compression) = &overrides.compression {
+        builder = builder.set_compression(parse_compression(compression, overrides.compression_level)?);
+    }
+    if let Some(writer_version) = &overrides.writer_version {
+        builder = builder.set_writer_version(parse_writer_version(writer_version)?);
+    }
+    if let Some(dictionary_enabled) = overrides.dictionary_enabled {
+        builder = builder.set_dictionary_enabled(dictionary_enabled);
+    }
+    if let Some(data_page_size_limit) = overrides.data_page_size_limit {
+        builder = builder.set_data_page_size_limit(data_page_size_limit);
+    }
+    if let Some(data_page_row_count_limit) = overrides.data_page_row_count_limit {
+        builder = builder.set_data_page_row_count_limit(data_page_row_count_limit);
+    }
+    if let Some(write_batch_size) = overrides.write_batch_size {
+        builder = builder.set_write_batch_size(write_batch_size);
+    }
+
+    for (column, column_overrides) in &overrides.column_overrides {
+        let path = ColumnPath::from(column.as_str());
+
+        if let Some(compression) = &column_overrides.compression {
+            builder = builder.set_column_compression(
+                path.clone(),
+                parse_compression(compression, column_overrides.compression_level)?,
+            );
+        }
+        if let Some(dictionary_enabled) = column_overrides.dictionary_enabled {
+            builder = builder.set_column_dictionary_enabled(path.clone(), dictionary_enabled);
+        }
+        if let Some(statistics_enabled) = column_overrides.statistics_enabled {
+            builder = builder.set_column_statistics_enabled(
+                path.clone(),
+                if statistics_enabled {
+                    EnabledStatistics::Page
+                } else {
+                    EnabledStatistics::None
+                },
+            );
+        }
+        if let Some(bloom_filter_enabled) = column_overrides.bloom_filter_enabled {
+            builder = builder.set_column_bloom_filter_enabled(path, bloom_filter_enabled);
+        }
+    }
+
+    Ok(builder)
+}
+
+// ============================================================================
+// ORC Strategy
+// ============================================================================
+
+/// ORC compression codec, mirroring the role [`Compression`] plays for Parquet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrcCompression {
+    None,
+    Zlib,
+    Snappy,
+    Zstd,
+}
+
+/// ORC-specific writer configuration, mirroring the role [`WriterProperties`] plays for
+/// Parquet: compression codec, stripe sizing, and whether SARGs (ORC's predicate-pushdown
+/// search arguments, the ORC analogue of Parquet row-group statistics) are embedded in the
+/// row-index entries written to the file.
+#[derive(Debug, Clone)]
+pub struct OrcWriterConfig {
+    pub compression: OrcCompression,
+    pub stripe_size: usize,
+    pub row_index_stride: usize,
+    pub enable_sargs: bool,
+}
+
+impl Default for OrcWriterConfig {
+    fn default() -> Self {
+        Self {
+            compression: OrcCompression::Zstd,
+            stripe_size: 64 * 1024 * 1024,
+            row_index_stride: 10_000,
+            enable_sargs: true,
+        }
+    }
+}
+
+/// Strategy trait for ORC-based storage formats, analogous to [`ParquetFormatStrategy`] for
+/// Parquet. Extends `FormatStrategy` with ORC-specific writer configuration and DataFusion
+/// integration. Formats that store data as ORC files should implement this trait instead of
+/// `ParquetFormatStrategy`.
+pub trait OrcFormatStrategy: FormatStrategy {
+    /// Returns the ORC writer configuration for this format.
+    fn writer_config(&self) -> OrcWriterConfig;
+
+    /// Returns DataFusion ListingOptions configured for reading files in this format.
+    fn listing_options(&self) -> ListingOptions;
+}
+
+/// Strategy for ORC-backed storage, a columnar alternative to Parquet used by the same
+/// Arrow/DataFusion ecosystem. Useful for interop with systems (Hive, ClickHouse) that read
+/// ORC natively, and for SARGs-based predicate pushdown where Parquet row-group statistics
+/// aren't a good fit.
+pub struct OrcFormat;
+
+impl FormatStrategy for OrcFormat {
+    fn file_extension(&self) -> &'static str {
+        "orc"
+    }
+
+    fn name(&self) -> &'static str {
+        "orc"
+    }
+}
+
+impl OrcFormatStrategy for OrcFormat {
+    fn writer_config(&self) -> OrcWriterConfig {
+        OrcWriterConfig::default()
+    }
+
+    fn listing_options(&self) -> ListingOptions {
+        ListingOptions::new(Arc::new(OrcFileFormat::default()))
+            .with_file_extension(format!(".{}", self.file_extension()))
+    }
+}
+
+// ============================================================================
+// Format Registry
+// ============================================================================
+
+/// A strategy as held by the [`FormatRegistry`]. Every registered format offers the base
+/// [`FormatStrategy`] behavior; formats backed by Parquet additionally offer
+/// [`ParquetFormatStrategy`], recovered through [`as_parquet`](Self::as_parquet), and formats
+/// backed by ORC additionally offer [`OrcFormatStrategy`], recovered through
+/// [`as_orc`](Self::as_orc).
+#[derive(Clone)]
+enum RegisteredStrategy {
+    Base(Arc<dyn FormatStrategy>),
+    Parquet(Arc<dyn ParquetFormatStrategy>),
+    Orc(Arc<dyn OrcFormatStrategy>),
+}
+
+impl RegisteredStrategy {
+    fn strategy(&self) -> Arc<dyn FormatStrategy> {
+        match self {
+            Self::Base(s) => s.clone(),
+            Self::Parquet(s) => s.clone() as Arc<dyn FormatStrategy>,
+            Self::Orc(s) => s.clone() as Arc<dyn FormatStrategy>,
+        }
+    }
+
+    fn as_parquet(&self) -> Option<Arc<dyn ParquetFormatStrategy>> {
+        match self {
+            Self::Parquet(s) => Some(s.clone()),
+            Self::Base(_) | Self::Orc(_) => None,
+        }
+    }
+
+    fn as_orc(&self) -> Option<Arc<dyn OrcFormatStrategy>> {
+        match self {
+            Self::Orc(s) => Some(s.clone()),
+            Self::Base(_) | Self::Parquet(_) => None,
+        }
+    }
+}
+
+/// Maps a format name to its registered strategy, so adding a format no longer requires
+/// editing the [`Format`] enum, its `strategy()`/`as_parquet()`/`as_orc()` resolvers, or
+/// `FromStr`. Mirrors the approach DataFusion uses for externally-registered file types: a
+/// name-keyed table of trait objects that built-ins are seeded into and callers can overlay
+/// or extend at runtime.
+pub struct FormatRegistry {
+    strategies: HashMap<String, RegisteredStrategy>,
+}
+
+impl FormatRegistry {
+    /// Builds a registry pre-populated with the `default`/`ragged`/`image`/`orc` built-ins.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self {
+            strategies: HashMap::new(),
+        };
+
+        registry.register_parquet(Arc::new(DefaultFormatStrategy));
+        registry.register_parquet(Arc::new(RaggedFormatStrategy::default()));
+        registry.register_parquet(Arc::new(ImageFormatStrategy));
+        registry.register_orc(Arc::new(OrcFormat));
+
+        registry
+    }
+
+    /// Registers a non-Parquet, non-ORC `strategy` under its own [`FormatStrategy::name`],
+    /// replacing any existing entry of the same name (including a built-in one).
+    pub fn register(&mut self, strategy: Arc<dyn FormatStrategy>) {
+        self.strategies
+            .insert(strategy.name().to_owned(), RegisteredStrategy::Base(strategy));
+    }
+
+    /// Registers a Parquet-backed `strategy` under its own [`FormatStrategy::name`],
+    /// replacing any existing entry of the same name (including a built-in one).
+    pub fn register_parquet(&mut self, strategy: Arc<dyn ParquetFormatStrategy>) {
+        self.strategies
+            .insert(strategy.name().to_owned(), RegisteredStrategy::Parquet(strategy));
+    }
+
+    /// Registers an ORC-backed `strategy` under its own [`FormatStrategy::name`], replacing
+    /// any existing entry of the same name (including a built-in one).
+    pub fn register_orc(&mut self, strategy: Arc<dyn OrcFormatStrategy>) {
+        self.strategies
+            .insert(strategy.name().to_owned(), RegisteredStrategy::Orc(strategy));
+    }
+
+    fn get(&self, name: &str) -> Option<RegisteredStrategy> {
+        self.strategies.get(name).cloned()
+    }
+}
+
+impl Default for FormatRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+/// Process-wide registry consulted by `Format::from_str`/`strategy()`/`as_parquet()`.
+static REGISTRY: LazyLock<RwLock<FormatRegistry>> =
+    LazyLock::new(|| RwLock::new(FormatRegistry::with_builtins()));
+
+/// Registers `strategy` globally under its own name, so it (or a replacement for one of the
+/// built-ins) flows through `Format::from_str`/`strategy()`/`as_parquet()` from then on.
+pub fn register_format(strategy: Arc<dyn FormatStrategy>) {
+    REGISTRY
+        .write()
+        .expect("format registry lock poisoned")
+        .register(strategy);
+}
+
+/// Registers a Parquet-backed `strategy` globally under its own name. See [`register_format`].
+pub fn register_parquet_format(strategy: Arc<dyn ParquetFormatStrategy>) {
+    REGISTRY
+        .write()
+        .expect("format registry lock poisoned")
+        .register_parquet(strategy);
+}
+
+/// Registers an ORC-backed `strategy` globally under its own name. See [`register_format`].
+pub fn register_orc_format(strategy: Arc<dyn OrcFormatStrategy>) {
+    REGISTRY
+        .write()
+        .expect("format registry lock poisoned")
+        .register_orc(strategy);
+}
+
+fn resolve(name: &str) -> Option<RegisteredStrategy> {
+    REGISTRY.read().expect("format registry lock poisoned").get(name)
+}
+
 // ============================================================================
 // Format Enum
 // ============================================================================
 
 /// This enum allows choosing the appropriate storage strategy based on the
 /// structure of the data being written.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum Format {
     /// Serialization format used to store data in a columnar format.
@@ -197,27 +722,53 @@ pub enum Format {
     /// Serialization format for images and dense multi-dimensional arrays.
     /// This format is optimized for storing high-dimensional data efficiently.
     Image,
+
+    /// Columnar format backed by ORC instead of Parquet, for interop with systems that read
+    /// ORC natively or that want SARGs-based predicate pushdown.
+    Orc,
+
+    /// A format registered at runtime through [`register_format`]/[`register_parquet_format`]/
+    /// [`register_orc_format`], resolved by name rather than a fixed variant.
+    Custom(String),
 }
 
 impl Format {
+    /// The name this format is registered under in the [`FormatRegistry`].
+    fn registry_name(&self) -> &str {
+        match self {
+            Self::Default => "default",
+            Self::Ragged => "ragged",
+            Self::Image => "image",
+            Self::Orc => "orc",
+            Self::Custom(name) => name,
+        }
+    }
+
     /// Returns the base strategy implementation for this format variant.
     ///
     /// Use this method when you only need format-agnostic behavior like
     /// file extension or format name. For Parquet-specific operations,
     /// use [`as_parquet()`](Self::as_parquet) instead.
-    pub fn strategy(&self) -> Box<dyn FormatStrategy> {
-        match self {
-            Self::Default => Box::new(DefaultFormatStrategy),
-            Self::Ragged => Box::new(RaggedFormatStrategy),
-            Self::Image => Box::new(ImageFormatStrategy),
-        }
+    ///
+    /// # Panics
+    ///
+    /// Panics if this format's name isn't registered in the global [`FormatRegistry`]. This
+    /// can only happen for a `Custom` format that was deserialized or constructed without a
+    /// matching [`register_format`]/[`register_parquet_format`]/[`register_orc_format`] call
+    /// having run first.
+    pub fn strategy(&self) -> Arc<dyn FormatStrategy> {
+        resolve(self.registry_name())
+            .unwrap_or_else(|| panic!("format `{}` is not registered", self.registry_name()))
+            .strategy()
     }
 
     /// Returns the Parquet strategy if this format uses Parquet storage.
     ///
     /// Use this method when you need Parquet-specific configuration like
     /// writer properties or DataFusion listing options. Returns `None` for
-    /// formats that don't use Parquet as their underlying storage.
+    /// formats that don't use Parquet as their underlying storage — `Orc`, or any
+    /// `Custom` format registered through [`register_format`]/[`register_orc_format`] rather
+    /// than [`register_parquet_format`].
     ///
     /// # Example
     ///
@@ -230,13 +781,27 @@ impl Format {
     ///     let options = parquet_strategy.listing_options();
     /// }
     /// ```
-    pub fn as_parquet(&self) -> Option<Box<dyn ParquetFormatStrategy>> {
-        match self {
-            Self::Default => Some(Box::new(DefaultFormatStrategy)),
-            Self::Ragged => Some(Box::new(RaggedFormatStrategy)),
-            Self::Image => Some(Box::new(ImageFormatStrategy)),
-            // Future non-Parquet formats would return None here
-        }
+    pub fn as_parquet(&self) -> Option<Arc<dyn ParquetFormatStrategy>> {
+        resolve(self.registry_name()).and_then(|s| s.as_parquet())
+    }
+
+    /// Returns the ORC strategy if this format uses ORC storage. Returns `None` for formats
+    /// that don't, including any `Custom` format registered through
+    /// [`register_format`]/[`register_parquet_format`] rather than [`register_orc_format`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mosaicod::rw::Format;
+    ///
+    /// let format = Format::Orc;
+    /// if let Some(orc_strategy) = format.as_orc() {
+    ///     let config = orc_strategy.writer_config();
+    ///     let options = orc_strategy.listing_options();
+    /// }
+    /// ```
+    pub fn as_orc(&self) -> Option<Arc<dyn OrcFormatStrategy>> {
+        resolve(self.registry_name()).and_then(|s| s.as_orc())
     }
 }
 
@@ -260,6 +825,8 @@ impl std::str::FromStr for Format {
             "default" => Ok(Self::Default),
             "ragged" => Ok(Self::Ragged),
             "image" => Ok(Self::Image),
+            "orc" => Ok(Self::Orc),
+            _ if resolve(value).is_some() => Ok(Self::Custom(value.to_owned())),
             _ => Err(Error::UnkownFormat(value.to_owned())),
         }
     }
@@ -288,6 +855,11 @@ mod tests {
         assert!(image.is_ok());
         assert_eq!(image.as_ref().unwrap(), &Format::Image);
         assert_eq!(image.unwrap().as_extension(), params::ext::PARQUET);
+
+        let orc = Format::from_str("orc");
+        assert!(orc.is_ok());
+        assert_eq!(orc.as_ref().unwrap(), &Format::Orc);
+        assert_eq!(orc.unwrap().as_extension(), "orc");
     }
 
     #[test]
@@ -295,6 +867,7 @@ mod tests {
         assert_eq!("ragged", Format::Ragged.to_string());
         assert_eq!("default", Format::Default.to_string());
         assert_eq!("image", Format::Image.to_string());
+        assert_eq!("orc", Format::Orc.to_string());
     }
 
     #[test]
@@ -302,6 +875,7 @@ mod tests {
         assert_eq!(Format::Default.strategy().name(), "default");
         assert_eq!(Format::Ragged.strategy().name(), "ragged");
         assert_eq!(Format::Image.strategy().name(), "image");
+        assert_eq!(Format::Orc.strategy().name(), "orc");
     }
 
     #[test]
@@ -309,6 +883,7 @@ mod tests {
         assert_eq!(Format::Default.strategy().file_extension(), "parquet");
         assert_eq!(Format::Ragged.strategy().file_extension(), "parquet");
         assert_eq!(Format::Image.strategy().file_extension(), "parquet");
+        assert_eq!(Format::Orc.strategy().file_extension(), "orc");
     }
 
     #[test]
@@ -334,5 +909,167 @@ mod tests {
         assert!(Format::Default.as_parquet().is_some());
         assert!(Format::Ragged.as_parquet().is_some());
         assert!(Format::Image.as_parquet().is_some());
+        assert!(Format::Orc.as_parquet().is_none());
+    }
+
+    #[test]
+    fn as_orc_returns_some_only_for_orc_format() {
+        assert!(Format::Orc.as_orc().is_some());
+        assert!(Format::Default.as_orc().is_none());
+        assert!(Format::Ragged.as_orc().is_none());
+        assert!(Format::Image.as_orc().is_none());
+    }
+
+    #[test]
+    fn orc_strategy_writer_config_and_listing_options() {
+        let orc = Format::Orc.as_orc().unwrap();
+
+        let config = orc.writer_config();
+        assert!(config.enable_sargs);
+
+        let _ = orc.listing_options();
+    }
+
+    #[test]
+    fn writer_properties_with_options_overrides_compression() {
+        let overrides = ParquetWriteOptions {
+            compression: Some("gzip(9)".to_owned()),
+            ..Default::default()
+        };
+
+        let props = Format::Default
+            .as_parquet()
+            .unwrap()
+            .writer_properties_with_options(&overrides)
+            .unwrap();
+
+        assert_eq!(
+            props.compression(&ColumnPath::from("any_column")),
+            Compression::GZIP(GzipLevel::try_new(9).unwrap())
+        );
+    }
+
+    #[test]
+    fn writer_properties_with_options_preserves_timestamp_defaults_unless_overridden() {
+        let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
+        let overrides = ParquetWriteOptions {
+            compression: Some("snappy".to_owned()),
+            ..Default::default()
+        };
+
+        let props = Format::Ragged
+            .as_parquet()
+            .unwrap()
+            .writer_properties_with_options(&overrides)
+            .unwrap();
+
+        assert_eq!(props.compression(&ts_path), Compression::UNCOMPRESSED);
+        assert!(props.bloom_filter_properties(&ts_path).is_some());
+    }
+
+    #[test]
+    fn writer_properties_with_options_applies_column_override() {
+        let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
+        let overrides = ParquetWriteOptions {
+            column_overrides: HashMap::from([(
+                params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP.to_owned(),
+                ParquetColumnWriteOptions {
+                    bloom_filter_enabled: Some(false),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+
+        let props = Format::Ragged
+            .as_parquet()
+            .unwrap()
+            .writer_properties_with_options(&overrides)
+            .unwrap();
+
+        assert!(props.bloom_filter_properties(&ts_path).is_none());
+    }
+
+    #[test]
+    fn parse_compression_rejects_unknown_codec() {
+        let overrides = ParquetWriteOptions {
+            compression: Some("not_a_codec".to_owned()),
+            ..Default::default()
+        };
+
+        assert!(Format::Default
+            .as_parquet()
+            .unwrap()
+            .writer_properties_with_options(&overrides)
+            .is_err());
+    }
+
+    #[test]
+    fn writer_properties_with_metadata_includes_reserved_format_key() {
+        let kv = [("producer".to_owned(), "mosaicod-test".to_owned())];
+
+        let props = Format::Ragged.as_parquet().unwrap().writer_properties_with_metadata(&kv);
+
+        let metadata = props.key_value_metadata().expect("key/value metadata set");
+        assert!(metadata
+            .iter()
+            .any(|entry| entry.key == FORMAT_METADATA_KEY && entry.value.as_deref() == Some("ragged")));
+        assert!(metadata
+            .iter()
+            .any(|entry| entry.key == "producer" && entry.value.as_deref() == Some("mosaicod-test")));
+    }
+
+    #[test]
+    fn default_writes_page_level_timestamp_statistics_for_manifest_pruning() {
+        let ts_path = ColumnPath::from(params::ARROW_SCHEMA_COLUMN_NAME_INDEX_TIMESTAMP);
+        let props = Format::Default.as_parquet().unwrap().writer_properties();
+
+        assert_eq!(props.statistics_enabled(&ts_path), EnabledStatistics::Page);
+    }
+
+    #[test]
+    fn ragged_uses_smaller_row_count_bounded_pages_than_image() {
+        let ragged_props = Format::Ragged.as_parquet().unwrap().writer_properties();
+        let image_props = Format::Image.as_parquet().unwrap().writer_properties();
+
+        assert!(ragged_props.data_page_size_limit() < image_props.data_page_size_limit());
+        assert!(ragged_props.data_page_row_count_limit() < image_props.data_page_row_count_limit());
+    }
+
+    #[test]
+    fn ragged_defaults_to_compliant_nested_types() {
+        assert!(Format::Ragged.as_parquet().unwrap().arrow_schema_options().compliant_nested_types);
+    }
+
+    #[test]
+    fn ragged_can_opt_out_of_compliant_nested_types_for_backward_compatibility() {
+        let strategy = RaggedFormatStrategy::new(false);
+
+        assert!(!strategy.compliant_nested_types());
+        assert!(!strategy.arrow_schema_options().compliant_nested_types);
+    }
+
+    struct DummyFormatStrategy;
+
+    impl FormatStrategy for DummyFormatStrategy {
+        fn file_extension(&self) -> &'static str {
+            "dummy"
+        }
+
+        fn name(&self) -> &'static str {
+            "custom_test_format"
+        }
+    }
+
+    #[test]
+    fn custom_format_flows_through_from_str_and_strategy() {
+        register_format(Arc::new(DummyFormatStrategy));
+
+        let format = Format::from_str("custom_test_format").unwrap();
+
+        assert_eq!(format, Format::Custom("custom_test_format".to_owned()));
+        assert_eq!(format.strategy().name(), "custom_test_format");
+        assert_eq!(format.strategy().file_extension(), "dummy");
+        assert!(format.as_parquet().is_none());
     }
 }
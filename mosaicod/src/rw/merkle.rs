@@ -0,0 +1,122 @@
+//! Merkle integrity digests for finalized chunk data files.
+//!
+//! Mirrors how object-store blob writers build a content-addressed digest over a blob:
+//! [`merkle_root`] splits the buffer into fixed-size blocks, SHA-256-hashes each block into a
+//! leaf, then repeatedly hashes fixed-arity runs of child digests together level by level until
+//! a single root digest remains. [`super::chunked_writer::ChunkedWriter`] computes this over a
+//! chunk's final written bytes (same bytes [`super::checksum::crc32c`] covers) and attaches it
+//! to [`super::chunk_writer::ChunkMetadata`], available to the `on_chunk_created` callback.
+//!
+//! [`merkle_root`] only returns the root; it doesn't retain the intermediate per-block digests a
+//! Merkle proof needs, so as it stands this just gives a caller a second, tree-shaped checksum
+//! to compare against on a full reread — a caller wanting to verify a single block without
+//! rehashing the whole chunk would need to additionally persist (or recompute, then cache) those
+//! intermediate digests themselves.
+
+use sha2::{Digest, Sha256};
+
+/// Block size leaves are hashed over.
+const BLOCK_SIZE: usize = 64 * 1024;
+
+/// Arity used when combining digests level by level. Deliberately much smaller than
+/// `BLOCK_SIZE`, so that buffers larger than `ARITY * BLOCK_SIZE` (16 MiB) end up with a real
+/// multi-level tree rather than one flat level of leaves combined in a single pass — keeping a
+/// later range-verification proof close to `O(log n)` digests instead of `O(n)`. Buffers of
+/// 16 MiB or less have at most `ARITY` leaves and are combined in a single pass.
+const ARITY: usize = 256;
+
+/// A 32-byte SHA-256 digest.
+pub type Digest32 = [u8; 32];
+
+/// Domain-separation tag prefixed to a leaf's hash input, distinct from [`NODE_TAG`] so a leaf's
+/// digest can never be crafted to collide with some internal node's digest (the classic
+/// second-preimage attack RFC 6962 fixes the same way).
+const LEAF_TAG: u8 = 0x00;
+/// Domain-separation tag prefixed to an internal node's hash input. See [`LEAF_TAG`].
+const NODE_TAG: u8 = 0x01;
+
+/// Builds a Merkle tree over `buffer` and returns its root digest.
+///
+/// `buffer` is split into fixed `BLOCK_SIZE` blocks, each SHA-256-hashed into a leaf digest.
+/// Leaves are then combined `ARITY`-wide: every run of up to `ARITY` sibling digests is
+/// concatenated and SHA-256-hashed into one parent digest, repeated level by level until a
+/// single root digest remains. An empty buffer still yields a well-defined root: the leaf hash
+/// of one empty block (the SHA-256 of [`LEAF_TAG`] alone).
+pub fn merkle_root(buffer: &[u8]) -> Digest32 {
+    let mut level: Vec<Digest32> = if buffer.is_empty() {
+        vec![hash_block(&[])]
+    } else {
+        buffer.chunks(BLOCK_SIZE).map(hash_block).collect()
+    };
+
+    while level.len() > 1 {
+        level = level
+            .chunks(ARITY)
+            .map(|siblings| {
+                let mut hasher = Sha256::new();
+                hasher.update([NODE_TAG]);
+                for sibling in siblings {
+                    hasher.update(sibling);
+                }
+                hasher.finalize().into()
+            })
+            .collect();
+    }
+
+    level[0]
+}
+
+fn hash_block(block: &[u8]) -> Digest32 {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_TAG]);
+    hasher.update(block);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_buffer_hashes_to_the_hash_of_an_empty_block() {
+        assert_eq!(merkle_root(&[]), hash_block(&[]));
+    }
+
+    #[test]
+    fn single_block_root_is_just_that_blocks_leaf_hash() {
+        let buffer = vec![7u8; BLOCK_SIZE - 1];
+        assert_eq!(merkle_root(&buffer), hash_block(&buffer));
+    }
+
+    #[test]
+    fn root_is_deterministic() {
+        let buffer = vec![3u8; BLOCK_SIZE * 3 + 17];
+        assert_eq!(merkle_root(&buffer), merkle_root(&buffer));
+    }
+
+    #[test]
+    fn a_single_changed_byte_changes_the_root() {
+        let mut buffer = vec![1u8; BLOCK_SIZE * 2];
+        let a = merkle_root(&buffer);
+
+        buffer[BLOCK_SIZE + 5] ^= 0xff;
+        let b = merkle_root(&buffer);
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn several_blocks_still_reduce_to_a_single_32_byte_root() {
+        let buffer = vec![5u8; BLOCK_SIZE * 3 + 1];
+        let root = merkle_root(&buffer);
+        assert_eq!(root.len(), 32);
+        assert_ne!(root, hash_block(&buffer[..BLOCK_SIZE]));
+    }
+
+    #[test]
+    fn more_leaves_than_arity_still_combine_to_a_single_root() {
+        // More than ARITY leaves, forcing at least two combining passes above the leaf level.
+        let buffer = vec![9u8; BLOCK_SIZE * (ARITY + 1)];
+        assert_eq!(merkle_root(&buffer).len(), 32);
+    }
+}
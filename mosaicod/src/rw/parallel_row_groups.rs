@@ -0,0 +1,153 @@
+//! Parallel, multi-core row-group encoding for a single output Parquet file.
+//!
+//! [`write_parallel`] backs [`super::chunked_writer::ChunkedWriter::with_parallel_row_groups`]:
+//! instead of one [`super::chunk_writer::ChunkWriter`] encoding every batch serially on a single
+//! blocking thread, the buffered batches are partitioned across `num_workers` independent
+//! `spawn_blocking` tasks, each encoding its own share into a standalone row group's worth of
+//! per-column chunks. Those row groups are then appended in order into a single output file via
+//! [`SerializedFileWriter`], which only has to write each worker's already-encoded bytes and
+//! stitch a footer referencing all of them — the CPU-bound encoding itself, not the (cheap,
+//! sequential) stitching step, is what runs concurrently.
+//!
+//! Known limitation: bloom filters and column indexes both need a single writer's global view
+//! of a column across the whole file (a bloom filter sizes itself off a global distinct-value
+//! estimate; a column index is one structure per column per file, not per row group), so neither
+//! is supported in this mode regardless of what the writing format's [`WriterProperties`]
+//! otherwise configure.
+
+use std::sync::Arc;
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
+use futures::future::try_join_all;
+use parquet::arrow::arrow_to_parquet_schema;
+use parquet::arrow::arrow_writer::{compute_leaves, get_column_writers, ArrowColumnChunk, ArrowColumnWriter};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::SchemaDescriptor;
+
+use super::Error;
+
+/// Encodes `batches` into a single Parquet file's bytes, spreading the encoding work (not the
+/// final stitching) across up to `num_workers` blocking-pool tasks.
+///
+/// `batches` is partitioned into up to `num_workers` contiguous groups (see [`partition_batches`]),
+/// each becoming exactly one row group in the output file, in the same order the batches were
+/// given. `batches` must be non-empty — [`super::chunked_writer::ChunkedWriter`] only calls this
+/// once it already has at least one buffered batch.
+pub async fn write_parallel(
+    schema: SchemaRef,
+    properties: WriterProperties,
+    batches: Vec<RecordBatch>,
+    num_workers: usize,
+) -> Result<Vec<u8>, Error> {
+    let properties = Arc::new(properties);
+    let num_workers = num_workers.max(1).min(batches.len().max(1));
+
+    let parquet_schema =
+        Arc::new(arrow_to_parquet_schema(&schema, &properties).map_err(|e| Error::ParquetEncode(e.to_string()))?);
+
+    // Every partition's worker is spawned up front, before any of them are awaited, so all
+    // `num_workers` blocking-pool tasks actually run concurrently — awaiting one at a time as
+    // it's spawned would serialize the very encoding this option exists to parallelize.
+    let workers = partition_batches(batches, num_workers).into_iter().map(|partition| {
+        let schema = schema.clone();
+        let properties = properties.clone();
+        let parquet_schema = parquet_schema.clone();
+
+        tokio::task::spawn_blocking(move || encode_row_group(&schema, &properties, &parquet_schema, &partition))
+    });
+
+    let row_groups = try_join_all(workers)
+        .await
+        .map_err(|e| Error::BlockingOperationError(e.to_string()))?
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    tokio::task::spawn_blocking(move || stitch_row_groups(&parquet_schema, &properties, row_groups))
+        .await
+        .map_err(|e| Error::BlockingOperationError(e.to_string()))?
+}
+
+/// Divides `batches` into up to `num_workers` contiguous, roughly row-count-even groups. A group
+/// may span several input batches (they're concatenated into one row group each), but a single
+/// batch is never split across two groups, so a worker's row group boundary always falls on an
+/// existing batch boundary.
+fn partition_batches(batches: Vec<RecordBatch>, num_workers: usize) -> Vec<Vec<RecordBatch>> {
+    let total_rows: usize = batches.iter().map(RecordBatch::num_rows).sum();
+    let rows_per_partition = total_rows.div_ceil(num_workers).max(1);
+
+    let mut partitions = Vec::new();
+    let mut current = Vec::new();
+    let mut current_rows = 0;
+
+    for batch in batches {
+        current_rows += batch.num_rows();
+        current.push(batch);
+
+        if current_rows >= rows_per_partition {
+            partitions.push(std::mem::take(&mut current));
+            current_rows = 0;
+        }
+    }
+
+    if !current.is_empty() {
+        partitions.push(current);
+    }
+
+    partitions
+}
+
+/// Encodes one worker's partition into a standalone row group's worth of per-column chunks,
+/// without touching an output file — the actual write happens later, in [`stitch_row_groups`].
+fn encode_row_group(
+    schema: &SchemaRef,
+    properties: &Arc<WriterProperties>,
+    parquet_schema: &Arc<SchemaDescriptor>,
+    batches: &[RecordBatch],
+) -> Result<Vec<ArrowColumnChunk>, Error> {
+    let mut writers =
+        get_column_writers(parquet_schema, properties, schema).map_err(|e| Error::ParquetEncode(e.to_string()))?;
+
+    for batch in batches {
+        for (column_writer, (field, array)) in writers.iter_mut().zip(schema.fields().iter().zip(batch.columns())) {
+            for leaf in compute_leaves(field, array).map_err(|e| Error::ParquetEncode(e.to_string()))? {
+                column_writer.write(&leaf).map_err(|e| Error::ParquetEncode(e.to_string()))?;
+            }
+        }
+    }
+
+    writers
+        .into_iter()
+        .map(|writer: ArrowColumnWriter| writer.close().map_err(|e| Error::ParquetEncode(e.to_string())))
+        .collect()
+}
+
+/// Appends every worker's already-encoded row group, in partition order, into a single output
+/// file and returns its finished bytes, including a footer referencing all of them.
+fn stitch_row_groups(
+    parquet_schema: &Arc<SchemaDescriptor>,
+    properties: &Arc<WriterProperties>,
+    row_groups: Vec<Vec<ArrowColumnChunk>>,
+) -> Result<Vec<u8>, Error> {
+    let mut buffer = Vec::new();
+    let mut file_writer =
+        SerializedFileWriter::new(&mut buffer, parquet_schema.root_schema_ptr(), properties.clone())
+            .map_err(|e| Error::ParquetEncode(e.to_string()))?;
+
+    for chunks in row_groups {
+        let mut row_group_writer = file_writer.next_row_group().map_err(|e| Error::ParquetEncode(e.to_string()))?;
+
+        for chunk in chunks {
+            chunk
+                .append_to_row_group(&mut row_group_writer)
+                .map_err(|e| Error::ParquetEncode(e.to_string()))?;
+        }
+
+        row_group_writer.close().map_err(|e| Error::ParquetEncode(e.to_string()))?;
+    }
+
+    file_writer.close().map_err(|e| Error::ParquetEncode(e.to_string()))?;
+
+    Ok(buffer)
+}
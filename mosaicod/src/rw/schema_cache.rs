@@ -0,0 +1,136 @@
+//! Process-scoped cache of Arrow schemas recovered from Parquet footers.
+//!
+//! [`FacadeTopic::arrow_schema`](crate::repo::facades::FacadeTopic::arrow_schema) now reads
+//! only a chunk's trailing footer (see [`crate::rw::footer`]) instead of the whole file, but a
+//! topic's schema never changes once chunk 0 is written, so re-fetching and re-parsing that
+//! footer on every lookup is still wasted store round trips. [`SchemaCache`] keeps the
+//! last-seen schema per topic locator around so repeated lookups skip the store entirely.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use arrow::datatypes::SchemaRef;
+
+/// Maximum number of topic schemas to retain before the oldest-inserted entry is evicted.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// An LRU-ish cache of [`SchemaRef`]s keyed by topic locator, shared process-wide through
+/// [`schema_cache`]. "LRU-ish" because eviction is FIFO by insertion order rather than by
+/// last access — good enough for a cache this small and this rarely invalidated.
+pub struct SchemaCache {
+    capacity: usize,
+    entries: Mutex<HashMap<String, SchemaRef>>,
+    /// Insertion order, oldest first, used for eviction once `capacity` is exceeded.
+    order: Mutex<Vec<String>>,
+}
+
+impl SchemaCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(HashMap::new()),
+            order: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Returns the cached schema for `locator`, if any.
+    pub fn get(&self, locator: &str) -> Option<SchemaRef> {
+        self.entries.lock().expect("schema cache poisoned").get(locator).cloned()
+    }
+
+    /// Inserts `schema` for `locator`, evicting the oldest entry first once `capacity` is
+    /// exceeded.
+    pub fn put(&self, locator: String, schema: SchemaRef) {
+        let mut entries = self.entries.lock().expect("schema cache poisoned");
+        let mut order = self.order.lock().expect("schema cache poisoned");
+
+        if !entries.contains_key(&locator) {
+            order.push(locator.clone());
+        }
+        entries.insert(locator, schema);
+
+        while entries.len() > self.capacity && !order.is_empty() {
+            let oldest = order.remove(0);
+            entries.remove(&oldest);
+        }
+    }
+
+    /// Drops the cached schema for `locator`, if any. Call this after a topic is recreated
+    /// (dropping and re-adding chunk 0 under the same locator) so a stale schema can't be
+    /// served.
+    pub fn invalidate(&self, locator: &str) {
+        let mut entries = self.entries.lock().expect("schema cache poisoned");
+        let mut order = self.order.lock().expect("schema cache poisoned");
+
+        entries.remove(locator);
+        order.retain(|l| l != locator);
+    }
+
+    /// Number of schemas currently cached.
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("schema cache poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Process-wide instance consulted by `FacadeTopic::arrow_schema`.
+static SCHEMA_CACHE: LazyLock<Arc<SchemaCache>> =
+    LazyLock::new(|| Arc::new(SchemaCache::new(DEFAULT_CAPACITY)));
+
+/// Returns the process-wide [`SchemaCache`].
+pub fn schema_cache() -> Arc<SchemaCache> {
+    SCHEMA_CACHE.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> SchemaRef {
+        Arc::new(arrow::datatypes::Schema::empty())
+    }
+
+    #[test]
+    fn get_returns_none_for_uncached_locator() {
+        let cache = SchemaCache::new(8);
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn put_then_get_returns_cached_schema() {
+        let cache = SchemaCache::new(8);
+        cache.put("a".to_owned(), schema());
+
+        assert!(cache.get("a").is_some());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn capacity_evicts_oldest_entry_first() {
+        let cache = SchemaCache::new(2);
+
+        cache.put("a".to_owned(), schema());
+        cache.put("b".to_owned(), schema());
+        cache.put("c".to_owned(), schema());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn invalidate_drops_only_the_named_locator() {
+        let cache = SchemaCache::new(8);
+        cache.put("a".to_owned(), schema());
+        cache.put("b".to_owned(), schema());
+
+        cache.invalidate("a");
+
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+    }
+}
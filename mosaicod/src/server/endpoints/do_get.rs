@@ -1,19 +1,31 @@
+use std::time::Duration;
+
 use arrow_flight::{
     Ticket,
     encode::{FlightDataEncoder, FlightDataEncoderBuilder},
     error::FlightError,
 };
 
-use futures::TryStreamExt;
-use log::{debug, info, trace};
+use futures::{StreamExt, TryStreamExt, stream};
+use log::{debug, info, trace, warn};
+
+use crate::{
+    marshal, query, repo,
+    server::{errors::ServerError, metrics::MetricsRef, topic_watch},
+    store,
+    types::{self, Resource},
+};
 
-use crate::{marshal, query, repo, server::errors::ServerError, store, types::Resource};
+/// How long a follow subscriber waits for a notification of new data before giving up and
+/// closing the stream, so abandoned long-polls don't accumulate server side.
+const FOLLOW_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
 
 pub async fn do_get(
     store: store::StoreRef,
     repo: repo::Repository,
     ts_engine: query::TimeseriesGatewayRef,
     ticket: Ticket,
+    metrics: MetricsRef,
 ) -> Result<FlightDataEncoder, ServerError> {
     let ticket = marshal::flight::ticket_topic_from_binary(&ticket.ticket)?;
 
@@ -28,15 +40,39 @@ pub async fn do_get(
 
     trace!("{:?}", metadata);
 
+    let ontology_tag = metadata.properties.ontology_tag.clone();
     let batch_size = tfacade.compute_optimal_batch_size().await?;
+    metrics.record_batch_size("topic", &ontology_tag, batch_size);
+    let format = metadata.properties.serialization_format;
+
+    // Where a follow ticket's initial read ends, so the follow loop resumes after it.
+    let served_through = match ticket.follow {
+        true => match tfacade.manifest().await {
+            Ok(manifest) => manifest.timestamp.map(|ts| ts.range.end),
+            Err(repo::FacadeError::NotFound(_)) => None,
+            Err(e) => return Err(e.into()),
+        },
+        false => None,
+    };
 
+    let read_started_at = std::time::Instant::now();
     let mut query_result = ts_engine
-        .read(
-            &tfacade.locator.name(),
-            metadata.properties.serialization_format,
-            Some(batch_size),
-        )
+        .read(&tfacade.locator.name(), format, Some(batch_size))
         .await?;
+    metrics.observe_query_latency("topic", &ontology_tag, read_started_at.elapsed());
+
+    if let Some(ts_range) = ticket.timestamp_range.clone() {
+        debug!("requesting timestamp range {}", ts_range);
+        query_result = query_result.filter_by_timestamp_range(ts_range)?;
+    }
+
+    if !ticket.filters.is_empty() {
+        query_result = query_result.filter(build_ontology_filter(&ticket.filters)?)?;
+    }
+
+    // Project down to the requested columns before computing the advertised schema, so
+    // the schema matches the data actually streamed below.
+    query_result = query_result.select_columns(&ticket.columns)?;
 
     // Append JSON metadata to original data schema
     let metadata = marshal::JsonTopicMetadata::from(metadata);
@@ -46,13 +82,51 @@ pub async fn do_get(
     let schema = query_result.schema_with_metadata(flatten_mdata);
     trace!("{:?}", schema);
 
-    if let Some(ts_range) = ticket.timestamp_range {
-        debug!("requesting timestamp range {}", ts_range);
-        query_result = query_result.filter_by_timestamp_range(ts_range)?;
-    }
-
     // Get data stream from query result
-    let stream = query_result.stream().await?;
+    let stream = query_result.stream().await?.map_err(ServerError::from);
+
+    // A follow ticket only makes sense if its end is still unbounded; otherwise there's
+    // nothing left to wait for once the fixed range has been served.
+    let follow = ticket.follow
+        && ticket
+            .timestamp_range
+            .as_ref()
+            .is_none_or(|r| r.end.is_unbounded());
+
+    let stream: std::pin::Pin<Box<dyn futures::Stream<Item = Result<_, ServerError>> + Send>> =
+        if follow {
+            let start = ticket
+                .timestamp_range
+                .as_ref()
+                .map(|r| r.start)
+                .unwrap_or_else(types::Timestamp::unbounded_neg);
+            let cursor = served_through.filter(|end| *end > start).unwrap_or(start);
+
+            let idle_timeout = ticket
+                .follow_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(FOLLOW_IDLE_TIMEOUT);
+
+            Box::pin(stream.chain(follow_stream(
+                tfacade,
+                ts_engine,
+                format,
+                batch_size,
+                cursor,
+                ticket.columns.clone(),
+                ticket.filters.clone(),
+                idle_timeout,
+                schema.clone(),
+            )))
+        } else {
+            Box::pin(stream)
+        };
+
+    // Record bytes/batches actually streamed out, after every filter/projection/follow
+    // stage above has run, so the numbers reflect what the client receives.
+    let stream = stream.inspect_ok(move |batch: &arrow::array::RecordBatch| {
+        metrics.record_do_get_batch("topic", &ontology_tag, batch.get_array_memory_size());
+    });
 
     // Convert the data stream to a flight stream casting the returned error
     let stream = stream.map_err(|e| FlightError::ExternalError(Box::new(e)));
@@ -61,3 +135,199 @@ pub async fn do_get(
         .with_schema(schema)
         .build(stream))
 }
+
+/// State driving [`follow_stream`]'s poll loop.
+struct FollowState {
+    tfacade: repo::FacadeTopic,
+    ts_engine: query::TimeseriesGatewayRef,
+    format: types::Format,
+    batch_size: usize,
+    /// Timestamp through which data has already been served; the next poll re-reads the
+    /// manifest and, if it advanced, refetches everything strictly after this cursor.
+    cursor: types::Timestamp,
+    /// Batches fetched by the last poll that haven't been yielded yet.
+    pending: std::vec::IntoIter<arrow::array::RecordBatch>,
+    notify: std::sync::Arc<tokio::sync::Notify>,
+    columns: Vec<String>,
+    filters: Vec<types::flight::ValueFilter>,
+    /// How long to wait for new data before emitting an empty "no change" marker batch
+    /// and closing the stream, letting a client reconnect with the same `cursor` instead
+    /// of holding the connection open indefinitely.
+    idle_timeout: Duration,
+    /// Schema used to build the empty marker batch; `None` once that marker has been
+    /// emitted, signalling the next poll to end the stream.
+    schema: Option<arrow::datatypes::SchemaRef>,
+}
+
+/// Long-polls the topic's manifest for data appended after `cursor`, pushing new batches
+/// as they land. If no write wakes it up within `idle_timeout`, emits a single empty
+/// "no change" marker batch (same schema, zero rows) and closes the stream; the client can
+/// reconnect with a ticket carrying the same `cursor` to keep following without gaps or
+/// duplicates. `columns` and `filters` are re-applied to every poll's read, same as the
+/// initial one.
+fn follow_stream(
+    tfacade: repo::FacadeTopic,
+    ts_engine: query::TimeseriesGatewayRef,
+    format: types::Format,
+    batch_size: usize,
+    cursor: types::Timestamp,
+    columns: Vec<String>,
+    filters: Vec<types::flight::ValueFilter>,
+    idle_timeout: Duration,
+    schema: arrow::datatypes::SchemaRef,
+) -> impl futures::Stream<Item = Result<arrow::array::RecordBatch, ServerError>> {
+    let locator_name: String = tfacade.locator.clone().into();
+    let notify = topic_watch::notifier_for(&locator_name);
+
+    let state = FollowState {
+        tfacade,
+        ts_engine,
+        format,
+        batch_size,
+        cursor,
+        pending: Vec::new().into_iter(),
+        notify,
+        columns,
+        filters,
+        idle_timeout,
+        schema: Some(schema),
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(batch) = state.pending.next() {
+                return Some((Ok(batch), state));
+            }
+
+            // The marker was already emitted on a prior iteration; end the stream.
+            let Some(schema) = state.schema.clone() else {
+                return None;
+            };
+
+            // Register interest in the next notification *before* checking the manifest, so a
+            // write landing between the check and the wait below still wakes us: `Notify` only
+            // wakes waiters already registered when `notify_waiters` is called, it doesn't queue
+            // a permit like `notify_one`, so getting the future after the check would let that
+            // write's wakeup pass by unobserved and block the follower for the full idle timeout.
+            let notified = state.notify.notified();
+
+            let manifest = match state.tfacade.manifest().await {
+                Ok(manifest) => manifest,
+                Err(repo::FacadeError::NotFound(_)) => types::TopicManifest::new(),
+                Err(e) => return Some((Err(ServerError::from(e)), state)),
+            };
+
+            let new_upper = manifest.timestamp.as_ref().map(|ts| ts.range.end);
+            let has_new_data = new_upper.is_some_and(|upper| upper > state.cursor);
+
+            if !has_new_data {
+                trace!("follower idle, waiting for new data on `{}`", state.tfacade.locator);
+                if tokio::time::timeout(state.idle_timeout, notified).await.is_err() {
+                    warn!(
+                        "follower on `{}` timed out after {:?} with no new data, \
+                         emitting a no-change marker at cursor {:?} and closing",
+                        state.tfacade.locator, state.idle_timeout, state.cursor
+                    );
+                    state.schema = None;
+                    return Some((Ok(arrow::array::RecordBatch::new_empty(schema)), state));
+                }
+                continue;
+            }
+
+            let new_upper = new_upper.expect("checked above");
+            let range = types::TimestampRange::between(state.cursor, new_upper)
+                .with_start_bound(types::Bound::Exclusive);
+
+            let ontology_filter = if state.filters.is_empty() {
+                None
+            } else {
+                match build_ontology_filter(&state.filters) {
+                    Ok(f) => Some(f),
+                    Err(e) => return Some((Err(e), state)),
+                }
+            };
+
+            let query_result = match state
+                .ts_engine
+                .read(&state.tfacade.locator.name(), state.format, Some(state.batch_size))
+                .await
+                .and_then(|qr| qr.filter_by_timestamp_range(range))
+            {
+                Ok(qr) => qr,
+                Err(e) => return Some((Err(ServerError::from(e)), state)),
+            };
+
+            let query_result = match ontology_filter
+                .map_or(Ok(query_result), |f| query_result.filter(f))
+                .and_then(|qr| qr.select_columns(&state.columns))
+            {
+                Ok(qr) => qr,
+                Err(e) => return Some((Err(ServerError::from(e)), state)),
+            };
+
+            let batches: Vec<_> = match query_result.stream().await {
+                Ok(s) => match s.try_collect().await {
+                    Ok(batches) => batches,
+                    Err(e) => return Some((Err(ServerError::from(e)), state)),
+                },
+                Err(e) => return Some((Err(ServerError::from(e)), state)),
+            };
+
+            state.cursor = new_upper;
+            state.pending = batches.into_iter();
+        }
+    })
+}
+
+/// Converts a ticket-carried scalar into the query engine's own value representation.
+fn to_query_value(value: &types::flight::FilterValue) -> query::Value {
+    match value {
+        types::flight::FilterValue::Integer(v) => query::Value::Integer(*v),
+        types::flight::FilterValue::Float(v) => query::Value::Float(*v),
+        types::flight::FilterValue::Text(v) => query::Value::Text(v.clone()),
+        types::flight::FilterValue::Boolean(v) => query::Value::Boolean(*v),
+    }
+}
+
+/// Translates a ticket's query-engine-independent [`types::flight::ValueFilter`]s into the
+/// query engine's own [`query::OntologyExprGroup`], kept as a separate conversion (rather
+/// than a `From` impl) since neither type is owned by this crate.
+fn build_ontology_filter(
+    filters: &[types::flight::ValueFilter],
+) -> Result<query::OntologyExprGroup<query::Value>, ServerError> {
+    let items = filters
+        .iter()
+        .map(|f| {
+            let field = query::OntologyField::try_new(f.column.clone())?;
+            let op = match f.op {
+                types::flight::FilterOp::Eq => query::Op::Eq(scalar_value(f)?),
+                types::flight::FilterOp::Neq => query::Op::Neq(scalar_value(f)?),
+                types::flight::FilterOp::Lt => query::Op::Lt(scalar_value(f)?),
+                types::flight::FilterOp::Lte => query::Op::Leq(scalar_value(f)?),
+                types::flight::FilterOp::Gt => query::Op::Gt(scalar_value(f)?),
+                types::flight::FilterOp::Gte => query::Op::Geq(scalar_value(f)?),
+                types::flight::FilterOp::In => {
+                    query::Op::In(f.values.iter().map(to_query_value).collect())
+                }
+            };
+
+            Ok::<_, query::Error>((field, op).into())
+        })
+        .collect::<Result<Vec<_>, query::Error>>()?;
+
+    Ok(query::OntologyExprGroup::new(items))
+}
+
+/// Pulls the single value a scalar (non-`In`) operator requires out of a ticket-carried
+/// filter, rejecting anything else.
+///
+/// A hand-crafted `Ticket` is decoded straight off the wire via bincode
+/// (`marshal::flight::ticket_topic_from_binary`), which has no way to enforce "exactly one
+/// value" on `ValueFilter::values` the way the JSON marshal path incidentally does, so this
+/// must be checked explicitly before indexing rather than assumed.
+fn scalar_value(filter: &types::flight::ValueFilter) -> Result<query::Value, query::Error> {
+    match filter.values.as_slice() {
+        [value] => Ok(to_query_value(value)),
+        _ => Err(query::Error::bad_field(filter.column.clone())),
+    }
+}
@@ -6,7 +6,7 @@ use arrow::datatypes::SchemaRef;
 use arrow_flight::decode::{DecodedFlightData, DecodedPayload, FlightDataDecoder};
 use arrow_flight::flight_descriptor::DescriptorType;
 use futures::TryStreamExt;
-use log::{info, trace};
+use log::{info, trace, warn};
 
 pub async fn do_put(ctx: Context, decoder: &mut FlightDataDecoder) -> Result<(), ServerError> {
     let (cmd, schema) = extract_command_and_schema_from_header_message(decoder).await?;
@@ -72,6 +72,7 @@ async fn do_put_topic_data(
     crate::arrow::check_schema(&schema)?;
 
     let mut handle = repo::FacadeTopic::new(locator, ctx.store.clone(), ctx.repo.clone());
+    let locator_name: String = handle.locator.clone().into();
 
     // perform the match between received key and topic id
     let r_id = handle.resource_id().await?;
@@ -81,6 +82,20 @@ async fn do_put_topic_data(
     }
 
     let mdata = handle.metadata().await?;
+    let dek = handle.dek_for_topic(&mdata).await?;
+
+    // `ts_engine`/`TimeseriesGatewayRef`, the only real read path `DoGet` has, never consults
+    // a topic's DEK and can't decrypt what gets written here; accepting the write anyway would
+    // make the topic's data permanently unreadable the moment its first chunk lands. Reject the
+    // write explicitly until decryption is wired into that read path instead of shipping a
+    // write-only data loss trap (see `FacadeTopic::dek_for_topic`).
+    if dek.is_some() {
+        return Err(repo::FacadeError::missing_data(format!(
+            "topic `{locator_name}` has encryption enabled, but writes to encrypted topics are \
+             unsupported: DoGet cannot decrypt them yet"
+        ))
+        .into());
+    }
 
     // Setup the callback that will be used to create the repository record for the data catalog
     // and prepare variables that will be moved in the closure
@@ -88,14 +103,28 @@ async fn do_put_topic_data(
     let serialization_format = mdata.properties.serialization_format;
     let topic_id = r_id.id;
 
+    // Replay any journaled but not-yet-completed finalize left by a previous crash before
+    // handing this topic out for a new write session (see `FacadeTopic::recover`).
+    if handle
+        .recover(ctx.timeseries_querier.clone(), serialization_format.clone())
+        .await?
+    {
+        warn!("recovered topic '{locator_name}' from an incomplete finalize before accepting new writes");
+    }
+
     trace!("creating topic writer");
-    let mut writer = handle.writer(ctx.timeseries_querier, serialization_format);
+    let mut writer = handle.writer(ctx.timeseries_querier, serialization_format, dek);
 
     trace!("setup chunk creation callback for topic");
-    writer.on_chunk_created(move |target_path, cols_stats, chunk_metadata| {
+    // `ctx.metrics` (see `crate::server::metrics::Metrics`) records chunk creation, column
+    // stats counts, and commit latency for this write; it's a no-op recorder when metrics
+    // collection isn't enabled for this process.
+    let metrics = ctx.metrics.clone();
+    writer.on_chunk_created(move |target_path, cols_stats, chunk_metadata, checksum| {
         let topic_id = topic_id;
         let repo_clone = ctx.repo.clone();
         let ontology_tag = ontology_tag.clone();
+        let metrics = metrics.clone();
 
         async move {
             trace!(
@@ -111,6 +140,8 @@ async fn do_put_topic_data(
                 target_path,
                 cols_stats,
                 chunk_metadata,
+                checksum,
+                metrics,
             )
             .await?)
         }
@@ -118,11 +149,16 @@ async fn do_put_topic_data(
 
     // Consume all batches
     trace!("ready to consume batches");
-    while let Some(data) = decoder
-        .try_next()
-        .await
-        .map_err(|e| ServerError::StreamError(e.to_string()))?
-    {
+    loop {
+        let data = match decoder.try_next().await {
+            Ok(Some(data)) => data,
+            Ok(None) => break,
+            Err(e) => {
+                abort_writer_ignoring_error(writer).await;
+                return Err(ServerError::StreamError(e.to_string()));
+            }
+        };
+
         match data.payload {
             DecodedPayload::RecordBatch(batch) => {
                 trace!(
@@ -130,25 +166,48 @@ async fn do_put_topic_data(
                     batch.columns().len(),
                     batch.get_array_memory_size()
                 );
-                writer.write(&batch).await?;
+                // A mid-stream write error leaves any chunks already uploaded for this topic
+                // orphaned unless they're explicitly purged; `abort` deletes them per the
+                // writer's default `AbortPolicy::Purge` before the error is surfaced. The
+                // write error, not a subsequent abort failure, is always what's reported back.
+                if let Err(e) = writer.write(&batch).await {
+                    abort_writer_ignoring_error(writer).await;
+                    return Err(e.into());
+                }
             }
             DecodedPayload::Schema(_) => {
+                abort_writer_ignoring_error(writer).await;
                 return Err(ServerError::DuplicateSchemaInPayload);
             }
             DecodedPayload::None => {
+                abort_writer_ignoring_error(writer).await;
                 return Err(ServerError::NoData);
             }
         }
     }
 
     // If the finalize fails (e.g. problems during stats computation) the topic will not be locked,
-    // this allows the reindexing (currently not implemented) of the topic
+    // this allows the reindexing (currently not implemented) of the topic. `finalize` purges any
+    // chunks it already wrote before surfacing the error, same as the `abort` calls above.
     trace!("finializing data write");
     writer.finalize().await?;
 
+    // Wake any `DoGet` followers long-polling this topic so they pick up the new data
+    // without waiting out their idle timeout.
+    crate::server::topic_watch::notify_new_data(&locator_name);
+
     Ok(())
 }
 
+/// Aborts `writer`, logging (rather than propagating) any failure to do so. Used on every
+/// error path out of [`do_put_topic_data`]'s ingest loop so that an abort failure never
+/// shadows the original error that triggered it.
+async fn abort_writer_ignoring_error(writer: repo::FacadeTopicWriterGuard<'_>) {
+    if let Err(e) = writer.abort().await {
+        warn!("failed to abort topic writer while handling an earlier error: {e}");
+    }
+}
+
 async fn on_chunk_created(
     repo: repo::Repository,
     topic_id: i32,
@@ -156,22 +215,32 @@ async fn on_chunk_created(
     target_path: impl AsRef<std::path::Path>,
     cstats: types::OntologyModelStats,
     chunk_metadata: rw::ChunkMetadata,
+    checksum: u32,
+    metrics: crate::server::metrics::MetricsRef,
 ) -> Result<(), ServerError> {
+    let size_bytes = chunk_metadata.size_bytes as i64;
     let mut handle = repo::FacadeChunk::create(
         topic_id,
         &target_path,
-        chunk_metadata.size_bytes as i64,
+        size_bytes,
         chunk_metadata.row_count as i64,
+        checksum,
         &repo,
     )
     .await?;
+    metrics.record_chunk_created("topic", ontology_tag, size_bytes);
 
-    // Use batch insert for better performance (single INSERT per type instead of N)
-    handle
+    // Use batch insert for better performance (single INSERT per type instead of N); the
+    // numeric/textual split is reported by the facade so this layer doesn't need to
+    // re-inspect `cstats` just to label the metric.
+    let (numeric_count, textual_count) = handle
         .push_ontology_model_stats(ontology_tag, cstats)
         .await?;
+    metrics.record_stats_inserted("topic", ontology_tag, numeric_count, textual_count);
 
+    let commit_started_at = std::time::Instant::now();
     handle.finalize().await?;
+    metrics.observe_commit_latency(commit_started_at.elapsed());
 
     Ok(())
 }
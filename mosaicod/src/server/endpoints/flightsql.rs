@@ -0,0 +1,110 @@
+//! FlightSQL dialect support over mosaico's topic/sequence model.
+//!
+//! Generic Arrow Flight SQL clients (JDBC/ODBC bridges, `flight_sql_client`, etc.) send
+//! well-known protobuf `Command*` messages packed in a `google.protobuf.Any` as
+//! `FlightDescriptor::cmd`, rather than mosaico's own JSON [`types::flight::GetFlightInfoCmd`].
+//! This module recognizes that subset of the dialect and maps it onto the resolvers the
+//! JSON cmd format already uses:
+//!
+//! - `CommandGetCatalogs` / `CommandGetDbSchemas` / `CommandGetTables` describe the shape
+//!   of mosaico's single catalog, its sequences (schemas), and their topics (tables). Only
+//!   the introspection schema is produced here, not actual rows.
+//!   //(cabba) TODO: wire a `DoGet` ticket kind that replays these rows instead of only
+//!   describing their shape.
+//! - `CommandStatementQuery` accepts a small `SELECT ... FROM <resource> [WHERE timestamp
+//!   <op> <value> [AND ...]]` dialect, parsed into the same `GetFlightInfoCmd` the JSON
+//!   format produces and resolved through the existing topic/sequence machinery — this one
+//!   is fully functional end to end through `DoGet`.
+
+use super::Context;
+use crate::{marshal, server::errors::ServerError};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow_flight::sql::{Any, CommandGetCatalogs, CommandGetDbSchemas, CommandGetTables, CommandStatementQuery};
+use arrow_flight::{FlightDescriptor, FlightInfo};
+use prost::Message;
+
+/// A FlightSQL command this server recognizes, decoded from the `Any`-wrapped payload
+/// carried by `FlightDescriptor::cmd`.
+pub enum Command {
+    GetCatalogs,
+    GetDbSchemas(CommandGetDbSchemas),
+    GetTables(CommandGetTables),
+    StatementQuery(CommandStatementQuery),
+}
+
+/// Attempts to unpack `raw` as one of the [`Command`] variants above. Returns `None` for
+/// anything else, including mosaico's own JSON cmd format, so the caller falls back to its
+/// existing dispatch.
+pub fn try_decode(raw: &[u8]) -> Option<Command> {
+    let any = Any::decode(raw).ok()?;
+
+    if any.unpack::<CommandGetCatalogs>().ok().flatten().is_some() {
+        return Some(Command::GetCatalogs);
+    }
+    if let Some(cmd) = any.unpack::<CommandGetDbSchemas>().ok().flatten() {
+        return Some(Command::GetDbSchemas(cmd));
+    }
+    if let Some(cmd) = any.unpack::<CommandGetTables>().ok().flatten() {
+        return Some(Command::GetTables(cmd));
+    }
+    if let Some(cmd) = any.unpack::<CommandStatementQuery>().ok().flatten() {
+        return Some(Command::StatementQuery(cmd));
+    }
+
+    None
+}
+
+/// Resolves a recognized FlightSQL [`Command`] into a [`FlightInfo`].
+pub async fn get_flight_info(
+    ctx: Context,
+    desc: FlightDescriptor,
+    cmd: Command,
+) -> Result<FlightInfo, ServerError> {
+    match cmd {
+        Command::GetCatalogs => catalogs_info(desc),
+        Command::GetDbSchemas(_) => db_schemas_info(desc),
+        Command::GetTables(_) => tables_info(desc),
+        Command::StatementQuery(cmd) => {
+            let cmd = marshal::flight::statement_query_cmd(&cmd.query)?;
+            super::get_flight_info_for_cmd(ctx, desc, cmd).await
+        }
+    }
+}
+
+/// Schema-only `FlightInfo` describing the shape of `CommandGetCatalogs`'s result set.
+/// mosaico exposes a single, fixed catalog, so there's nothing to stream via `DoGet` yet.
+fn catalogs_info(desc: FlightDescriptor) -> Result<FlightInfo, ServerError> {
+    let schema = Schema::new(vec![Field::new("catalog_name", DataType::Utf8, false)]);
+
+    Ok(FlightInfo::new()
+        .with_descriptor(desc)
+        .try_with_schema(&schema)?)
+}
+
+/// Schema-only `FlightInfo` describing the shape of `CommandGetDbSchemas`'s result set;
+/// mosaico's sequences stand in as FlightSQL "db schemas".
+fn db_schemas_info(desc: FlightDescriptor) -> Result<FlightInfo, ServerError> {
+    let schema = Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, false),
+    ]);
+
+    Ok(FlightInfo::new()
+        .with_descriptor(desc)
+        .try_with_schema(&schema)?)
+}
+
+/// Schema-only `FlightInfo` describing the shape of `CommandGetTables`'s result set;
+/// mosaico's topics stand in as FlightSQL "tables".
+fn tables_info(desc: FlightDescriptor) -> Result<FlightInfo, ServerError> {
+    let schema = Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("table_type", DataType::Utf8, false),
+    ]);
+
+    Ok(FlightInfo::new()
+        .with_descriptor(desc)
+        .try_with_schema(&schema)?)
+}
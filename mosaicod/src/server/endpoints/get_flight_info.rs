@@ -9,6 +9,7 @@ use arrow::datatypes::{Field, Schema};
 use arrow_flight::{
     FlightDescriptor, FlightEndpoint, FlightInfo, Ticket, flight_descriptor::DescriptorType,
 };
+use futures::{StreamExt, stream};
 use log::{info, trace};
 
 pub async fn get_flight_info(
@@ -17,170 +18,327 @@ pub async fn get_flight_info(
 ) -> Result<FlightInfo, ServerError> {
     match desc.r#type() {
         DescriptorType::Cmd => {
+            if let Some(cmd) = super::flightsql::try_decode(&desc.cmd) {
+                return super::flightsql::get_flight_info(ctx, desc, cmd).await;
+            }
+
+            if marshal::flight::is_get_flight_info_batch_cmd(&desc.cmd) {
+                return get_flight_info_batch(ctx, desc).await;
+            }
+
             let cmd = marshal::flight::get_flight_info_cmd(&desc.cmd)?;
-            let resource_name = &cmd.resource_locator;
-
-            info!("requesting info for resource {}", resource_name);
-
-            let resource = repo::get_resource_locator_from_name(&ctx.repo, resource_name).await?;
-
-            match resource.resource_type() {
-                types::ResourceType::Sequence => {
-                    let handle = FacadeSequence::new(
-                        resource.name().into(),
-                        ctx.store.clone(),
-                        ctx.repo.clone(),
-                    );
-                    let metadata = handle.metadata().await?;
-
-                    trace!(
-                        "{} building empty schema (+platform metadata)",
-                        handle.locator
-                    );
-
-                    // Collect metadata
-                    let metadata = marshal::JsonSequenceMetadata::from(metadata);
-                    let flatten_metadata = metadata.to_flat_hashmap().map_err(FacadeError::from)?;
-
-                    // Collect schema
-                    let schema = Schema::new_with_metadata(Vec::<Field>::new(), flatten_metadata);
-
-                    trace!("{} generating endpoints", handle.locator);
-                    let topics = handle.topic_list().await?;
-
-                    // Collect manifests
-                    let manifests = collect_manifests(ctx, &topics).await?;
-
-                    // Populate endpoints
-                    let endpoints: Vec<FlightEndpoint> = topics
-                        .into_iter()
-                        .enumerate()
-                        .map(|(index, topic)| {
-                            let ticket = types::flight::TicketTopic {
-                                locator: topic.name().clone(),
-                                timestamp_range: cmd.timestamp_range.clone(),
-                            };
-
-                            let app_mdata =
-                                marshal::flight::TopicAppMetadata::new(&manifests[index]);
-
-                            let e = FlightEndpoint::new()
-                                .with_ticket(Ticket {
-                                    ticket: marshal::flight::ticket_topic_to_binary(ticket)?.into(),
-                                })
-                                .with_app_metadata(app_mdata)
-                                .with_location(topic.url()?);
-
-                            Ok::<FlightEndpoint, ServerError>(e)
-                        })
-                        .collect::<Result<_, ServerError>>()?;
+            get_flight_info_for_cmd(ctx, desc, cmd).await
+        }
+        _ => Err(ServerError::UnsupportedDescriptor),
+    }
+}
 
-                    trace!("{} generating endpoints: {:?}", handle.locator, endpoints);
-                    let mut flight_info = FlightInfo::new()
-                        .with_descriptor(desc.clone())
-                        .try_with_schema(&schema)?;
+/// Resolves a single-resource `GetFlightInfo` request, shared by mosaico's own JSON cmd
+/// format and FlightSQL's `CommandStatementQuery` (see [`super::flightsql`]), both of
+/// which boil down to the same [`types::flight::GetFlightInfoCmd`].
+pub(super) async fn get_flight_info_for_cmd(
+    ctx: Context,
+    desc: FlightDescriptor,
+    cmd: types::flight::GetFlightInfoCmd,
+) -> Result<FlightInfo, ServerError> {
+    let resource_name = &cmd.resource_locator;
 
-                    for endpoint in endpoints {
-                        flight_info = flight_info.with_endpoint(endpoint);
-                    }
+    info!("requesting info for resource {}", resource_name);
 
-                    trace!("{} done", handle.locator);
-                    Ok(flight_info)
-                }
+    let resource = repo::get_resource_locator_from_name(&ctx.repo, resource_name).await?;
 
-                types::ResourceType::Topic => {
-                    let handle =
-                        FacadeTopic::new(resource.name().into(), ctx.store, ctx.repo.clone());
-                    let metadata = handle.metadata().await?;
-
-                    trace!("{} building schema (+platform metadata)", handle.locator);
-
-                    // Collect schema, if no schema was found generate an create an empty schema
-                    let schema = match handle
-                        .arrow_schema(metadata.properties.serialization_format)
-                        .await
-                    {
-                        Ok(s) => s,
-                        Err(FacadeError::NotFound(_)) => crate::arrow::empty_schema_ref(),
-                        Err(e) => return Err(e.into()),
-                    };
+    match resource.resource_type() {
+        types::ResourceType::Sequence => {
+            let handle = FacadeSequence::new(
+                resource.name().into(),
+                ctx.store.clone(),
+                ctx.repo.clone(),
+            );
+            let metadata = handle.metadata().await?;
 
-                    // Collect metadata
-                    let metadata = marshal::JsonTopicMetadata::from(metadata);
-                    let flatten_metadata = metadata.to_flat_hashmap().map_err(FacadeError::from)?;
+            trace!(
+                "{} building empty schema (+platform metadata)",
+                handle.locator
+            );
 
-                    // Build schema to send
-                    let schema =
-                        Schema::new_with_metadata(schema.fields().clone(), flatten_metadata);
+            // Collect metadata
+            let metadata = marshal::JsonSequenceMetadata::from(metadata);
+            let flatten_metadata = metadata.to_flat_hashmap().map_err(FacadeError::from)?;
 
-                    // Collect manifest, if no manifest is found an empty one is returned while
-                    // other errors are propagated
-                    let manifest = match handle.manifest().await {
-                        Ok(m) => m,
-                        Err(FacadeError::NotFound(_)) => types::TopicManifest::new(),
-                        Err(e) => return Err(e.into()),
-                    };
+            // Collect schema
+            let schema = Schema::new_with_metadata(Vec::<Field>::new(), flatten_metadata);
 
-                    // We can get directly the only elements since collect_manifests ensures that
-                    // there will be at least one entry returned (if no error)
-                    let app_mdata = marshal::flight::TopicAppMetadata::new(&manifest);
+            trace!("{} generating endpoints", handle.locator);
+            let topics = handle.topic_list().await?;
 
+            // Collect manifests
+            let manifests = collect_manifests(ctx, &topics).await?;
+
+            // Populate endpoints
+            let endpoints: Vec<FlightEndpoint> = topics
+                .into_iter()
+                .enumerate()
+                .map(|(index, topic)| {
                     let ticket = types::flight::TicketTopic {
-                        locator: handle.locator.clone().into(),
-                        timestamp_range: cmd.timestamp_range,
+                        locator: topic.name().clone(),
+                        timestamp_range: cmd.timestamp_range.clone(),
+                        follow: cmd.follow,
+                        follow_timeout_secs: cmd.follow_timeout_secs,
+                        columns: cmd.columns.clone(),
+                        filters: cmd.filters.clone(),
                     };
 
-                    // building a single endpoint for topic data
-                    let endpoint = FlightEndpoint::new()
+                    let app_mdata = marshal::flight::TopicAppMetadata::new(&manifests[index]);
+
+                    let e = FlightEndpoint::new()
                         .with_ticket(Ticket {
                             ticket: marshal::flight::ticket_topic_to_binary(ticket)?.into(),
                         })
                         .with_app_metadata(app_mdata)
-                        .with_location(handle.locator.url()?);
+                        .with_location(topic.url()?);
+
+                    Ok::<FlightEndpoint, ServerError>(e)
+                })
+                .collect::<Result<_, ServerError>>()?;
+
+            trace!("{} generating endpoints: {:?}", handle.locator, endpoints);
+            let mut flight_info = FlightInfo::new()
+                .with_descriptor(desc.clone())
+                .try_with_schema(&schema)?;
 
-                    trace!("{} generating endpoint {:?}", handle.locator, endpoint);
+            for endpoint in endpoints {
+                flight_info = flight_info.with_endpoint(endpoint);
+            }
+
+            trace!("{} done", handle.locator);
+            Ok(flight_info)
+        }
+
+        types::ResourceType::Topic => {
+            let handle = FacadeTopic::new(resource.name().into(), ctx.store, ctx.repo.clone());
+            let metadata = handle.metadata().await?;
+
+            trace!("{} building schema (+platform metadata)", handle.locator);
+
+            // Collect schema, if no schema was found generate an create an empty schema
+            let schema = match handle
+                .arrow_schema(metadata.properties.serialization_format)
+                .await
+            {
+                Ok(s) => s,
+                Err(FacadeError::NotFound(_)) => crate::arrow::empty_schema_ref(),
+                Err(e) => return Err(e.into()),
+            };
+
+            // Narrow the fields down to the requested projection, if any, so the schema we
+            // advertise matches what `DoGet` will actually stream.
+            let schema = project_schema(&schema, &cmd.columns)?;
+
+            // Collect metadata
+            let metadata = marshal::JsonTopicMetadata::from(metadata);
+            let flatten_metadata = metadata.to_flat_hashmap().map_err(FacadeError::from)?;
+
+            // Build schema to send
+            let schema = Schema::new_with_metadata(schema.fields().clone(), flatten_metadata);
+
+            // Collect manifest, if no manifest is found an empty one is returned while
+            // other errors are propagated
+            let manifest = match handle.manifest().await {
+                Ok(m) => m,
+                Err(FacadeError::NotFound(_)) => types::TopicManifest::new(),
+                Err(e) => return Err(e.into()),
+            };
+
+            // The single endpoint's app metadata comes from this topic's own manifest
+            let app_mdata = marshal::flight::TopicAppMetadata::new(&manifest);
+
+            let ticket = types::flight::TicketTopic {
+                locator: handle.locator.clone().into(),
+                timestamp_range: cmd.timestamp_range,
+                follow: cmd.follow,
+                follow_timeout_secs: cmd.follow_timeout_secs,
+                columns: cmd.columns,
+                filters: cmd.filters,
+            };
+
+            // building a single endpoint for topic data
+            let endpoint = FlightEndpoint::new()
+                .with_ticket(Ticket {
+                    ticket: marshal::flight::ticket_topic_to_binary(ticket)?.into(),
+                })
+                .with_app_metadata(app_mdata)
+                .with_location(handle.locator.url()?);
+
+            trace!("{} generating endpoint {:?}", handle.locator, endpoint);
+
+            let mut flight_info = FlightInfo::new()
+                .with_descriptor(desc.clone())
+                .try_with_schema(&schema)?;
+
+            flight_info = flight_info.with_endpoint(endpoint);
+
+            trace!("{} done", handle.locator);
+            Ok(flight_info)
+        }
+    }
+}
 
-                    let mut flight_info = FlightInfo::new()
-                        .with_descriptor(desc.clone())
-                        .try_with_schema(&schema)?;
+/// Narrows `schema`'s fields down to `columns`, in the order requested, so the advertised
+/// schema matches the projection `DoGet` will apply while scanning. Returns `schema`
+/// unchanged when `columns` is empty, i.e. no projection was requested.
+fn project_schema(schema: &Schema, columns: &[String]) -> Result<Schema, ServerError> {
+    if columns.is_empty() {
+        return Ok(schema.clone());
+    }
 
-                    flight_info = flight_info.with_endpoint(endpoint);
+    let fields = columns
+        .iter()
+        .map(|name| schema.field_with_name(name).cloned())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
 
-                    trace!("{} done", handle.locator);
-                    Ok(flight_info)
+/// Resolves a batched `GetFlightInfo` request, returning a single [`FlightInfo`] whose
+/// endpoints span every requested resource. A sequence entry is expanded into one
+/// endpoint per topic it contains, each inheriting that entry's range/follow settings;
+/// manifest fetches for every resulting topic are fanned out through
+/// [`collect_manifests`] rather than one round trip per batch entry.
+async fn get_flight_info_batch(ctx: Context, desc: FlightDescriptor) -> Result<FlightInfo, ServerError> {
+    let batch_cmd = marshal::flight::get_flight_info_batch_cmd(&desc.cmd)?;
+
+    info!(
+        "requesting batched info for {} resource(s)",
+        batch_cmd.entries.len()
+    );
+
+    let mut topics_with_settings = Vec::with_capacity(batch_cmd.entries.len());
+    for entry in batch_cmd.entries {
+        let resource =
+            repo::get_resource_locator_from_name(&ctx.repo, &entry.resource_locator).await?;
+
+        match resource.resource_type() {
+            types::ResourceType::Topic => {
+                topics_with_settings.push((
+                    types::TopicResourceLocator::from(entry.resource_locator),
+                    entry.timestamp_range,
+                    entry.follow,
+                    entry.follow_timeout_secs,
+                    entry.columns,
+                    entry.filters,
+                ));
+            }
+            types::ResourceType::Sequence => {
+                let handle =
+                    FacadeSequence::new(resource.name().into(), ctx.store.clone(), ctx.repo.clone());
+
+                for topic in handle.topic_list().await? {
+                    topics_with_settings.push((
+                        topic,
+                        entry.timestamp_range.clone(),
+                        entry.follow,
+                        entry.follow_timeout_secs,
+                        entry.columns.clone(),
+                        entry.filters.clone(),
+                    ));
                 }
             }
         }
-        _ => Err(ServerError::UnsupportedDescriptor),
     }
+
+    let topics: Vec<types::TopicResourceLocator> = topics_with_settings
+        .iter()
+        .map(|(topic, ..)| topic.clone())
+        .collect();
+    let manifests = collect_manifests(ctx, &topics).await?;
+
+    let endpoints: Vec<FlightEndpoint> = topics_with_settings
+        .into_iter()
+        .zip(manifests.iter())
+        .map(|((topic, timestamp_range, follow, follow_timeout_secs, columns, filters), manifest)| {
+            let ticket = types::flight::TicketTopic {
+                locator: topic.name().clone(),
+                timestamp_range,
+                follow,
+                follow_timeout_secs,
+                columns,
+                filters,
+            };
+
+            let app_mdata = marshal::flight::TopicAppMetadata::new(manifest);
+
+            let e = FlightEndpoint::new()
+                .with_ticket(Ticket {
+                    ticket: marshal::flight::ticket_topic_to_binary(ticket)?.into(),
+                })
+                .with_app_metadata(app_mdata)
+                .with_location(topic.url()?);
+
+            Ok::<FlightEndpoint, ServerError>(e)
+        })
+        .collect::<Result<_, ServerError>>()?;
+
+    // A batch spans resources with potentially different schemas, so the aggregated
+    // response carries no single schema of its own; callers resolve per-endpoint schemas
+    // via the ticket's own topic, the same way a single-sequence request does today.
+    let schema = crate::arrow::empty_schema_ref();
+
+    trace!("batch request done, {} endpoint(s)", endpoints.len());
+
+    let mut flight_info = FlightInfo::new()
+        .with_descriptor(desc.clone())
+        .try_with_schema(&schema)?;
+
+    for endpoint in endpoints {
+        flight_info = flight_info.with_endpoint(endpoint);
+    }
+
+    Ok(flight_info)
 }
 
+/// Default number of topic manifests fetched concurrently by [`collect_manifests`] when
+/// `Context` doesn't override it.
+const DEFAULT_MANIFEST_FETCH_CONCURRENCY: usize = 16;
+
 /// Retrieves the manifest for every provided topic.
 ///
 /// This function guarantees a 1:1 mapping: the output vector will strictly correspond
-/// to the input slice in both length and order.
+/// to the input slice in both length and order. Fetches are fanned out concurrently,
+/// bounded by `ctx.manifest_fetch_concurrency`, instead of paying one serial round-trip
+/// per topic.
 pub async fn collect_manifests(
     ctx: Context,
     topics: &[types::TopicResourceLocator],
 ) -> Result<Vec<types::TopicManifest>, ServerError> {
-    let mut manifests = Vec::new();
+    let limit = ctx
+        .manifest_fetch_concurrency
+        .unwrap_or(DEFAULT_MANIFEST_FETCH_CONCURRENCY)
+        .max(1);
 
-    for topic in topics {
+    let fetches = topics.iter().map(|topic| {
         // (cabba) TODO: avoid cloning avery time store and repo, maybe a `.into_parts()` to reuse
         // facade resources ?
         let handler =
             FacadeTopic::new(topic.name().to_owned(), ctx.store.clone(), ctx.repo.clone());
 
-        // Collect manifest, if no manifest is found an empty one is returned while
-        // other errors are propagated
-        let manifest = match handler.manifest().await {
-            Ok(manifest) => manifest,
-            Err(FacadeError::NotFound(_)) => types::TopicManifest::new(),
-            Err(e) => return Err(e.into()),
-        };
+        async move {
+            // Collect manifest, if no manifest is found an empty one is returned while
+            // other errors are propagated
+            match handler.manifest().await {
+                Ok(manifest) => Ok(manifest),
+                Err(FacadeError::NotFound(_)) => Ok(types::TopicManifest::new()),
+                Err(e) => Err(e),
+            }
+        }
+    });
 
-        manifests.push(manifest);
-    }
+    // `buffered` drives up to `limit` futures concurrently while still yielding results in
+    // the original input order, preserving the documented 1:1 invariant above.
+    let manifests: Vec<Result<types::TopicManifest, FacadeError>> =
+        stream::iter(fetches).buffered(limit).collect().await;
 
-    Ok(manifests)
+    manifests
+        .into_iter()
+        .collect::<Result<Vec<_>, FacadeError>>()
+        .map_err(Into::into)
 }
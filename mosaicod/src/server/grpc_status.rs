@@ -0,0 +1,59 @@
+//! Maps [`ServerError`] onto `tonic::Status`, attaching a machine-readable
+//! [`types::flight::ErrorDetail`] to the status's `details` payload so Flight clients can
+//! branch on a stable error code and a `retryable` flag instead of pattern-matching the
+//! status message.
+//!
+//! Only `StreamError` is classified as `Unavailable`/retryable, and the request-shape errors
+//! listed in [`classify`] as `InvalidArgument`. Every other variant, store and repository
+//! failures included, falls back to `Internal`/non-retryable; `ErrorCode::NotFound` is never
+//! produced here.
+
+use crate::{marshal, server::errors::ServerError, types};
+use tonic::{Code, Status};
+
+/// Classifies `err` into a gRPC [`Code`] plus the [`types::flight::ErrorDetail`] carried
+/// alongside it.
+fn classify(err: &ServerError) -> (Code, types::flight::ErrorDetail) {
+    let (code, error_code, retryable) = match err {
+        ServerError::UnsupportedDescriptor
+        | ServerError::MissingDescriptior
+        | ServerError::MissingSchema
+        | ServerError::MissingDoPutHeaderMessage
+        | ServerError::DuplicateSchemaInPayload
+        | ServerError::BadKey
+        | ServerError::NoData => (
+            Code::InvalidArgument,
+            types::flight::ErrorCode::InvalidArgument,
+            false,
+        ),
+
+        // An upstream connection hiccup is worth retrying, ideally with backoff, rather
+        // than surfacing as an opaque `Unknown`.
+        ServerError::StreamError(_) => (
+            Code::Unavailable,
+            types::flight::ErrorCode::Unavailable,
+            true,
+        ),
+
+        _ => (Code::Internal, types::flight::ErrorCode::Internal, false),
+    };
+
+    (
+        code,
+        types::flight::ErrorDetail {
+            code: error_code,
+            retryable,
+        },
+    )
+}
+
+/// Converts `err` into a `tonic::Status` carrying a human-readable message plus an encoded
+/// [`types::flight::ErrorDetail`] in its `details` payload.
+pub fn to_status(err: ServerError) -> Status {
+    let (code, detail) = classify(&err);
+    let message = err.to_string();
+
+    let details = marshal::flight::error_detail_to_binary(detail).unwrap_or_default();
+
+    Status::with_details(code, message, details.into())
+}
@@ -0,0 +1,31 @@
+//! Wakes long-poll "follow" readers in `do_get` when new data lands for a topic.
+//!
+//! `DoPut` notifies the locator's entry after a successful write; followers parked in
+//! `do_get`'s follow loop wake up, re-check the topic manifest past their cursor, and
+//! resume streaming rather than re-issuing `GetFlightInfo`/`DoGet`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::Notify;
+
+fn registry() -> &'static Mutex<HashMap<String, Arc<Notify>>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, Arc<Notify>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Returns the notifier for `locator`, creating one the first time it's referenced.
+pub fn notifier_for(locator: &str) -> Arc<Notify> {
+    registry()
+        .lock()
+        .expect("topic watch registry poisoned")
+        .entry(locator.to_owned())
+        .or_insert_with(|| Arc::new(Notify::new()))
+        .clone()
+}
+
+/// Wakes any follower parked on `locator`. Called by `DoPut` after a successful write.
+pub fn notify_new_data(locator: &str) {
+    if let Some(notify) = registry().lock().expect("topic watch registry poisoned").get(locator) {
+        notify.notify_waiters();
+    }
+}
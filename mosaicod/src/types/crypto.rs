@@ -0,0 +1,14 @@
+//! Envelope-encryption primitives for chunk data at rest.
+//!
+//! An encrypted topic (`TopicProperties::encrypted`) gets its own [`DataEncryptionKey`] (DEK),
+//! generated once on the topic's first write (see
+//! [`FacadeTopic::dek_for_topic`](crate::repo::facades::FacadeTopic::dek_for_topic)) and
+//! wrapped by a [`KeyEncryptionKey`] (KEK) sourced from the `encryption_master_key`
+//! configurable (see `params::configurables`) before being persisted as a [`WrappedKey`]
+//! alongside the topic. The DEK itself never touches the repository or the object store
+//! unwrapped.
+//!
+//! These types are defined once, in `mosaicod_core::crypto`, and re-exported here so the old
+//! tree and the `mosaicod-*` crates share a single implementation instead of drifting apart.
+
+pub use mosaicod_core::crypto::{CryptoError, DataEncryptionKey, KeyEncryptionKey, WrappedKey};
@@ -10,6 +10,33 @@ pub struct DoPutCmd {
 pub struct GetFlightInfoCmd {
     pub resource_locator: String,
     pub timestamp_range: Option<TimestampRange>,
+    /// Whether the caller wants the resulting ticket to live-tail new data instead of
+    /// closing once the current timestamp range has been served.
+    pub follow: bool,
+    /// How long a `follow` ticket's `DoGet` blocks waiting for new data before emitting a
+    /// "no change" marker, overriding `DoGet`'s own default idle timeout. Ignored when
+    /// `follow` is false.
+    pub follow_timeout_secs: Option<u64>,
+    /// Column projection applied server-side by `DoGet`. Empty means no projection, i.e.
+    /// every column is returned.
+    pub columns: Vec<String>,
+    /// Value predicates pushed down into `DoGet`'s scan, in addition to `timestamp_range`.
+    pub filters: Vec<ValueFilter>,
+}
+
+/// One resource entry of a batched [`GetFlightInfoBatchCmd`] request.
+pub struct GetFlightInfoBatchEntry {
+    pub resource_locator: String,
+    pub timestamp_range: Option<TimestampRange>,
+    pub follow: bool,
+    pub follow_timeout_secs: Option<u64>,
+    pub columns: Vec<String>,
+    pub filters: Vec<ValueFilter>,
+}
+
+/// Request info on multiple mosaico resources (topics or sequences) in a single round trip.
+pub struct GetFlightInfoBatchCmd {
+    pub entries: Vec<GetFlightInfoBatchEntry>,
 }
 
 pub struct TicketTopic {
@@ -17,4 +44,77 @@ pub struct TicketTopic {
     pub locator: String,
     /// Optional timestamp range used to limit the data stream
     pub timestamp_range: Option<TimestampRange>,
+    /// When true and `timestamp_range`'s end is unbounded, `DoGet` keeps the stream open
+    /// and pushes newly-appended batches as they land instead of closing immediately.
+    pub follow: bool,
+    /// How long `DoGet` blocks between polls waiting for new data before emitting a "no
+    /// change" marker batch and closing the stream, overriding the server's own default.
+    pub follow_timeout_secs: Option<u64>,
+    /// Column projection applied server-side by `DoGet`. Empty means no projection, i.e.
+    /// every column is returned.
+    pub columns: Vec<String>,
+    /// Value predicates applied server-side by `DoGet`, in addition to `timestamp_range`.
+    pub filters: Vec<ValueFilter>,
+}
+
+/// Comparison applied by a [`ValueFilter`], independent of any particular query engine's
+/// own predicate representation.
+#[derive(Clone)]
+pub enum FilterOp {
+    Eq,
+    Neq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    /// Matches when the column's value is one of [`ValueFilter::values`].
+    In,
+}
+
+/// A scalar operand carried by a [`ValueFilter`], independent of any particular query
+/// engine's own value representation.
+#[derive(Clone)]
+pub enum FilterValue {
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Boolean(bool),
+}
+
+/// A single `column <op> value` predicate pushed down into `DoGet`'s scan, carried
+/// alongside the timestamp range on both [`GetFlightInfoCmd`] and [`TicketTopic`].
+#[derive(Clone)]
+pub struct ValueFilter {
+    pub column: String,
+    pub op: FilterOp,
+    /// The operand(s): exactly one value for every op except [`FilterOp::In`], which may
+    /// carry several.
+    pub values: Vec<FilterValue>,
+}
+
+/// Stable, machine-readable classification of a server error, attached to the `tonic::Status`
+/// a Flight RPC returns so clients can branch on it instead of pattern-matching the message
+/// string. Deliberately coarser than `ServerError` itself: new `ServerError` variants should
+/// map onto one of these rather than growing this list.
+#[derive(Clone, Copy)]
+pub enum ErrorCode {
+    /// The requested resource (topic, sequence, file) does not exist.
+    NotFound,
+    /// The request itself is malformed or unsupported; retrying unchanged won't help.
+    InvalidArgument,
+    /// A dependency (store, repo) is temporarily unreachable; retrying, ideally with
+    /// backoff, may succeed.
+    Unavailable,
+    /// An unclassified failure internal to the server.
+    Internal,
+}
+
+/// Machine-readable detail attached to a gRPC status's `details` payload, alongside the
+/// human-readable message, so clients can tell "retry with backoff" from "fix your request"
+/// without parsing error text.
+#[derive(Clone)]
+pub struct ErrorDetail {
+    pub code: ErrorCode,
+    /// Whether retrying the same request, possibly after a backoff, could succeed.
+    pub retryable: bool,
 }